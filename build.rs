@@ -0,0 +1,13 @@
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // protoc comes with the build dependencies rather than having to be installed
+    let mut config = tonic_prost_build::Config::new();
+    config.protoc_executable(protoc_bin_vendored::protoc_bin_path()?);
+
+    tonic_prost_build::configure().compile_with_config(
+        config,
+        &["proto/organization/v1/organization.proto"],
+        &["proto"],
+    )?;
+
+    Ok(())
+}
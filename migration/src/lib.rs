@@ -1,7 +1,21 @@
 pub use sea_orm_migration::prelude::*;
 
+mod portable;
+
 mod m20251119_070234_create_company_table;
 mod m20251119_070643_create_department_table;
+mod m20251203_021540_add_version_to_organization;
+mod m20251205_083012_add_soft_delete_to_organization;
+mod m20251210_040522_add_audit_columns_and_log;
+mod m20251215_062418_add_tenant_to_organization;
+mod m20251218_031205_create_outbox_table;
+mod m20251222_074533_create_webhook_tables;
+mod m20251229_020814_create_jobs_table;
+mod m20260106_031742_add_search_to_organization;
+mod m20260112_041530_create_event_log_table;
+mod m20260120_052814_deny_unscoped_tenant_rows;
+mod m20260121_034417_add_lease_to_jobs;
+mod m20260126_023051_add_created_at_index_to_event_log;
 
 pub struct Migrator;
 
@@ -11,6 +25,18 @@ impl MigratorTrait for Migrator {
         vec![
             Box::new(m20251119_070234_create_company_table::Migration),
             Box::new(m20251119_070643_create_department_table::Migration),
+            Box::new(m20251203_021540_add_version_to_organization::Migration),
+            Box::new(m20251205_083012_add_soft_delete_to_organization::Migration),
+            Box::new(m20251210_040522_add_audit_columns_and_log::Migration),
+            Box::new(m20251215_062418_add_tenant_to_organization::Migration),
+            Box::new(m20251218_031205_create_outbox_table::Migration),
+            Box::new(m20251222_074533_create_webhook_tables::Migration),
+            Box::new(m20251229_020814_create_jobs_table::Migration),
+            Box::new(m20260106_031742_add_search_to_organization::Migration),
+            Box::new(m20260112_041530_create_event_log_table::Migration),
+            Box::new(m20260120_052814_deny_unscoped_tenant_rows::Migration),
+            Box::new(m20260121_034417_add_lease_to_jobs::Migration),
+            Box::new(m20260126_023051_add_created_at_index_to_event_log::Migration),
         ]
     }
 }
@@ -1,4 +1,4 @@
-use sea_orm_migration::{prelude::*, schema::*};
+use sea_orm_migration::{prelude::*, schema::*, sea_orm::DatabaseBackend};
 
 #[derive(DeriveMigrationName)]
 pub struct Migration;
@@ -6,13 +6,20 @@ pub struct Migration;
 #[async_trait::async_trait]
 impl MigrationTrait for Migration {
     async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // SQLite can't drop the constraint once names are only unique among the rows that
+        // are not soft deleted, so the other backends wait for that index
+        let mut name = match manager.get_database_backend() {
+            DatabaseBackend::Postgres => string_len_uniq("name", 200),
+            _ => string_len("name", 200),
+        };
+
         manager
             .create_table(
                 Table::create()
                     .table("companies")
                     .if_not_exists()
                     .col(pk_uuid("id"))
-                    .col(string_len_uniq("name", 200).not_null())
+                    .col(name.not_null())
                     .to_owned(),
             )
             .await
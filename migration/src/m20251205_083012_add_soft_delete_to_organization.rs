@@ -0,0 +1,83 @@
+use sea_orm_migration::{prelude::*, schema::*, sea_orm::DatabaseBackend};
+
+use crate::portable::{active_unique_index, add_active_column, drop_active_column, postgres_only};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        for table in ["companies", "departments"] {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(table)
+                        .add_column(timestamp_with_time_zone_null("deleted_at"))
+                        .to_owned(),
+                )
+                .await?;
+
+            // names only have to be unique among the rows that are not soft deleted
+            postgres_only(
+                manager,
+                &format!(r#"ALTER TABLE "{table}" DROP CONSTRAINT IF EXISTS "{table}_name_key""#),
+            )
+            .await?;
+
+            add_active_column(manager, table).await?;
+
+            manager
+                .create_index(active_unique_index(manager, table, &["name"]))
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        for table in ["companies", "departments"] {
+            manager
+                .drop_index(
+                    Index::drop()
+                        .name(format!("idx_{table}_name_active"))
+                        .table(table)
+                        .to_owned(),
+                )
+                .await?;
+
+            drop_active_column(manager, table).await?;
+
+            if manager.get_database_backend() == DatabaseBackend::Postgres {
+                manager
+                    .get_connection()
+                    .execute_unprepared(&format!(
+                        r#"ALTER TABLE "{table}" ADD CONSTRAINT "{table}_name_key" UNIQUE ("name")"#
+                    ))
+                    .await?;
+            } else {
+                manager
+                    .create_index(
+                        Index::create()
+                            .name(format!("{table}_name_key"))
+                            .table(table)
+                            .col("name")
+                            .unique()
+                            .to_owned(),
+                    )
+                    .await?;
+            }
+
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(table)
+                        .drop_column("deleted_at")
+                        .to_owned(),
+                )
+                .await?;
+        }
+
+        Ok(())
+    }
+}
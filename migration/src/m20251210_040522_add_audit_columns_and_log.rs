@@ -0,0 +1,88 @@
+use sea_orm_migration::{prelude::*, schema::*, sea_orm::DatabaseBackend};
+
+const EPOCH: &str = "1970-01-01 00:00:00+00:00";
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // SQLite adds columns one at a time, and only with a constant default, which the
+        // repositories never rely on as they stamp the rows themselves
+        let stamped_at = |name: &'static str| match manager.get_database_backend() {
+            DatabaseBackend::Sqlite => timestamp_with_time_zone(name).default(EPOCH).to_owned(),
+            _ => timestamp_with_time_zone(name)
+                .default(Expr::current_timestamp())
+                .to_owned(),
+        };
+
+        for table in ["companies", "departments"] {
+            for column in [
+                stamped_at("created_at"),
+                stamped_at("updated_at"),
+                string_null("created_by"),
+                string_null("updated_by"),
+            ] {
+                manager
+                    .alter_table(Table::alter().table(table).add_column(column).to_owned())
+                    .await?;
+            }
+        }
+
+        manager
+            .create_table(
+                Table::create()
+                    .table("audit_log")
+                    .if_not_exists()
+                    .col(pk_uuid("id"))
+                    .col(string_len("entity", 50))
+                    .col(uuid("entity_id"))
+                    .col(string_len("action", 20))
+                    .col(string_null("actor"))
+                    .col(json_binary_null("before"))
+                    .col(json_binary_null("after"))
+                    .col(string_null("request_id"))
+                    .col(timestamp_with_time_zone("created_at").default(Expr::current_timestamp()))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_audit_log_entity")
+                    .table("audit_log")
+                    .col("entity")
+                    .col("entity_id")
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_audit_log_created_at")
+                    .table("audit_log")
+                    .col("created_at")
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table("audit_log").to_owned())
+            .await?;
+
+        for table in ["companies", "departments"] {
+            for column in ["created_at", "updated_at", "created_by", "updated_by"] {
+                manager
+                    .alter_table(Table::alter().table(table).drop_column(column).to_owned())
+                    .await?;
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,155 @@
+use sea_orm_migration::{prelude::*, schema::*, sea_orm::DatabaseBackend};
+
+use crate::portable::{active_unique_index, postgres_only};
+
+// rows that existed before multi-tenancy are handed to the default tenant
+const DEFAULT_TENANT_ID: &str = "00000000-0000-0000-0000-000000000000";
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // MySQL stores uuids as binary, with no room for the default; it takes existing
+        // rows without one, and SQLite only adds a not null column that has one
+        let tenant_id = match manager.get_database_backend() {
+            DatabaseBackend::MySql => uuid("tenant_id"),
+            _ => uuid("tenant_id").default(DEFAULT_TENANT_ID).to_owned(),
+        };
+
+        for table in ["companies", "departments"] {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(table)
+                        .add_column(tenant_id.clone())
+                        .to_owned(),
+                )
+                .await?;
+
+            postgres_only(
+                manager,
+                &format!(r#"ALTER TABLE "{table}" ALTER COLUMN "tenant_id" DROP DEFAULT"#),
+            )
+            .await?;
+
+            manager
+                .create_index(
+                    Index::create()
+                        .name(format!("idx_{table}_tenant_id"))
+                        .table(table)
+                        .col("tenant_id")
+                        .to_owned(),
+                )
+                .await?;
+
+            // the same name can be used by different tenants
+            manager
+                .drop_index(
+                    Index::drop()
+                        .name(format!("idx_{table}_name_active"))
+                        .table(table)
+                        .to_owned(),
+                )
+                .await?;
+
+            manager
+                .create_index(active_unique_index(manager, table, &["tenant_id", "name"]))
+                .await?;
+        }
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table("audit_log")
+                    .add_column(uuid_null("tenant_id"))
+                    .to_owned(),
+            )
+            .await?;
+
+        postgres_only(
+            manager,
+            &format!(r#"UPDATE "audit_log" SET "tenant_id" = '{DEFAULT_TENANT_ID}'"#),
+        )
+        .await?;
+
+        // Only enforced for roles that neither own the tables nor bypass RLS, and only
+        // when the application sets `app.tenant_id` (see DB_ROW_LEVEL_SECURITY). Tightened by
+        // m20260120_052814_deny_unscoped_tenant_rows.
+        for table in ["companies", "departments", "audit_log"] {
+            postgres_only(
+                manager,
+                &format!(
+                    r#"ALTER TABLE "{table}" ENABLE ROW LEVEL SECURITY;
+                    CREATE POLICY "{table}_tenant_isolation" ON "{table}"
+                        USING (
+                            coalesce(current_setting('app.tenant_id', true), '') = ''
+                            OR "tenant_id" = current_setting('app.tenant_id', true)::uuid
+                        )"#
+                ),
+            )
+            .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        for table in ["companies", "departments", "audit_log"] {
+            postgres_only(
+                manager,
+                &format!(
+                    r#"DROP POLICY IF EXISTS "{table}_tenant_isolation" ON "{table}";
+                    ALTER TABLE "{table}" DISABLE ROW LEVEL SECURITY"#
+                ),
+            )
+            .await?;
+        }
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table("audit_log")
+                    .drop_column("tenant_id")
+                    .to_owned(),
+            )
+            .await?;
+
+        for table in ["companies", "departments"] {
+            manager
+                .drop_index(
+                    Index::drop()
+                        .name(format!("idx_{table}_name_active"))
+                        .table(table)
+                        .to_owned(),
+                )
+                .await?;
+
+            manager
+                .create_index(active_unique_index(manager, table, &["name"]))
+                .await?;
+
+            // SQLite doesn't drop an indexed column, unlike the others
+            manager
+                .drop_index(
+                    Index::drop()
+                        .name(format!("idx_{table}_tenant_id"))
+                        .table(table)
+                        .to_owned(),
+                )
+                .await?;
+
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(table)
+                        .drop_column("tenant_id")
+                        .to_owned(),
+                )
+                .await?;
+        }
+
+        Ok(())
+    }
+}
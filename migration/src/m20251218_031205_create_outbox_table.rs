@@ -0,0 +1,49 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table("outbox")
+                    .if_not_exists()
+                    .col(pk_uuid("id"))
+                    .col(uuid_null("tenant_id"))
+                    .col(string_len("event_type", 100))
+                    .col(uuid("aggregate_id"))
+                    .col(json_binary("payload"))
+                    .col(string_len("status", 20).default("pending"))
+                    .col(integer("attempts").default(0))
+                    .col(
+                        timestamp_with_time_zone("next_attempt_at")
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(text_null("last_error"))
+                    .col(timestamp_with_time_zone("created_at").default(Expr::current_timestamp()))
+                    .col(timestamp_with_time_zone_null("published_at"))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_outbox_pending")
+                    .table("outbox")
+                    .col("next_attempt_at")
+                    .and_where(Expr::col("status").eq("pending"))
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table("outbox").to_owned())
+            .await
+    }
+}
@@ -0,0 +1,102 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table("webhook_subscriptions")
+                    .if_not_exists()
+                    .col(pk_uuid("id"))
+                    .col(uuid("tenant_id"))
+                    .col(string_len("url", 2048))
+                    .col(json_binary("events"))
+                    .col(string_len("secret", 128))
+                    .col(boolean("active").default(true))
+                    .col(timestamp_with_time_zone("created_at").default(Expr::current_timestamp()))
+                    .col(timestamp_with_time_zone("updated_at").default(Expr::current_timestamp()))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_webhook_subscriptions_tenant_id")
+                    .table("webhook_subscriptions")
+                    .col("tenant_id")
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table("webhook_deliveries")
+                    .if_not_exists()
+                    .col(pk_uuid("id"))
+                    .col(uuid("tenant_id"))
+                    .col(uuid("subscription_id"))
+                    .col(uuid("message_id"))
+                    .col(string_len("event_type", 100))
+                    .col(json_binary("payload"))
+                    .col(string_len("status", 20).default("pending"))
+                    .col(integer("attempts").default(0))
+                    .col(
+                        timestamp_with_time_zone("next_attempt_at")
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(integer_null("last_status_code"))
+                    .col(text_null("last_error"))
+                    .col(timestamp_with_time_zone("created_at").default(Expr::current_timestamp()))
+                    .col(timestamp_with_time_zone_null("delivered_at"))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_webhook_deliveries_subscription")
+                            .from("webhook_deliveries", "subscription_id")
+                            .to("webhook_subscriptions", "id")
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // an event redelivered by the outbox must not reach a subscriber twice
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_webhook_deliveries_message")
+                    .table("webhook_deliveries")
+                    .col("subscription_id")
+                    .col("message_id")
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_webhook_deliveries_pending")
+                    .table("webhook_deliveries")
+                    .col("next_attempt_at")
+                    .and_where(Expr::col("status").eq("pending"))
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table("webhook_deliveries").to_owned())
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table("webhook_subscriptions").to_owned())
+            .await
+    }
+}
@@ -0,0 +1,62 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table("jobs")
+                    .if_not_exists()
+                    .col(pk_uuid("id"))
+                    .col(uuid_null("tenant_id"))
+                    .col(string_len("name", 100))
+                    .col(string_len("queue", 50).default("default"))
+                    .col(json_binary("payload"))
+                    .col(string_len("status", 20).default("pending"))
+                    .col(integer("attempts").default(0))
+                    .col(integer("max_attempts").default(5))
+                    .col(timestamp_with_time_zone("run_at").default(Expr::current_timestamp()))
+                    .col(timestamp_with_time_zone_null("locked_at"))
+                    .col(string_len_null("dedupe_key", 200))
+                    .col(text_null("last_error"))
+                    .col(timestamp_with_time_zone("created_at").default(Expr::current_timestamp()))
+                    .col(timestamp_with_time_zone_null("finished_at"))
+                    .to_owned(),
+            )
+            .await?;
+
+        // scheduled jobs are enqueued by every running instance, only the first one wins
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_jobs_dedupe_key")
+                    .table("jobs")
+                    .col("dedupe_key")
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_jobs_pending")
+                    .table("jobs")
+                    .col("queue")
+                    .col("run_at")
+                    .and_where(Expr::col("status").eq("pending"))
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table("jobs").to_owned())
+            .await
+    }
+}
@@ -0,0 +1,52 @@
+use sea_orm_migration::{prelude::*, sea_orm::DatabaseBackend};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // the other backends search with LIKE on the name, see search_repo_impl
+        if manager.get_database_backend() != DatabaseBackend::Postgres {
+            return Ok(());
+        }
+
+        let db = manager.get_connection();
+
+        db.execute_unprepared("CREATE EXTENSION IF NOT EXISTS pg_trgm")
+            .await?;
+
+        // 'simple' rather than a language: names are not worth stemming
+        for table in ["companies", "departments"] {
+            db.execute_unprepared(&format!(
+                r#"ALTER TABLE "{table}" ADD COLUMN "search_vector" tsvector
+                    GENERATED ALWAYS AS (to_tsvector('simple', "name")) STORED;
+                CREATE INDEX "idx_{table}_search_vector" ON "{table}" USING GIN ("search_vector");
+                CREATE INDEX "idx_{table}_name_trgm" ON "{table}" USING GIN ("name" gin_trgm_ops)"#
+            ))
+            .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        if manager.get_database_backend() != DatabaseBackend::Postgres {
+            return Ok(());
+        }
+
+        let db = manager.get_connection();
+
+        // pg_trgm stays, other schemas may depend on it
+        for table in ["companies", "departments"] {
+            db.execute_unprepared(&format!(
+                r#"DROP INDEX IF EXISTS "idx_{table}_name_trgm";
+                DROP INDEX IF EXISTS "idx_{table}_search_vector";
+                ALTER TABLE "{table}" DROP COLUMN IF EXISTS "search_vector""#
+            ))
+            .await?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,58 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+use crate::portable::postgres_only;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table("event_log")
+                    .if_not_exists()
+                    // numbered as they are published, which is what the change feed resumes from
+                    .col(big_integer("seq").auto_increment().primary_key())
+                    .col(uuid_uniq("message_id"))
+                    .col(uuid_null("tenant_id"))
+                    .col(string_len("event_type", 100))
+                    .col(uuid("aggregate_id"))
+                    .col(json_binary("payload"))
+                    .col(timestamp_with_time_zone("created_at").default(Expr::current_timestamp()))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_event_log_tenant_seq")
+                    .table("event_log")
+                    .col("tenant_id")
+                    .col("seq")
+                    .to_owned(),
+            )
+            .await?;
+
+        postgres_only(
+            manager,
+            r#"ALTER TABLE "event_log" ENABLE ROW LEVEL SECURITY;
+            CREATE POLICY "event_log_tenant_isolation" ON "event_log"
+                USING (
+                    coalesce(current_setting('app.tenant_id', true), '') = ''
+                    OR "tenant_id" = current_setting('app.tenant_id', true)::uuid
+                )"#,
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table("event_log").to_owned())
+            .await
+    }
+}
@@ -0,0 +1,54 @@
+use sea_orm_migration::prelude::*;
+
+use crate::portable::postgres_only;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+const TABLES: [&str; 4] = ["companies", "departments", "audit_log", "event_log"];
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // A session that never set `app.tenant_id` sees nothing, `*` is how maintenance
+        // work (`TenantScope::All`) asks for every tenant.
+        for table in TABLES {
+            postgres_only(
+                manager,
+                &format!(
+                    r#"DROP POLICY IF EXISTS "{table}_tenant_isolation" ON "{table}";
+                    CREATE POLICY "{table}_tenant_isolation" ON "{table}"
+                        USING (
+                            CASE coalesce(current_setting('app.tenant_id', true), '')
+                                WHEN '' THEN false
+                                WHEN '*' THEN true
+                                ELSE "tenant_id" = current_setting('app.tenant_id', true)::uuid
+                            END
+                        )"#
+                ),
+            )
+            .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        for table in TABLES {
+            postgres_only(
+                manager,
+                &format!(
+                    r#"DROP POLICY IF EXISTS "{table}_tenant_isolation" ON "{table}";
+                    CREATE POLICY "{table}_tenant_isolation" ON "{table}"
+                        USING (
+                            coalesce(current_setting('app.tenant_id', true), '') = ''
+                            OR "tenant_id" = current_setting('app.tenant_id', true)::uuid
+                        )"#
+                ),
+            )
+            .await?;
+        }
+
+        Ok(())
+    }
+}
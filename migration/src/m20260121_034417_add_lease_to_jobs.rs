@@ -0,0 +1,31 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // one column at a time, SQLite adds no more
+        for column in [
+            uuid_null("locked_by"),
+            timestamp_with_time_zone_null("locked_until"),
+        ] {
+            manager
+                .alter_table(Table::alter().table("jobs").add_column(column).to_owned())
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        for column in ["locked_by", "locked_until"] {
+            manager
+                .alter_table(Table::alter().table("jobs").drop_column(column).to_owned())
+                .await?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,31 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // what the events past their retention are found with
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_event_log_created_at")
+                    .table("event_log")
+                    .col("created_at")
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx_event_log_created_at")
+                    .table("event_log")
+                    .to_owned(),
+            )
+            .await
+    }
+}
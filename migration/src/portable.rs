@@ -0,0 +1,73 @@
+use sea_orm_migration::{prelude::*, sea_orm::DatabaseBackend};
+
+/// For what only Postgres has, such as row-level security and full text search.
+pub(crate) async fn postgres_only(manager: &SchemaManager<'_>, sql: &str) -> Result<(), DbErr> {
+    if manager.get_database_backend() == DatabaseBackend::Postgres {
+        manager.get_connection().execute_unprepared(sql).await?;
+    }
+
+    Ok(())
+}
+
+/// A unique index over `cols` among the rows that are not soft deleted. MySQL has no
+/// partial indexes, so there the index also covers the `active` column added by
+/// `add_active_column`, which turns null along with `deleted_at`.
+pub(crate) fn active_unique_index(
+    manager: &SchemaManager<'_>,
+    table: &str,
+    cols: &[&str],
+) -> IndexCreateStatement {
+    let mut index = Index::create()
+        .name(format!("idx_{table}_name_active"))
+        .table(Alias::new(table))
+        .unique()
+        .to_owned();
+
+    for col in cols {
+        index.col(Alias::new(*col));
+    }
+
+    match manager.get_database_backend() {
+        DatabaseBackend::MySql => index.col(Alias::new("active")).to_owned(),
+        _ => index
+            .and_where(Expr::col(Alias::new("deleted_at")).is_null())
+            .to_owned(),
+    }
+}
+
+pub(crate) async fn add_active_column(
+    manager: &SchemaManager<'_>,
+    table: &str,
+) -> Result<(), DbErr> {
+    if manager.get_database_backend() != DatabaseBackend::MySql {
+        return Ok(());
+    }
+
+    manager
+        .get_connection()
+        .execute_unprepared(&format!(
+            "ALTER TABLE `{table}` ADD COLUMN `active` TINYINT \
+                AS (IF(`deleted_at` IS NULL, 1, NULL)) STORED"
+        ))
+        .await?;
+
+    Ok(())
+}
+
+pub(crate) async fn drop_active_column(
+    manager: &SchemaManager<'_>,
+    table: &str,
+) -> Result<(), DbErr> {
+    if manager.get_database_backend() != DatabaseBackend::MySql {
+        return Ok(());
+    }
+
+    manager
+        .alter_table(
+            Table::alter()
+                .table(Alias::new(table))
+                .drop_column(Alias::new("active"))
+                .to_owned(),
+        )
+        .await
+}
@@ -0,0 +1,40 @@
+use async_trait::async_trait;
+
+use crate::{
+    application::{
+        SecureCase,
+        dtos::audit::{ReqQueryAuditLogDto, ResAuditLogDto},
+        error::AppError,
+    },
+    define_case,
+    domain::audit::AuditLogCriteria,
+    presentation::{guards::UserInfo, middlewares::validator::QueryParams, response::CaseResponse},
+};
+
+define_case!(QueryAuditLogUseCase);
+
+#[async_trait]
+impl SecureCase for QueryAuditLogUseCase {
+    type Input = QueryParams<ReqQueryAuditLogDto>;
+    type Output = Vec<ResAuditLogDto>;
+
+    async fn execute(
+        self,
+        QueryParams(dto): QueryParams<ReqQueryAuditLogDto>,
+        user: UserInfo,
+    ) -> Result<CaseResponse<Vec<ResAuditLogDto>>, AppError> {
+        tracing::debug!("dto: {:?}", dto);
+
+        let provider = self.state.db_context.read_provider(user.tenant());
+        let repo = provider.audit_repo();
+
+        let logs = repo
+            .query(&AuditLogCriteria::from(&dto))
+            .await?
+            .into_iter()
+            .map(Into::into)
+            .collect();
+
+        Ok(CaseResponse::ok(logs))
+    }
+}
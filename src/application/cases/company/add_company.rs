@@ -1,10 +1,16 @@
 use async_trait::async_trait;
+use sea_orm::DatabaseTransaction;
+use uuid::Uuid;
 
 use crate::{
-    application::{SecureCase, dtos::company::ReqAddCompanyDto, error::AppError},
+    application::{
+        SecureCase, TransactionalCase, dtos::company::ReqAddCompanyDto, error::AppError,
+    },
     define_case,
-    domain::organization::repositories::CompanyRepository,
+    domain::{error::DomainError, events::DomainEvent, organization::Company},
+    infrastructure::db::RepositoryProvider,
     presentation::{guards::UserInfo, middlewares::validator::JsonParams, response::CaseResponse},
+    with_transaction,
 };
 
 define_case!(AddCompanyUseCase);
@@ -17,14 +23,39 @@ impl SecureCase for AddCompanyUseCase {
     async fn execute(
         self,
         JsonParams(dto): JsonParams<ReqAddCompanyDto>,
-        _user: UserInfo,
+        user: UserInfo,
     ) -> Result<CaseResponse<String>, AppError> {
         tracing::debug!("dto: {:?}", dto);
 
-        let provider = self.state.db_context.provider();
-        let repo = provider.company_repo();
-        let id = repo.add(dto).await?;
+        let company = Company::try_from(dto)?;
 
-        Ok(CaseResponse::created(id.to_string()))
+        let id = with_transaction!(self.state.db_context, user.tenant(), provider => {
+            Self::run(provider, company).await
+        })?;
+
+        let location = format!("/api/v1/companies/{}", id);
+
+        Ok(CaseResponse::created(id.to_string()).with_location(&location))
+    }
+}
+
+#[async_trait]
+impl TransactionalCase for AddCompanyUseCase {
+    type Input = Company;
+    type Output = Uuid;
+
+    async fn run(
+        provider: &RepositoryProvider<'_, DatabaseTransaction>,
+        company: Company,
+    ) -> Result<Uuid, DomainError> {
+        let name = company.name.to_string();
+        let id = provider.company_repo().add(company).await?.into();
+
+        provider
+            .outbox_repo()
+            .add(DomainEvent::CompanyCreated { id, name })
+            .await?;
+
+        Ok(id)
     }
 }
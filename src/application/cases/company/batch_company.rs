@@ -0,0 +1,312 @@
+use async_trait::async_trait;
+use axum::http::StatusCode;
+use sea_orm::DatabaseTransaction;
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::{
+    application::{
+        SecureCase, TransactionalCase,
+        cases::company::{AddCompanyUseCase, DeleteCompanyUseCase, UpdateCompanyUseCase},
+        dtos::company::{
+            BatchMode, ReqAddCompanyDto, ReqBatchCompanyDto, ReqBatchDeleteCompanyDto,
+            ReqBatchUpdateCompanyDto, ReqCompanyOperationDto, ResBatchCompanyDto, ResBatchErrorDto,
+            ResBatchOperationDto,
+        },
+        error::{AppError, domain_error_parts},
+    },
+    define_case,
+    domain::{
+        error::DomainError,
+        events::DomainEvent,
+        organization::{Company, CompanyName},
+    },
+    infrastructure::db::RepositoryProvider,
+    presentation::{
+        guards::UserInfo,
+        middlewares::{conditional::IfMatch, validator::JsonParams},
+        response::CaseResponse,
+    },
+    with_savepoint, with_transaction,
+};
+
+type Provider<'a> = RepositoryProvider<'a, DatabaseTransaction>;
+
+define_case!(BatchCompanyUseCase);
+
+#[async_trait]
+impl SecureCase for BatchCompanyUseCase {
+    type Input = JsonParams<ReqBatchCompanyDto>;
+    type Output = ResBatchCompanyDto;
+
+    async fn execute(
+        self,
+        JsonParams(dto): JsonParams<ReqBatchCompanyDto>,
+        user: UserInfo,
+    ) -> Result<CaseResponse<ResBatchCompanyDto>, AppError> {
+        tracing::debug!("mode: {:?} operations: {}", dto.mode, dto.operations.len());
+
+        let mode = dto.mode;
+        let operations = dto.operations;
+
+        let report = with_transaction!(self.state.db_context, user.tenant(), provider => {
+            run(provider, mode, operations).await
+        })?;
+
+        let status = if report.committed {
+            StatusCode::OK
+        } else {
+            StatusCode::UNPROCESSABLE_ENTITY
+        };
+
+        Ok(CaseResponse::new(status, report))
+    }
+}
+
+async fn run(
+    provider: &Provider<'_>,
+    mode: BatchMode,
+    operations: Vec<ReqCompanyOperationDto>,
+) -> Result<ResBatchCompanyDto, DomainError> {
+    let total = operations.len();
+    let mut results = Vec::with_capacity(total);
+
+    let mut valid = Vec::with_capacity(total);
+    for (index, op) in operations.into_iter().enumerate() {
+        match op.validate() {
+            Ok(()) => valid.push((index, op)),
+            Err(e) => results.push(failed(
+                index,
+                StatusCode::BAD_REQUEST,
+                "INPUT_VALIDATE_FAIL",
+                &e.to_string(),
+            )),
+        }
+    }
+
+    if mode == BatchMode::Atomic && !results.is_empty() {
+        return Ok(report(mode, false, results, total));
+    }
+
+    // the whole batch is a savepoint so that an atomic one can be undone while still
+    // reporting what happened
+    let applied: Result<_, Rollback> = with_savepoint!(provider, batch => {
+        // consecutive creates go to the database as a single insert
+        let mut creates = Vec::new();
+        let mut operations = valid.into_iter().peekable();
+        while let Some((index, op)) = operations.next() {
+            match op {
+                ReqCompanyOperationDto::Create(dto) => {
+                    creates.push((index, dto));
+
+                    if !matches!(
+                        operations.peek(),
+                        Some((_, ReqCompanyOperationDto::Create(_)))
+                    ) {
+                        results.extend(create(batch, std::mem::take(&mut creates)).await?);
+                    }
+                }
+                ReqCompanyOperationDto::Update(dto) => {
+                    let result = with_savepoint!(batch, op => { update(op, dto).await });
+                    results.push(outcome(index, StatusCode::OK, result));
+                }
+                ReqCompanyOperationDto::Delete(dto) => {
+                    let result = with_savepoint!(batch, op => { delete(op, dto).await });
+                    results.push(outcome(index, StatusCode::NO_CONTENT, result));
+                }
+            }
+
+            if mode == BatchMode::Atomic && results.iter().any(|r| r.error.is_some()) {
+                return Err(Rollback::Failed(results));
+            }
+        }
+
+        Ok(results)
+    });
+
+    let (committed, results) = match applied {
+        Ok(results) => (true, results),
+        Err(Rollback::Failed(mut results)) => {
+            for result in results.iter_mut().filter(|r| r.error.is_none()) {
+                *result = not_applied(result.index);
+            }
+            (false, results)
+        }
+        Err(Rollback::Storage(e)) => return Err(e),
+    };
+
+    Ok(report(mode, committed, results, total))
+}
+
+/// Why the savepoint of a batch was rolled back.
+enum Rollback {
+    /// An operation of an atomic batch failed, with the results of those run so far.
+    Failed(Vec<ResBatchOperationDto>),
+    Storage(DomainError),
+}
+
+impl From<DomainError> for Rollback {
+    fn from(e: DomainError) -> Self {
+        Self::Storage(e)
+    }
+}
+
+/// Inserts the companies all at once, or one by one to tell which ones are failing
+/// when that does not work out.
+async fn create(
+    provider: &Provider<'_>,
+    creates: Vec<(usize, ReqAddCompanyDto)>,
+) -> Result<Vec<ResBatchOperationDto>, DomainError> {
+    let dtos: Vec<ReqAddCompanyDto> = creates.iter().map(|(_, dto)| dto.clone()).collect();
+
+    match with_savepoint!(provider, inner => { add(inner, dtos).await }) {
+        Ok(ids) => Ok(creates
+            .iter()
+            .zip(ids)
+            .map(|((index, _), id)| created(*index, id))
+            .collect()),
+        Err(e) if creates.len() == 1 => Ok(vec![failure(creates[0].0, &e)]),
+        Err(_) => {
+            let mut results = Vec::with_capacity(creates.len());
+            for (index, dto) in creates {
+                let result = with_savepoint!(provider, inner => {
+                    AddCompanyUseCase::run(inner, Company::try_from(dto)?).await
+                });
+
+                results.push(match result {
+                    Ok(id) => created(index, id),
+                    Err(e) => failure(index, &e),
+                });
+            }
+            Ok(results)
+        }
+    }
+}
+
+async fn add(
+    provider: &Provider<'_>,
+    dtos: Vec<ReqAddCompanyDto>,
+) -> Result<Vec<Uuid>, DomainError> {
+    let companies = dtos
+        .into_iter()
+        .map(Company::try_from)
+        .collect::<Result<Vec<_>, _>>()?;
+    let names: Vec<String> = companies.iter().map(|c| c.name.to_string()).collect();
+    let ids: Vec<Uuid> = provider
+        .company_repo()
+        .add_many(companies)
+        .await?
+        .into_iter()
+        .map(Uuid::from)
+        .collect();
+
+    provider
+        .outbox_repo()
+        .add_many(
+            ids.iter()
+                .zip(names)
+                .map(|(id, name)| DomainEvent::CompanyCreated { id: *id, name })
+                .collect(),
+        )
+        .await?;
+
+    Ok(ids)
+}
+
+async fn update(
+    provider: &Provider<'_>,
+    dto: ReqBatchUpdateCompanyDto,
+) -> Result<(Uuid, i32), DomainError> {
+    let name = CompanyName::new(dto.company.name)?;
+    let version =
+        UpdateCompanyUseCase::run(provider, (dto.id, IfMatch::version(dto.version), name)).await?;
+
+    Ok((dto.id, version))
+}
+
+async fn delete(
+    provider: &Provider<'_>,
+    dto: ReqBatchDeleteCompanyDto,
+) -> Result<(Uuid, i32), DomainError> {
+    DeleteCompanyUseCase::run(provider, (dto.id, IfMatch::version(dto.version))).await?;
+
+    Ok((dto.id, dto.version))
+}
+
+fn outcome(
+    index: usize,
+    status: StatusCode,
+    result: Result<(Uuid, i32), DomainError>,
+) -> ResBatchOperationDto {
+    match result {
+        Ok((id, version)) => ResBatchOperationDto {
+            index,
+            status: status.as_u16(),
+            id: Some(id),
+            // a deleted company has no version left to refer to
+            version: (status != StatusCode::NO_CONTENT).then_some(version),
+            error: None,
+        },
+        Err(e) => failure(index, &e),
+    }
+}
+
+fn created(index: usize, id: Uuid) -> ResBatchOperationDto {
+    ResBatchOperationDto {
+        index,
+        status: StatusCode::CREATED.as_u16(),
+        id: Some(id),
+        version: Some(1),
+        error: None,
+    }
+}
+
+fn failure(index: usize, e: &DomainError) -> ResBatchOperationDto {
+    let (status, code, message) = domain_error_parts(e);
+    failed(index, status, &code, &message)
+}
+
+fn not_applied(index: usize) -> ResBatchOperationDto {
+    failed(
+        index,
+        StatusCode::FAILED_DEPENDENCY,
+        "BATCH_ROLLED_BACK",
+        "not applied because another operation of the batch failed.",
+    )
+}
+
+fn failed(index: usize, status: StatusCode, code: &str, message: &str) -> ResBatchOperationDto {
+    ResBatchOperationDto {
+        index,
+        status: status.as_u16(),
+        id: None,
+        version: None,
+        error: Some(ResBatchErrorDto {
+            code: code.to_string(),
+            message: message.to_string(),
+        }),
+    }
+}
+
+/// Sorts the results back in the order of the operations, those an atomic batch never
+/// got to being reported as not applied.
+fn report(
+    mode: BatchMode,
+    committed: bool,
+    mut results: Vec<ResBatchOperationDto>,
+    total: usize,
+) -> ResBatchCompanyDto {
+    let done: Vec<usize> = results.iter().map(|r| r.index).collect();
+    results.extend(
+        (0..total)
+            .filter(|index| !done.contains(index))
+            .map(not_applied),
+    );
+    results.sort_by_key(|r| r.index);
+
+    ResBatchCompanyDto {
+        mode,
+        committed,
+        results,
+    }
+}
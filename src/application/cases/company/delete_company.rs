@@ -0,0 +1,74 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use sea_orm::DatabaseTransaction;
+use uuid::Uuid;
+
+use crate::{
+    application::{SecureCase, TransactionalCase, dtos::company::ReqCompanyIdDto, error::AppError},
+    define_case,
+    domain::{error::DomainError, events::DomainEvent},
+    infrastructure::db::RepositoryProvider,
+    presentation::{
+        guards::UserInfo,
+        middlewares::{
+            conditional::{IfMatch, IfMatchParams},
+            validator::PathParams,
+        },
+        response::CaseResponse,
+    },
+    with_transaction,
+};
+
+define_case!(DeleteCompanyUseCase);
+
+#[async_trait]
+impl SecureCase for DeleteCompanyUseCase {
+    type Input = IfMatchParams<PathParams<ReqCompanyIdDto>>;
+    type Output = ();
+
+    async fn execute(
+        self,
+        IfMatchParams { if_match, params }: Self::Input,
+        user: UserInfo,
+    ) -> Result<CaseResponse<()>, AppError> {
+        let PathParams(dto) = params;
+        tracing::debug!("id: {:?} if-match: {:?}", dto.id, if_match);
+
+        with_transaction!(self.state.db_context, user.tenant(), provider => {
+            Self::run(provider, (dto.id, if_match)).await
+        })?;
+
+        Ok(CaseResponse::<()>::no_content())
+    }
+}
+
+#[async_trait]
+impl TransactionalCase for DeleteCompanyUseCase {
+    /// The company and the version it is expected at.
+    type Input = (Uuid, IfMatch);
+    type Output = ();
+
+    async fn run(
+        provider: &RepositoryProvider<'_, DatabaseTransaction>,
+        (id, if_match): Self::Input,
+    ) -> Result<(), DomainError> {
+        let now = Utc::now();
+
+        let repo = provider.company_repo();
+        let version = if_match
+            .resolve(async { Ok(repo.find(id.into()).await?.map(|c| c.version)) })
+            .await?;
+        repo.delete(id.into(), version, now).await?;
+        provider
+            .department_repo()
+            .delete_by_company(id.into(), now)
+            .await?;
+
+        provider
+            .outbox_repo()
+            .add(DomainEvent::CompanyDeleted { id })
+            .await?;
+
+        Ok(())
+    }
+}
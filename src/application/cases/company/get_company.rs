@@ -0,0 +1,39 @@
+use async_trait::async_trait;
+
+use crate::{
+    application::{
+        SecureCase,
+        dtos::company::{ReqCompanyIdDto, ResGetCompanyDto},
+        error::AppError,
+    },
+    define_case,
+    domain::error::DomainError,
+    presentation::{guards::UserInfo, middlewares::validator::PathParams, response::CaseResponse},
+};
+
+define_case!(GetCompanyUseCase);
+
+#[async_trait]
+impl SecureCase for GetCompanyUseCase {
+    type Input = PathParams<ReqCompanyIdDto>;
+    type Output = ResGetCompanyDto;
+
+    async fn execute(
+        self,
+        PathParams(dto): PathParams<ReqCompanyIdDto>,
+        user: UserInfo,
+    ) -> Result<CaseResponse<ResGetCompanyDto>, AppError> {
+        tracing::debug!("dto: {:?}", dto);
+
+        let provider = self.state.db_context.read_provider(user.tenant());
+        let repo = provider.company_repo();
+
+        let company = repo.find(dto.id.into()).await?.ok_or_else(|| {
+            DomainError::NotFound(format!("company with id: {} is not found", dto.id))
+        })?;
+
+        let version = company.version;
+
+        Ok(CaseResponse::ok(ResGetCompanyDto::from(company)).with_etag(version))
+    }
+}
@@ -1,5 +1,17 @@
-mod query_company;
 mod add_company;
+mod batch_company;
+mod delete_company;
+mod get_company;
+mod query_company;
+mod query_deleted_company;
+mod restore_company;
+mod update_company;
 
+pub use add_company::*;
+pub use batch_company::*;
+pub use delete_company::*;
+pub use get_company::*;
 pub use query_company::*;
-pub use add_company::*;
\ No newline at end of file
+pub use query_deleted_company::*;
+pub use restore_company::*;
+pub use update_company::*;
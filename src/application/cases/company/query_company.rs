@@ -7,7 +7,7 @@ use crate::{
         error::AppError,
     },
     define_case,
-    domain::organization::repositories::CompanyRepository,
+    domain::organization::CompanyCriteria,
     presentation::{guards::UserInfo, middlewares::validator::QueryParams, response::CaseResponse},
 };
 
@@ -21,15 +21,20 @@ impl SecureCase for QueryCompanyUseCase {
     async fn execute(
         self,
         QueryParams(dto): QueryParams<ReqQueryCompanyDto>,
-        _user: UserInfo,
+        user: UserInfo,
     ) -> Result<CaseResponse<Vec<ResQueryCompanyDto>>, AppError> {
-        tracing::debug!("dto: {:?} user: {:?}", dto, _user);
+        tracing::debug!("dto: {:?} user: {:?}", dto, user);
 
-        let provider = self.state.db_context.provider();
+        let provider = self.state.db_context.read_provider(user.tenant());
         let repo = provider.company_repo();
 
-        let companies = repo.query(&dto).await?;
+        let companies = repo.query(&CompanyCriteria::from(&dto)).await?;
 
-        Ok(CaseResponse::ok(companies))
+        Ok(CaseResponse::ok(
+            companies
+                .into_iter()
+                .map(ResQueryCompanyDto::from)
+                .collect(),
+        ))
     }
 }
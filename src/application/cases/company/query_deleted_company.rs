@@ -0,0 +1,33 @@
+use async_trait::async_trait;
+
+use crate::{
+    application::{SecureCase, dtos::company::ResDeletedCompanyDto, error::AppError},
+    define_case,
+    presentation::{guards::UserInfo, response::CaseResponse},
+};
+
+define_case!(QueryDeletedCompanyUseCase);
+
+#[async_trait]
+impl SecureCase for QueryDeletedCompanyUseCase {
+    type Input = ();
+    type Output = Vec<ResDeletedCompanyDto>;
+
+    async fn execute(
+        self,
+        _: Self::Input,
+        user: UserInfo,
+    ) -> Result<CaseResponse<Vec<ResDeletedCompanyDto>>, AppError> {
+        let provider = self.state.db_context.read_provider(user.tenant());
+        let repo = provider.company_repo();
+
+        let companies = repo.query_deleted().await?;
+
+        Ok(CaseResponse::ok(
+            companies
+                .into_iter()
+                .map(ResDeletedCompanyDto::from)
+                .collect(),
+        ))
+    }
+}
@@ -0,0 +1,36 @@
+use async_trait::async_trait;
+
+use crate::{
+    application::{SecureCase, dtos::company::ReqCompanyIdDto, error::AppError},
+    define_case,
+    domain::events::DomainEvent,
+    presentation::{guards::UserInfo, middlewares::validator::PathParams, response::CaseResponse},
+    with_transaction,
+};
+
+define_case!(RestoreCompanyUseCase);
+
+#[async_trait]
+impl SecureCase for RestoreCompanyUseCase {
+    type Input = PathParams<ReqCompanyIdDto>;
+    type Output = ();
+
+    async fn execute(
+        self,
+        PathParams(dto): PathParams<ReqCompanyIdDto>,
+        user: UserInfo,
+    ) -> Result<CaseResponse<()>, AppError> {
+        tracing::debug!("dto: {:?}", dto);
+
+        with_transaction!(self.state.db_context, user.tenant(), provider => {
+            let deleted_at = provider.company_repo().restore(dto.id.into()).await?;
+            provider.department_repo().restore_by_company(dto.id.into(), deleted_at).await?;
+
+            provider.outbox_repo().add(DomainEvent::CompanyRestored { id: dto.id }).await?;
+
+            Ok(())
+        })?;
+
+        Ok(CaseResponse::<()>::no_content())
+    }
+}
@@ -0,0 +1,74 @@
+use async_trait::async_trait;
+use sea_orm::DatabaseTransaction;
+use uuid::Uuid;
+
+use crate::{
+    application::{
+        SecureCase, TransactionalCase,
+        dtos::company::{ReqCompanyIdDto, ReqUpdateCompanyDto},
+        error::AppError,
+    },
+    define_case,
+    domain::{error::DomainError, events::DomainEvent, organization::CompanyName},
+    infrastructure::db::RepositoryProvider,
+    presentation::{
+        guards::UserInfo,
+        middlewares::{
+            conditional::{IfMatch, IfMatchParams},
+            validator::PathAndJsonParams,
+        },
+        response::CaseResponse,
+    },
+    with_transaction,
+};
+
+define_case!(UpdateCompanyUseCase);
+
+#[async_trait]
+impl SecureCase for UpdateCompanyUseCase {
+    type Input = IfMatchParams<PathAndJsonParams<ReqCompanyIdDto, ReqUpdateCompanyDto>>;
+    type Output = ();
+
+    async fn execute(
+        self,
+        IfMatchParams { if_match, params }: Self::Input,
+        user: UserInfo,
+    ) -> Result<CaseResponse<()>, AppError> {
+        let PathAndJsonParams { p, b } = params;
+        tracing::debug!("id: {:?} if-match: {:?} dto: {:?}", p.id, if_match, b);
+
+        let name = CompanyName::new(b.name)?;
+
+        let version = with_transaction!(self.state.db_context, user.tenant(), provider => {
+            Self::run(provider, (p.id, if_match, name)).await
+        })?;
+
+        Ok(CaseResponse::<()>::no_content().with_etag(version))
+    }
+}
+
+#[async_trait]
+impl TransactionalCase for UpdateCompanyUseCase {
+    /// The company, the version it is expected at and its new name.
+    type Input = (Uuid, IfMatch, CompanyName);
+    /// The version it is at after the update.
+    type Output = i32;
+
+    async fn run(
+        provider: &RepositoryProvider<'_, DatabaseTransaction>,
+        (id, if_match, name): Self::Input,
+    ) -> Result<i32, DomainError> {
+        let repo = provider.company_repo();
+        let version = if_match
+            .resolve(async { Ok(repo.find(id.into()).await?.map(|c| c.version)) })
+            .await?;
+        let version = repo.update(id.into(), version, name).await?;
+
+        provider
+            .outbox_repo()
+            .add(DomainEvent::CompanyUpdated { id, version })
+            .await?;
+
+        Ok(version)
+    }
+}
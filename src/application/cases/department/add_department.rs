@@ -1,12 +1,14 @@
 use async_trait::async_trait;
+use sea_orm::DatabaseTransaction;
+use uuid::Uuid;
 
 use crate::{
-    application::{SecureCase, dtos::department::ReqAddDepartmentDto, error::AppError},
-    define_case,
-    domain::{
-        error::DomainError,
-        organization::repositories::{CompanyRepository, DepartmentRepository},
+    application::{
+        SecureCase, TransactionalCase, dtos::department::ReqAddDepartmentDto, error::AppError,
     },
+    define_case,
+    domain::{error::DomainError, events::DomainEvent, organization::Department},
+    infrastructure::db::{RepositoryProvider, TxOptions},
     presentation::{guards::UserInfo, middlewares::validator::JsonParams, response::CaseResponse},
     with_transaction,
 };
@@ -21,26 +23,57 @@ impl SecureCase for AddDepartmentUseCase {
     async fn execute(
         self,
         JsonParams(dto): JsonParams<ReqAddDepartmentDto>,
-        _user: UserInfo,
+        user: UserInfo,
     ) -> Result<CaseResponse<String>, AppError> {
         tracing::debug!("dto: {:?}", dto);
 
-        let new_dep = with_transaction!(self.state.db_context, provider => {
+        let department = Department::try_from(dto)?;
 
-            let com_exists = provider.company_repo().exists(dto.company_id).await?;
+        let new_dep = with_transaction!(self.state.db_context, user.tenant(), TxOptions::serializable(), provider, clone(department) => {
+            Self::run(provider, department).await
+        })?
+        .to_string();
 
-            if !com_exists {
-                return Err(DomainError::DbError(sea_orm::DbErr::RecordNotFound(format!("company with id: {} is not found", dto.company_id))));
-            }
+        let location = format!("/api/v1/departments/{}", new_dep);
+
+        Ok(CaseResponse::created(new_dep).with_location(&location))
+    }
+}
+
+#[async_trait]
+impl TransactionalCase for AddDepartmentUseCase {
+    type Input = Department;
+    type Output = Uuid;
 
-            let dep_repo = provider.department_repo();
+    async fn run(
+        provider: &RepositoryProvider<'_, DatabaseTransaction>,
+        department: Department,
+    ) -> Result<Uuid, DomainError> {
+        let com_exists = provider
+            .company_repo()
+            .exists(department.company_id)
+            .await?;
 
-            let id = dep_repo.add(dto).await?;
+        if !com_exists {
+            return Err(DomainError::NotFound(format!(
+                "company with id: {} is not found",
+                department.company_id
+            )));
+        }
 
+        let company_id = department.company_id.into();
+        let name = department.name.to_string();
+        let id = provider.department_repo().add(department).await?.into();
 
-            Ok(id.to_string())
-        })?;
+        provider
+            .outbox_repo()
+            .add(DomainEvent::DepartmentCreated {
+                id,
+                company_id,
+                name,
+            })
+            .await?;
 
-        Ok(CaseResponse::created(new_dep))
+        Ok(id)
     }
 }
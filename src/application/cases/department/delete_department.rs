@@ -0,0 +1,43 @@
+use async_trait::async_trait;
+use chrono::Utc;
+
+use crate::{
+    application::{SecureCase, dtos::department::ReqDepartmentIdDto, error::AppError},
+    define_case,
+    domain::events::DomainEvent,
+    presentation::{
+        guards::UserInfo,
+        middlewares::{conditional::IfMatchParams, validator::PathParams},
+        response::CaseResponse,
+    },
+    with_transaction,
+};
+
+define_case!(DeleteDepartmentUseCase);
+
+#[async_trait]
+impl SecureCase for DeleteDepartmentUseCase {
+    type Input = IfMatchParams<PathParams<ReqDepartmentIdDto>>;
+    type Output = ();
+
+    async fn execute(
+        self,
+        IfMatchParams { if_match, params }: Self::Input,
+        user: UserInfo,
+    ) -> Result<CaseResponse<()>, AppError> {
+        let PathParams(dto) = params;
+        tracing::debug!("id: {:?} if-match: {:?}", dto.id, if_match);
+
+        with_transaction!(self.state.db_context, user.tenant(), provider => {
+            let repo = provider.department_repo();
+            let version = if_match
+                .resolve(async { Ok(repo.find(dto.id.into()).await?.map(|d| d.version)) })
+                .await?;
+            repo.delete(dto.id.into(), version, Utc::now()).await?;
+
+            provider.outbox_repo().add(DomainEvent::DepartmentDeleted { id: dto.id }).await
+        })?;
+
+        Ok(CaseResponse::<()>::no_content())
+    }
+}
@@ -0,0 +1,39 @@
+use async_trait::async_trait;
+
+use crate::{
+    application::{
+        SecureCase,
+        dtos::department::{ReqDepartmentIdDto, ResGetDepartmentDto},
+        error::AppError,
+    },
+    define_case,
+    domain::error::DomainError,
+    presentation::{guards::UserInfo, middlewares::validator::PathParams, response::CaseResponse},
+};
+
+define_case!(GetDepartmentUseCase);
+
+#[async_trait]
+impl SecureCase for GetDepartmentUseCase {
+    type Input = PathParams<ReqDepartmentIdDto>;
+    type Output = ResGetDepartmentDto;
+
+    async fn execute(
+        self,
+        PathParams(dto): PathParams<ReqDepartmentIdDto>,
+        user: UserInfo,
+    ) -> Result<CaseResponse<ResGetDepartmentDto>, AppError> {
+        tracing::debug!("dto: {:?}", dto);
+
+        let provider = self.state.db_context.read_provider(user.tenant());
+        let repo = provider.department_repo();
+
+        let department = repo.find(dto.id.into()).await?.ok_or_else(|| {
+            DomainError::NotFound(format!("department with id: {} is not found", dto.id))
+        })?;
+
+        let version = department.version;
+
+        Ok(CaseResponse::ok(ResGetDepartmentDto::from(department)).with_etag(version))
+    }
+}
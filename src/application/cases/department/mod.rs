@@ -1,2 +1,13 @@
 mod add_department;
+mod delete_department;
+mod get_department;
+mod query_deleted_department;
+mod restore_department;
+mod update_department;
+
 pub use add_department::*;
+pub use delete_department::*;
+pub use get_department::*;
+pub use query_deleted_department::*;
+pub use restore_department::*;
+pub use update_department::*;
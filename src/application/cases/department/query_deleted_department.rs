@@ -0,0 +1,33 @@
+use async_trait::async_trait;
+
+use crate::{
+    application::{SecureCase, dtos::department::ResDeletedDepartmentDto, error::AppError},
+    define_case,
+    presentation::{guards::UserInfo, response::CaseResponse},
+};
+
+define_case!(QueryDeletedDepartmentUseCase);
+
+#[async_trait]
+impl SecureCase for QueryDeletedDepartmentUseCase {
+    type Input = ();
+    type Output = Vec<ResDeletedDepartmentDto>;
+
+    async fn execute(
+        self,
+        _: Self::Input,
+        user: UserInfo,
+    ) -> Result<CaseResponse<Vec<ResDeletedDepartmentDto>>, AppError> {
+        let provider = self.state.db_context.read_provider(user.tenant());
+        let repo = provider.department_repo();
+
+        let departments = repo.query_deleted().await?;
+
+        Ok(CaseResponse::ok(
+            departments
+                .into_iter()
+                .map(ResDeletedDepartmentDto::from)
+                .collect(),
+        ))
+    }
+}
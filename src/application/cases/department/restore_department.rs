@@ -0,0 +1,40 @@
+use async_trait::async_trait;
+
+use crate::{
+    application::{SecureCase, dtos::department::ReqDepartmentIdDto, error::AppError},
+    define_case,
+    domain::{
+        error::{DomainError, ErrorKind},
+        events::DomainEvent,
+    },
+    presentation::{guards::UserInfo, middlewares::validator::PathParams, response::CaseResponse},
+    with_transaction,
+};
+
+define_case!(RestoreDepartmentUseCase);
+
+#[async_trait]
+impl SecureCase for RestoreDepartmentUseCase {
+    type Input = PathParams<ReqDepartmentIdDto>;
+    type Output = ();
+
+    async fn execute(
+        self,
+        PathParams(dto): PathParams<ReqDepartmentIdDto>,
+        user: UserInfo,
+    ) -> Result<CaseResponse<()>, AppError> {
+        tracing::debug!("dto: {:?}", dto);
+
+        with_transaction!(self.state.db_context, user.tenant(), provider => {
+            let company_id = provider.department_repo().restore(dto.id.into()).await?;
+
+            if !provider.company_repo().exists(company_id).await? {
+                return Err(DomainError::CaseError(ErrorKind::Conflict, "COMPANY_DELETED".to_string(), format!("company with id: {} must be restored first", company_id)));
+            }
+
+            provider.outbox_repo().add(DomainEvent::DepartmentRestored { id: dto.id }).await
+        })?;
+
+        Ok(CaseResponse::<()>::no_content())
+    }
+}
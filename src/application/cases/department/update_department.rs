@@ -0,0 +1,57 @@
+use async_trait::async_trait;
+
+use crate::{
+    application::{
+        SecureCase,
+        dtos::department::{ReqDepartmentIdDto, ReqUpdateDepartmentDto},
+        error::AppError,
+    },
+    define_case,
+    domain::{error::DomainError, events::DomainEvent, organization::DepartmentName},
+    presentation::{
+        guards::UserInfo,
+        middlewares::{conditional::IfMatchParams, validator::PathAndJsonParams},
+        response::CaseResponse,
+    },
+    with_transaction,
+};
+
+define_case!(UpdateDepartmentUseCase);
+
+#[async_trait]
+impl SecureCase for UpdateDepartmentUseCase {
+    type Input = IfMatchParams<PathAndJsonParams<ReqDepartmentIdDto, ReqUpdateDepartmentDto>>;
+    type Output = ();
+
+    async fn execute(
+        self,
+        IfMatchParams { if_match, params }: Self::Input,
+        user: UserInfo,
+    ) -> Result<CaseResponse<()>, AppError> {
+        let PathAndJsonParams { p, b } = params;
+        tracing::debug!("id: {:?} if-match: {:?} dto: {:?}", p.id, if_match, b);
+
+        let name = DepartmentName::new(b.name)?;
+
+        let version = with_transaction!(self.state.db_context, user.tenant(), provider => {
+
+            let com_exists = provider.company_repo().exists(b.company_id.into()).await?;
+
+            if !com_exists {
+                return Err(DomainError::NotFound(format!("company with id: {} is not found", b.company_id)));
+            }
+
+            let repo = provider.department_repo();
+            let version = if_match
+                .resolve(async { Ok(repo.find(p.id.into()).await?.map(|d| d.version)) })
+                .await?;
+            let version = repo.update(p.id.into(), version, name, b.company_id.into()).await?;
+
+            provider.outbox_repo().add(DomainEvent::DepartmentUpdated { id: p.id, version }).await?;
+
+            Ok(version)
+        })?;
+
+        Ok(CaseResponse::<()>::no_content().with_etag(version))
+    }
+}
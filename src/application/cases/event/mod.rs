@@ -0,0 +1,3 @@
+mod stream_events;
+
+pub use stream_events::*;
@@ -0,0 +1,233 @@
+use std::{collections::VecDeque, time::Duration};
+
+use async_trait::async_trait;
+use axum::{
+    body::Bytes,
+    http::{HeaderMap, HeaderValue},
+};
+use futures_util::stream;
+use tokio::{
+    sync::broadcast::{self, error::RecvError},
+    time::{Interval, MissedTickBehavior},
+};
+
+use crate::{
+    application::{
+        SecureCase,
+        dtos::event::{ReqEventFeedDto, ResEventLogDto},
+        error::AppError,
+    },
+    define_case,
+    domain::{
+        error::{DomainError, ErrorKind},
+        events::OutboxMessage,
+    },
+    infrastructure::db::TenantScope,
+    presentation::{
+        guards::UserInfo,
+        middlewares::validator::QueryParams,
+        response::{CaseResponse, RawBody},
+    },
+};
+
+pub const LAST_EVENT_ID_HEADER: &str = "last-event-id";
+
+const BATCH_SIZE: u64 = 100;
+
+// also how events published by other instances are picked up, and what keeps proxies
+// from closing an idle stream
+const POLL_INTERVAL: Duration = Duration::from_secs(15);
+
+define_case!(StreamEventsUseCase);
+
+#[async_trait]
+impl SecureCase for StreamEventsUseCase {
+    type Input = (HeaderMap, QueryParams<ReqEventFeedDto>);
+    type Output = RawBody;
+
+    async fn execute(
+        self,
+        (headers, QueryParams(dto)): Self::Input,
+        user: UserInfo,
+    ) -> Result<CaseResponse<RawBody>, AppError> {
+        let last_event_id = match headers.get(LAST_EVENT_ID_HEADER) {
+            Some(value) => Some(parse_last_event_id(value)?),
+            None => dto.last_event_id,
+        };
+        tracing::debug!("last_event_id: {:?} user: {:?}", last_event_id, user);
+
+        // subscribed first so that nothing logged in between is missed
+        let events = self.state.event_bus.subscribe();
+        let tenant = user.tenant();
+
+        let seq = match last_event_id {
+            Some(seq) => seq,
+            None => {
+                self.state
+                    .db_context
+                    .provider(tenant)
+                    .event_log_repo()
+                    .last_seq()
+                    .await?
+            }
+        };
+
+        // companies are only listed to admins, as on GET /companies
+        let companies = user.has_any_role(&["admin".to_string()]);
+
+        let feed = Feed::new(self.state, tenant, companies, events, seq);
+        let body = RawBody::stream("text/event-stream", feed.into_stream());
+
+        Ok(CaseResponse::ok(body).with_cache_control("no-cache"))
+    }
+}
+
+fn parse_last_event_id(value: &HeaderValue) -> Result<i64, DomainError> {
+    value
+        .to_str()
+        .ok()
+        .and_then(|v| v.trim().parse::<i64>().ok())
+        .filter(|seq| *seq >= 0)
+        .ok_or_else(|| {
+            DomainError::CaseError(
+                ErrorKind::BadRequest,
+                "INPUT_PARSE_FAIL".to_string(),
+                "Last-Event-ID must be the id of an event.".to_string(),
+            )
+        })
+}
+
+/// Reads the event log of a tenant after `seq`, whenever the bus tells something was
+/// published or the poll interval elapses. The log being committed in the order it is
+/// numbered, no event shows up behind `seq` once it was read past. The company events
+/// are skipped unless `companies` is set.
+struct Feed {
+    // keeps the bus open for as long as the stream is read
+    state: Arc<AppState>,
+    tenant: TenantScope,
+    companies: bool,
+    events: broadcast::Receiver<OutboxMessage>,
+    poll: Interval,
+    seq: i64,
+    // the last read filled a whole batch, so the log goes on after `seq`
+    behind: bool,
+    backlog: VecDeque<ResEventLogDto>,
+}
+
+enum Wake {
+    Published,
+    Poll,
+    Closed,
+}
+
+impl Feed {
+    fn new(
+        state: Arc<AppState>,
+        tenant: TenantScope,
+        companies: bool,
+        events: broadcast::Receiver<OutboxMessage>,
+        seq: i64,
+    ) -> Self {
+        let mut poll = tokio::time::interval(POLL_INTERVAL);
+        poll.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        Self {
+            state,
+            tenant,
+            companies,
+            events,
+            poll,
+            seq,
+            behind: false,
+            backlog: VecDeque::new(),
+        }
+    }
+
+    fn into_stream(self) -> impl futures_util::Stream<Item = Result<Bytes, axum::Error>> {
+        stream::unfold(self, |mut feed| async move {
+            let frame = feed.next_frame().await?;
+            Some((Ok(frame), feed))
+        })
+    }
+
+    /// The next event, or a comment keeping the stream alive when there was none for
+    /// a whole poll interval. Ends the stream when the log can't be read.
+    async fn next_frame(&mut self) -> Option<Bytes> {
+        loop {
+            if let Some(entry) = self.backlog.pop_front() {
+                return Some(event_frame(&entry));
+            }
+
+            // the first tick is immediate, which replays what was missed
+            let wake = if self.behind {
+                Wake::Published
+            } else {
+                tokio::select! {
+                    _ = self.poll.tick() => Wake::Poll,
+                    wake = wait_for_tenant(&mut self.events, self.tenant, self.companies) => wake,
+                }
+            };
+            if let Wake::Closed = wake {
+                return None;
+            }
+
+            let repo = self.state.db_context.provider(self.tenant);
+            match repo.event_log_repo().since(self.seq, BATCH_SIZE).await {
+                Ok(entries) => {
+                    self.behind = entries.len() as u64 == BATCH_SIZE;
+                    if let Some(last) = entries.last() {
+                        self.seq = last.seq;
+                    }
+
+                    let companies = self.companies;
+                    self.backlog.extend(
+                        entries
+                            .into_iter()
+                            .filter(|entry| companies || !is_company_event(&entry.event_type))
+                            .map(ResEventLogDto::from),
+                    );
+                    if self.backlog.is_empty() && !self.behind && matches!(wake, Wake::Poll) {
+                        return Some(Bytes::from_static(b": keep-alive\n\n"));
+                    }
+                }
+                Err(e) => {
+                    tracing::error!("failed to read the event log: {}", e);
+                    return None;
+                }
+            }
+        }
+    }
+}
+
+async fn wait_for_tenant(
+    events: &mut broadcast::Receiver<OutboxMessage>,
+    tenant: TenantScope,
+    companies: bool,
+) -> Wake {
+    loop {
+        match events.recv().await {
+            Ok(message)
+                if message.tenant_id == tenant.id()
+                    && (companies || !is_company_event(&message.event_type)) =>
+            {
+                return Wake::Published;
+            }
+            Ok(_) => continue,
+            // whatever was missed is in the log anyway
+            Err(RecvError::Lagged(_)) => return Wake::Published,
+            Err(RecvError::Closed) => return Wake::Closed,
+        }
+    }
+}
+
+fn is_company_event(event_type: &str) -> bool {
+    event_type.starts_with("company.")
+}
+
+fn event_frame(entry: &ResEventLogDto) -> Bytes {
+    let data = serde_json::to_string(entry).unwrap_or_default();
+    Bytes::from(format!(
+        "id: {}\nevent: {}\ndata: {}\n\n",
+        entry.seq, entry.event_type, data
+    ))
+}
@@ -0,0 +1,37 @@
+use async_trait::async_trait;
+
+use crate::{
+    application::{
+        SecureCase,
+        dtos::job::{ReqJobIdDto, ResJobDto},
+        error::AppError,
+    },
+    define_case,
+    domain::error::DomainError,
+    presentation::{guards::UserInfo, middlewares::validator::PathParams, response::CaseResponse},
+};
+
+define_case!(GetJobUseCase);
+
+#[async_trait]
+impl SecureCase for GetJobUseCase {
+    type Input = PathParams<ReqJobIdDto>;
+    type Output = ResJobDto;
+
+    async fn execute(
+        self,
+        PathParams(dto): PathParams<ReqJobIdDto>,
+        user: UserInfo,
+    ) -> Result<CaseResponse<ResJobDto>, AppError> {
+        tracing::debug!("dto: {:?}", dto);
+
+        let provider = self.state.db_context.provider(user.tenant());
+        let repo = provider.job_repo();
+
+        let job = repo.find(dto.id).await?.ok_or_else(|| {
+            DomainError::NotFound(format!("job with id: {} is not found", dto.id))
+        })?;
+
+        Ok(CaseResponse::ok(job.into()))
+    }
+}
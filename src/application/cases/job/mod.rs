@@ -0,0 +1,9 @@
+mod cancel_job;
+mod get_job;
+mod query_job;
+mod retry_job;
+
+pub use cancel_job::*;
+pub use get_job::*;
+pub use query_job::*;
+pub use retry_job::*;
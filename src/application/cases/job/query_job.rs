@@ -0,0 +1,40 @@
+use async_trait::async_trait;
+
+use crate::{
+    application::{
+        SecureCase,
+        dtos::job::{ReqQueryJobDto, ResJobDto},
+        error::AppError,
+    },
+    define_case,
+    domain::job::JobCriteria,
+    presentation::{guards::UserInfo, middlewares::validator::QueryParams, response::CaseResponse},
+};
+
+define_case!(QueryJobUseCase);
+
+#[async_trait]
+impl SecureCase for QueryJobUseCase {
+    type Input = QueryParams<ReqQueryJobDto>;
+    type Output = Vec<ResJobDto>;
+
+    async fn execute(
+        self,
+        QueryParams(dto): QueryParams<ReqQueryJobDto>,
+        user: UserInfo,
+    ) -> Result<CaseResponse<Vec<ResJobDto>>, AppError> {
+        tracing::debug!("dto: {:?}", dto);
+
+        let provider = self.state.db_context.provider(user.tenant());
+        let repo = provider.job_repo();
+
+        let jobs = repo
+            .query(&JobCriteria::from(&dto))
+            .await?
+            .into_iter()
+            .map(Into::into)
+            .collect();
+
+        Ok(CaseResponse::ok(jobs))
+    }
+}
@@ -0,0 +1,28 @@
+use async_trait::async_trait;
+
+use crate::{
+    application::{SecureCase, dtos::job::ReqJobIdDto, error::AppError},
+    define_case,
+    presentation::{guards::UserInfo, middlewares::validator::PathParams, response::CaseResponse},
+};
+
+define_case!(RetryJobUseCase);
+
+#[async_trait]
+impl SecureCase for RetryJobUseCase {
+    type Input = PathParams<ReqJobIdDto>;
+    type Output = ();
+
+    async fn execute(
+        self,
+        PathParams(dto): PathParams<ReqJobIdDto>,
+        user: UserInfo,
+    ) -> Result<CaseResponse<()>, AppError> {
+        tracing::debug!("dto: {:?}", dto);
+
+        let provider = self.state.db_context.provider(user.tenant());
+        provider.job_repo().retry(dto.id).await?;
+
+        Ok(CaseResponse::<()>::no_content())
+    }
+}
@@ -1,3 +1,8 @@
+pub mod audit;
 pub mod auth;
 pub mod company;
 pub mod department;
+pub mod event;
+pub mod job;
+pub mod organization;
+pub mod webhook;
@@ -0,0 +1,98 @@
+use std::time::Duration;
+
+use axum::body::Bytes;
+use chrono::Utc;
+use futures_util::StreamExt;
+use tokio::{sync::mpsc, time::timeout};
+use tokio_stream::wrappers::ReceiverStream;
+
+use crate::{
+    domain::{
+        error::{DomainError, ErrorKind},
+        organization::repositories::RowStream,
+    },
+    infrastructure::{
+        db::TxOptions,
+        helpers::export::{ExportEncoder, ExportFormat, ExportRow},
+    },
+    presentation::response::{CaseResponse, RawBody},
+};
+
+pub(super) type ChunkSender = mpsc::Sender<Result<Bytes, axum::Error>>;
+
+// how long a client may leave the body unread before the export gives up on it, not to
+// hold its transaction and connection any longer
+const SEND_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// The transaction the rows are read in, one snapshot for the export to be consistent.
+/// Never run again, since what was read the first time may have been sent already.
+pub(super) fn snapshot() -> TxOptions {
+    TxOptions::read_only().with_max_attempts(1)
+}
+
+/// Response whose body is fed by the export task through the returned sender.
+pub(super) fn attachment(name: &str, format: ExportFormat) -> (CaseResponse<RawBody>, ChunkSender) {
+    let (tx, rx) = mpsc::channel(8);
+
+    let filename = format!(
+        "{}-{}.{}",
+        name,
+        Utc::now().format("%Y%m%d"),
+        format.extension()
+    );
+    let body = RawBody::stream(format.content_type(), ReceiverStream::new(rx));
+
+    (CaseResponse::ok(body).with_attachment(&filename), tx)
+}
+
+/// Writes the rows as `T`, the DTO they are exported as.
+pub(super) async fn write_rows<R, T: ExportRow + From<R>>(
+    mut rows: RowStream<'_, R>,
+    format: ExportFormat,
+    sheet: &str,
+    tx: &ChunkSender,
+) -> Result<(), DomainError> {
+    let mut encoder = ExportEncoder::new::<T>(format, sheet).map_err(encoding_failed)?;
+
+    while let Some(row) = rows.next().await {
+        if let Some(chunk) = encoder.write(&T::from(row?)).map_err(encoding_failed)? {
+            // the client went away, no point in reading further
+            if !send(tx, chunk).await? {
+                return Ok(());
+            }
+        }
+    }
+
+    send(tx, encoder.finish().map_err(encoding_failed)?).await?;
+    Ok(())
+}
+
+/// Whether the client is still there to receive `chunk`, failing when it stopped
+/// reading for `SEND_TIMEOUT`.
+async fn send(tx: &ChunkSender, chunk: Bytes) -> Result<bool, DomainError> {
+    match timeout(SEND_TIMEOUT, tx.send(Ok(chunk))).await {
+        Ok(sent) => Ok(sent.is_ok()),
+        Err(_) => Err(DomainError::CaseError(
+            ErrorKind::Internal,
+            "EXPORT_STALLED".to_string(),
+            "the client stopped reading the export.".to_string(),
+        )),
+    }
+}
+
+/// Headers are long gone by the time an export fails, aborting the body is the only
+/// way left to tell the client.
+pub(super) async fn abort_on_error(tx: &ChunkSender, result: Result<(), DomainError>) {
+    if let Err(e) = result {
+        tracing::error!("export failed: {}", e);
+        let _ = timeout(SEND_TIMEOUT, tx.send(Err(axum::Error::new(e)))).await;
+    }
+}
+
+fn encoding_failed(e: anyhow::Error) -> DomainError {
+    DomainError::CaseError(
+        ErrorKind::Internal,
+        "EXPORT_FAILED".to_string(),
+        e.to_string(),
+    )
+}
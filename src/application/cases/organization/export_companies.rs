@@ -0,0 +1,60 @@
+use async_trait::async_trait;
+use axum::http::{HeaderMap, header};
+
+use crate::{
+    application::{
+        SecureCase,
+        dtos::organization::{ReqExportOrganizationDto, ResExportCompanyDto},
+        error::AppError,
+    },
+    define_case,
+    domain::organization::CompanyCriteria,
+    infrastructure::helpers::export::ExportFormat,
+    presentation::{
+        guards::UserInfo,
+        middlewares::validator::QueryParams,
+        response::{CaseResponse, RawBody},
+    },
+    with_transaction,
+};
+
+use super::export::{abort_on_error, attachment, snapshot, write_rows};
+
+define_case!(ExportCompaniesUseCase);
+
+#[async_trait]
+impl SecureCase for ExportCompaniesUseCase {
+    type Input = (HeaderMap, QueryParams<ReqExportOrganizationDto>);
+    type Output = RawBody;
+
+    async fn execute(
+        self,
+        (headers, QueryParams(dto)): Self::Input,
+        user: UserInfo,
+    ) -> Result<CaseResponse<RawBody>, AppError> {
+        tracing::debug!("dto: {:?}", dto);
+
+        let accept = headers.get(header::ACCEPT).and_then(|a| a.to_str().ok());
+        let format = ExportFormat::negotiate(dto.format, accept)?;
+
+        let (response, tx) = attachment("companies", format);
+
+        let db_context = self.state.db_context.clone();
+        let tenant = user.tenant();
+        let criteria = CompanyCriteria::from(&dto.filter);
+
+        tokio::spawn(async move {
+            let chunks = tx.clone();
+            let result = with_transaction!(db_context, tenant, snapshot(), provider, clone(criteria, chunks) => {
+                let repo = provider.export_repo();
+                let rows = repo.export_companies(&criteria).await?;
+
+                write_rows::<_, ResExportCompanyDto>(rows, format, "companies", &chunks).await
+            });
+
+            abort_on_error(&tx, result).await;
+        });
+
+        Ok(response)
+    }
+}
@@ -0,0 +1,345 @@
+use std::collections::{HashMap, HashSet};
+
+use async_trait::async_trait;
+use axum::http::StatusCode;
+use sea_orm::DatabaseTransaction;
+use uuid::Uuid;
+use validator::{Validate, ValidationErrors};
+
+use crate::{
+    application::{
+        SecureCase, TransactionalCase,
+        cases::{company::AddCompanyUseCase, department::AddDepartmentUseCase},
+        dtos::{
+            company::ReqAddCompanyDto,
+            department::ReqAddDepartmentDto,
+            organization::{
+                ImportMode, ReqImportOrganizationDto, ResImportCountDto, ResImportOrganizationDto,
+                ResImportRowErrorDto,
+            },
+        },
+        error::AppError,
+    },
+    define_case,
+    domain::{
+        error::{DomainError, ErrorKind},
+        organization::{Company, Department},
+    },
+    infrastructure::{
+        db::RepositoryProvider,
+        helpers::spreadsheet::{self, SheetRow},
+    },
+    presentation::{
+        guards::UserInfo,
+        middlewares::validator::{MultipartParams, UploadedFile},
+        response::CaseResponse,
+    },
+    with_savepoint, with_transaction,
+};
+
+const COMPANIES: &str = "companies";
+const DEPARTMENTS: &str = "departments";
+
+const MAX_ROWS: usize = 5000;
+
+define_case!(ImportOrganizationUseCase);
+
+#[async_trait]
+impl SecureCase for ImportOrganizationUseCase {
+    type Input = MultipartParams<ReqImportOrganizationDto>;
+    type Output = ResImportOrganizationDto;
+
+    async fn execute(
+        self,
+        MultipartParams(dto): MultipartParams<ReqImportOrganizationDto>,
+        user: UserInfo,
+    ) -> Result<CaseResponse<ResImportOrganizationDto>, AppError> {
+        tracing::debug!("mode: {:?}", dto.mode);
+
+        let mode = dto.mode;
+        let company_rows = read_file(COMPANIES, dto.companies.as_ref())?;
+        let department_rows = read_file(DEPARTMENTS, dto.departments.as_ref())?;
+
+        let report = with_transaction!(self.state.db_context, user.tenant(), provider => {
+            import(provider, mode, company_rows, department_rows).await
+        })?;
+
+        let status = if report.committed {
+            StatusCode::OK
+        } else {
+            StatusCode::UNPROCESSABLE_ENTITY
+        };
+
+        Ok(CaseResponse::new(status, report))
+    }
+}
+
+fn read_file(name: &str, file: Option<&UploadedFile>) -> Result<Vec<SheetRow>, DomainError> {
+    let Some(file) = file else {
+        return Ok(Vec::new());
+    };
+
+    let rows = spreadsheet::read_rows(&file.file_name, &file.data).map_err(|e| {
+        DomainError::CaseError(
+            ErrorKind::BadRequest,
+            "IMPORT_FILE_INVALID".to_string(),
+            format!("the {} file can't be read: {}", name, e),
+        )
+    })?;
+
+    if rows.len() > MAX_ROWS {
+        return Err(DomainError::CaseError(
+            ErrorKind::PayloadTooLarge,
+            "IMPORT_TOO_LARGE".to_string(),
+            format!("the {} file has more than {} rows", name, MAX_ROWS),
+        ));
+    }
+
+    Ok(rows)
+}
+
+struct NewDepartment {
+    row: usize,
+    dto: ReqAddDepartmentDto,
+    company: String,
+}
+
+async fn import(
+    provider: &RepositoryProvider<'_, DatabaseTransaction>,
+    mode: ImportMode,
+    company_rows: Vec<SheetRow>,
+    department_rows: Vec<SheetRow>,
+) -> Result<ResImportOrganizationDto, DomainError> {
+    let mut errors = Vec::new();
+
+    let mut companies = Vec::new();
+    let mut seen = HashSet::new();
+    for row in company_rows {
+        let dto = ReqAddCompanyDto {
+            name: row.get("name").to_string(),
+        };
+
+        if let Err(e) = dto.validate() {
+            errors.extend(validation_errors(COMPANIES, row.number, e));
+        } else if !seen.insert(dto.name.clone()) {
+            errors.push(row_error(
+                COMPANIES,
+                row.number,
+                Some("name"),
+                "name is duplicated in the file.",
+            ));
+        } else {
+            companies.push((row.number, dto));
+        }
+    }
+
+    let mut departments = Vec::new();
+    let mut seen = HashSet::new();
+    for row in department_rows {
+        let dto = ReqAddDepartmentDto {
+            name: row.get("name").to_string(),
+            // resolved from the company name once the companies are imported
+            company_id: Uuid::nil(),
+        };
+        let company = row.get("company").to_string();
+
+        if let Err(e) = dto.validate() {
+            errors.extend(validation_errors(DEPARTMENTS, row.number, e));
+        } else if company.is_empty() {
+            errors.push(row_error(
+                DEPARTMENTS,
+                row.number,
+                Some("company"),
+                "company is required.",
+            ));
+        } else if !seen.insert(dto.name.clone()) {
+            errors.push(row_error(
+                DEPARTMENTS,
+                row.number,
+                Some("name"),
+                "name is duplicated in the file.",
+            ));
+        } else {
+            departments.push(NewDepartment {
+                row: row.number,
+                dto,
+                company,
+            });
+        }
+    }
+
+    let company_names: Vec<String> = companies
+        .iter()
+        .map(|(_, dto)| dto.name.clone())
+        .chain(departments.iter().map(|d| d.company.clone()))
+        .collect::<HashSet<_>>()
+        .into_iter()
+        .collect();
+    let department_names: Vec<String> = departments.iter().map(|d| d.dto.name.clone()).collect();
+
+    let mut company_ids: HashMap<String, Uuid> = provider
+        .company_repo()
+        .find_ids_by_name(&company_names)
+        .await?
+        .into_iter()
+        .map(|(name, id)| (name, id.into()))
+        .collect();
+    let existing_departments = provider
+        .department_repo()
+        .find_ids_by_name(&department_names)
+        .await?;
+
+    companies.retain(|(row, dto)| {
+        let exists = company_ids.contains_key(&dto.name);
+        if exists {
+            errors.push(row_error(
+                COMPANIES,
+                *row,
+                Some("name"),
+                "company already exists.",
+            ));
+        }
+        !exists
+    });
+
+    let new_companies: HashSet<String> =
+        companies.iter().map(|(_, dto)| dto.name.clone()).collect();
+    departments.retain(|d| {
+        if existing_departments.contains_key(&d.dto.name) {
+            errors.push(row_error(
+                DEPARTMENTS,
+                d.row,
+                Some("name"),
+                "department already exists.",
+            ));
+            false
+        } else if !company_ids.contains_key(&d.company) && !new_companies.contains(&d.company) {
+            let message = format!("company {} is not found.", d.company);
+            errors.push(row_error(DEPARTMENTS, d.row, Some("company"), &message));
+            false
+        } else {
+            true
+        }
+    });
+
+    if mode == ImportMode::AllOrNothing && !errors.is_empty() {
+        return Ok(report(mode, false, 0, 0, errors));
+    }
+
+    let mut created_companies = 0;
+    for (row, dto) in companies {
+        let name = dto.name.clone();
+
+        let result = with_savepoint!(provider, inner => {
+            AddCompanyUseCase::run(inner, Company::try_from(dto)?).await
+        });
+
+        match result {
+            Ok(id) => {
+                company_ids.insert(name, id);
+                created_companies += 1;
+            }
+            Err(e) if mode == ImportMode::AllOrNothing => return Err(e),
+            Err(e) => errors.push(row_error(COMPANIES, row, None, &row_message(&e))),
+        }
+    }
+
+    let mut created_departments = 0;
+    for NewDepartment {
+        row,
+        mut dto,
+        company,
+    } in departments
+    {
+        // only missing when the company failed to import in best effort mode
+        let Some(company_id) = company_ids.get(&company) else {
+            let message = format!("company {} was not imported.", company);
+            errors.push(row_error(DEPARTMENTS, row, Some("company"), &message));
+            continue;
+        };
+        dto.company_id = *company_id;
+
+        let result = with_savepoint!(provider, inner => {
+            AddDepartmentUseCase::run(inner, Department::try_from(dto)?).await
+        });
+
+        match result {
+            Ok(_) => created_departments += 1,
+            Err(e) if mode == ImportMode::AllOrNothing => return Err(e),
+            Err(e) => errors.push(row_error(DEPARTMENTS, row, None, &row_message(&e))),
+        }
+    }
+
+    Ok(report(
+        mode,
+        true,
+        created_companies,
+        created_departments,
+        errors,
+    ))
+}
+
+fn row_message(e: &DomainError) -> String {
+    match e {
+        DomainError::Duplicated(_) => "name already exists.".to_string(),
+        _ => e.to_string(),
+    }
+}
+
+fn row_error(file: &str, row: usize, field: Option<&str>, message: &str) -> ResImportRowErrorDto {
+    ResImportRowErrorDto {
+        file: file.to_string(),
+        row,
+        field: field.map(str::to_string),
+        message: message.to_string(),
+    }
+}
+
+fn validation_errors(
+    file: &str,
+    row: usize,
+    errors: ValidationErrors,
+) -> Vec<ResImportRowErrorDto> {
+    errors
+        .field_errors()
+        .into_iter()
+        .flat_map(|(field, errors)| {
+            errors.iter().map(move |e| {
+                let message = e.message.as_deref().unwrap_or(&e.code);
+                row_error(file, row, Some(&field), message)
+            })
+        })
+        .collect()
+}
+
+fn report(
+    mode: ImportMode,
+    committed: bool,
+    created_companies: usize,
+    created_departments: usize,
+    errors: Vec<ResImportRowErrorDto>,
+) -> ResImportOrganizationDto {
+    // a row fails once however many of its fields are invalid
+    let failed = |file: &str| {
+        errors
+            .iter()
+            .filter(|e| e.file == file)
+            .map(|e| e.row)
+            .collect::<HashSet<_>>()
+            .len()
+    };
+
+    ResImportOrganizationDto {
+        mode,
+        committed,
+        companies: ResImportCountDto {
+            created: created_companies,
+            failed: failed(COMPANIES),
+        },
+        departments: ResImportCountDto {
+            created: created_departments,
+            failed: failed(DEPARTMENTS),
+        },
+        errors,
+    }
+}
@@ -0,0 +1,10 @@
+mod export;
+mod export_companies;
+mod export_departments;
+mod import_organization;
+mod search_organization;
+
+pub use export_companies::*;
+pub use export_departments::*;
+pub use import_organization::*;
+pub use search_organization::*;
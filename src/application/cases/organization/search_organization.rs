@@ -0,0 +1,48 @@
+use async_trait::async_trait;
+
+use crate::{
+    application::{
+        SecureCase,
+        dtos::organization::{ReqSearchOrganizationDto, ResSearchHitDto},
+        error::AppError,
+    },
+    define_case,
+    domain::organization::SearchKind,
+    presentation::{guards::UserInfo, middlewares::validator::QueryParams, response::CaseResponse},
+};
+
+const DEFAULT_LIMIT: u64 = 20;
+
+define_case!(SearchOrganizationUseCase);
+
+#[async_trait]
+impl SecureCase for SearchOrganizationUseCase {
+    type Input = QueryParams<ReqSearchOrganizationDto>;
+    type Output = Vec<ResSearchHitDto>;
+
+    async fn execute(
+        self,
+        QueryParams(dto): QueryParams<ReqSearchOrganizationDto>,
+        user: UserInfo,
+    ) -> Result<CaseResponse<Vec<ResSearchHitDto>>, AppError> {
+        tracing::debug!("dto: {:?}", dto);
+
+        // companies are only listed to admins, as on GET /companies
+        let kinds: &[SearchKind] = if user.has_any_role(&["admin".to_string()]) {
+            &[SearchKind::Company, SearchKind::Department]
+        } else {
+            &[SearchKind::Department]
+        };
+
+        let provider = self.state.db_context.read_provider(user.tenant());
+        let hits = provider
+            .search_repo()
+            .search(dto.q.trim(), kinds, dto.limit.unwrap_or(DEFAULT_LIMIT))
+            .await?
+            .into_iter()
+            .map(Into::into)
+            .collect();
+
+        Ok(CaseResponse::ok(hits))
+    }
+}
@@ -0,0 +1,50 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::{
+    application::{
+        SecureCase,
+        dtos::webhook::{ReqAddWebhookDto, ResAddWebhookDto},
+        error::AppError,
+    },
+    define_case,
+    presentation::{guards::UserInfo, middlewares::validator::JsonParams, response::CaseResponse},
+};
+
+define_case!(AddWebhookUseCase);
+
+#[async_trait]
+impl SecureCase for AddWebhookUseCase {
+    type Input = JsonParams<ReqAddWebhookDto>;
+    type Output = ResAddWebhookDto;
+
+    async fn execute(
+        self,
+        JsonParams(dto): JsonParams<ReqAddWebhookDto>,
+        user: UserInfo,
+    ) -> Result<CaseResponse<ResAddWebhookDto>, AppError> {
+        tracing::debug!("url: {} events: {:?}", dto.url, dto.events);
+
+        let hook = dto.into_webhook(generate_secret);
+        let secret = hook.secret.clone();
+
+        let provider = self.state.db_context.provider(user.tenant());
+        let id = provider.webhook_repo().add(hook).await?;
+
+        let location = format!("/api/v1/webhooks/{}", id);
+
+        Ok(CaseResponse::created(ResAddWebhookDto {
+            id: id.to_string(),
+            secret,
+        })
+        .with_location(&location))
+    }
+}
+
+fn generate_secret() -> String {
+    format!(
+        "whsec_{}{}",
+        Uuid::new_v4().simple(),
+        Uuid::new_v4().simple()
+    )
+}
@@ -0,0 +1,28 @@
+use async_trait::async_trait;
+
+use crate::{
+    application::{SecureCase, dtos::webhook::ReqWebhookIdDto, error::AppError},
+    define_case,
+    presentation::{guards::UserInfo, middlewares::validator::PathParams, response::CaseResponse},
+};
+
+define_case!(DeleteWebhookUseCase);
+
+#[async_trait]
+impl SecureCase for DeleteWebhookUseCase {
+    type Input = PathParams<ReqWebhookIdDto>;
+    type Output = ();
+
+    async fn execute(
+        self,
+        PathParams(dto): PathParams<ReqWebhookIdDto>,
+        user: UserInfo,
+    ) -> Result<CaseResponse<()>, AppError> {
+        tracing::debug!("dto: {:?}", dto);
+
+        let provider = self.state.db_context.provider(user.tenant());
+        provider.webhook_repo().delete(dto.id).await?;
+
+        Ok(CaseResponse::<()>::no_content())
+    }
+}
@@ -0,0 +1,37 @@
+use async_trait::async_trait;
+
+use crate::{
+    application::{
+        SecureCase,
+        dtos::webhook::{ReqWebhookIdDto, ResWebhookDto},
+        error::AppError,
+    },
+    define_case,
+    domain::error::DomainError,
+    presentation::{guards::UserInfo, middlewares::validator::PathParams, response::CaseResponse},
+};
+
+define_case!(GetWebhookUseCase);
+
+#[async_trait]
+impl SecureCase for GetWebhookUseCase {
+    type Input = PathParams<ReqWebhookIdDto>;
+    type Output = ResWebhookDto;
+
+    async fn execute(
+        self,
+        PathParams(dto): PathParams<ReqWebhookIdDto>,
+        user: UserInfo,
+    ) -> Result<CaseResponse<ResWebhookDto>, AppError> {
+        tracing::debug!("dto: {:?}", dto);
+
+        let provider = self.state.db_context.provider(user.tenant());
+        let repo = provider.webhook_repo();
+
+        let webhook = repo.find(dto.id).await?.ok_or_else(|| {
+            DomainError::NotFound(format!("webhook with id: {} is not found", dto.id))
+        })?;
+
+        Ok(CaseResponse::ok(webhook.into()))
+    }
+}
@@ -0,0 +1,13 @@
+mod add_webhook;
+mod delete_webhook;
+mod get_webhook;
+mod query_webhook;
+mod query_webhook_delivery;
+mod update_webhook;
+
+pub use add_webhook::*;
+pub use delete_webhook::*;
+pub use get_webhook::*;
+pub use query_webhook::*;
+pub use query_webhook_delivery::*;
+pub use update_webhook::*;
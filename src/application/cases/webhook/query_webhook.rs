@@ -0,0 +1,28 @@
+use async_trait::async_trait;
+
+use crate::{
+    application::{SecureCase, dtos::webhook::ResWebhookDto, error::AppError},
+    define_case,
+    presentation::{guards::UserInfo, response::CaseResponse},
+};
+
+define_case!(QueryWebhookUseCase);
+
+#[async_trait]
+impl SecureCase for QueryWebhookUseCase {
+    type Input = ();
+    type Output = Vec<ResWebhookDto>;
+
+    async fn execute(
+        self,
+        _: Self::Input,
+        user: UserInfo,
+    ) -> Result<CaseResponse<Vec<ResWebhookDto>>, AppError> {
+        let provider = self.state.db_context.provider(user.tenant());
+        let repo = provider.webhook_repo();
+
+        let webhooks = repo.query().await?.into_iter().map(Into::into).collect();
+
+        Ok(CaseResponse::ok(webhooks))
+    }
+}
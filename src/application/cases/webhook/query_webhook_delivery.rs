@@ -0,0 +1,48 @@
+use async_trait::async_trait;
+
+use crate::{
+    application::{
+        SecureCase,
+        dtos::webhook::{ReqQueryWebhookDeliveryDto, ReqWebhookIdDto, ResWebhookDeliveryDto},
+        error::AppError,
+    },
+    define_case,
+    domain::{error::DomainError, webhook::WebhookDeliveryCriteria},
+    presentation::{
+        guards::UserInfo, middlewares::validator::PathAndQueryParams, response::CaseResponse,
+    },
+};
+
+define_case!(QueryWebhookDeliveryUseCase);
+
+#[async_trait]
+impl SecureCase for QueryWebhookDeliveryUseCase {
+    type Input = PathAndQueryParams<ReqWebhookIdDto, ReqQueryWebhookDeliveryDto>;
+    type Output = Vec<ResWebhookDeliveryDto>;
+
+    async fn execute(
+        self,
+        PathAndQueryParams { p, q }: Self::Input,
+        user: UserInfo,
+    ) -> Result<CaseResponse<Vec<ResWebhookDeliveryDto>>, AppError> {
+        tracing::debug!("id: {:?} dto: {:?}", p.id, q);
+
+        let provider = self.state.db_context.provider(user.tenant());
+
+        if provider.webhook_repo().find(p.id).await?.is_none() {
+            return Err(
+                DomainError::NotFound(format!("webhook with id: {} is not found", p.id)).into(),
+            );
+        }
+
+        let deliveries = provider
+            .webhook_delivery_repo()
+            .query(p.id, &WebhookDeliveryCriteria::from(&q))
+            .await?
+            .into_iter()
+            .map(Into::into)
+            .collect();
+
+        Ok(CaseResponse::ok(deliveries))
+    }
+}
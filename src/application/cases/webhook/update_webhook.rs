@@ -0,0 +1,34 @@
+use async_trait::async_trait;
+
+use crate::{
+    application::{
+        SecureCase,
+        dtos::webhook::{ReqUpdateWebhookDto, ReqWebhookIdDto},
+        error::AppError,
+    },
+    define_case,
+    presentation::{
+        guards::UserInfo, middlewares::validator::PathAndJsonParams, response::CaseResponse,
+    },
+};
+
+define_case!(UpdateWebhookUseCase);
+
+#[async_trait]
+impl SecureCase for UpdateWebhookUseCase {
+    type Input = PathAndJsonParams<ReqWebhookIdDto, ReqUpdateWebhookDto>;
+    type Output = ();
+
+    async fn execute(
+        self,
+        PathAndJsonParams { p, b }: Self::Input,
+        user: UserInfo,
+    ) -> Result<CaseResponse<()>, AppError> {
+        tracing::debug!("id: {:?} dto: {:?}", p.id, b);
+
+        let provider = self.state.db_context.provider(user.tenant());
+        provider.webhook_repo().update(p.id, b.into()).await?;
+
+        Ok(CaseResponse::<()>::no_content())
+    }
+}
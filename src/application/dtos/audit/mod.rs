@@ -0,0 +1,3 @@
+mod query_audit_log;
+
+pub use query_audit_log::*;
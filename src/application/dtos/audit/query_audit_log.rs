@@ -0,0 +1,94 @@
+use chrono::{DateTime, FixedOffset};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::domain::audit::{AuditAction, AuditLog, AuditLogCriteria};
+
+/// `AuditAction` as the API reads and documents it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+#[schemars(rename = "AuditAction")]
+pub enum AuditActionDto {
+    Create,
+    Update,
+    Delete,
+    Restore,
+    Purge,
+}
+
+impl From<AuditActionDto> for AuditAction {
+    fn from(dto: AuditActionDto) -> Self {
+        match dto {
+            AuditActionDto::Create => Self::Create,
+            AuditActionDto::Update => Self::Update,
+            AuditActionDto::Delete => Self::Delete,
+            AuditActionDto::Restore => Self::Restore,
+            AuditActionDto::Purge => Self::Purge,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Validate, JsonSchema)]
+pub struct ReqQueryAuditLogDto {
+    pub entity: Option<String>,
+    pub entity_id: Option<Uuid>,
+    pub action: Option<AuditActionDto>,
+    pub actor: Option<String>,
+    pub request_id: Option<String>,
+    pub from: Option<DateTime<FixedOffset>>,
+    pub to: Option<DateTime<FixedOffset>>,
+    #[validate(range(min = 1, max = 500, message = "limit must be between 1 and 500."))]
+    pub limit: Option<u64>,
+    pub offset: Option<u64>,
+}
+
+impl From<&ReqQueryAuditLogDto> for AuditLogCriteria {
+    fn from(dto: &ReqQueryAuditLogDto) -> Self {
+        Self {
+            entity: dto.entity.clone(),
+            entity_id: dto.entity_id,
+            action: dto.action.map(Into::into),
+            actor: dto.actor.clone(),
+            request_id: dto.request_id.clone(),
+            from: dto.from,
+            to: dto.to,
+            limit: dto.limit,
+            offset: dto.offset,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct ResAuditLogDto {
+    #[schemars(with = "Uuid")]
+    pub id: String,
+    pub entity: String,
+    #[schemars(with = "Uuid")]
+    pub entity_id: String,
+    #[schemars(with = "AuditActionDto")]
+    pub action: String,
+    pub actor: Option<String>,
+    pub before: Option<Value>,
+    pub after: Option<Value>,
+    pub request_id: Option<String>,
+    pub created_at: DateTime<FixedOffset>,
+}
+
+impl From<AuditLog> for ResAuditLogDto {
+    fn from(a: AuditLog) -> Self {
+        Self {
+            id: a.id.to_string(),
+            entity: a.entity,
+            entity_id: a.entity_id.to_string(),
+            action: a.action,
+            actor: a.actor,
+            before: a.before,
+            after: a.after,
+            request_id: a.request_id,
+            created_at: a.created_at,
+        }
+    }
+}
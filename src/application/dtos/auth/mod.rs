@@ -1,2 +1,2 @@
 mod sign_in;
-pub use sign_in::*;
\ No newline at end of file
+pub use sign_in::*;
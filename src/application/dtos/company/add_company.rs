@@ -1,11 +1,13 @@
-use sea_orm::ActiveValue::Set;
+use schemars::JsonSchema;
 use serde::Deserialize;
-use uuid::Uuid;
 use validator::Validate;
 
-use crate::infrastructure::db::entities::companies;
+use crate::domain::{
+    error::DomainError,
+    organization::{Company, CompanyName},
+};
 
-#[derive(Debug, Deserialize, Validate, Clone)]
+#[derive(Debug, Deserialize, Validate, Clone, JsonSchema)]
 pub struct ReqAddCompanyDto {
     #[validate(length(
         min = 1,
@@ -15,11 +17,10 @@ pub struct ReqAddCompanyDto {
     pub name: String,
 }
 
-impl From<ReqAddCompanyDto> for companies::ActiveModel {
-    fn from(value: ReqAddCompanyDto) -> Self {
-        Self {
-            id: Set(Uuid::new_v4()),
-            name: Set(value.name),
-        }
+impl TryFrom<ReqAddCompanyDto> for Company {
+    type Error = DomainError;
+
+    fn try_from(value: ReqAddCompanyDto) -> Result<Self, Self::Error> {
+        Ok(Company::new(CompanyName::new(value.name)?))
     }
 }
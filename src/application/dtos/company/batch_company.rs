@@ -0,0 +1,99 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use validator::{Validate, ValidationError, ValidationErrors};
+
+use super::{ReqAddCompanyDto, ReqUpdateCompanyDto};
+
+pub const MAX_BATCH_OPERATIONS: usize = 500;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum BatchMode {
+    /// Nothing is kept unless every operation succeeds.
+    #[default]
+    Atomic,
+    /// Every operation is kept or rolled back on its own.
+    PerItem,
+}
+
+/// Operations are validated one by one when the batch runs, so that an invalid one is
+/// reported with its index rather than failing the whole request.
+#[derive(Debug, Deserialize, Validate, JsonSchema)]
+#[validate(schema(function = "validate_operation_count"))]
+pub struct ReqBatchCompanyDto {
+    #[serde(default)]
+    pub mode: BatchMode,
+    #[schemars(length(min = 1, max = MAX_BATCH_OPERATIONS))]
+    pub operations: Vec<ReqCompanyOperationDto>,
+}
+
+fn validate_operation_count(dto: &ReqBatchCompanyDto) -> Result<(), ValidationError> {
+    if dto.operations.is_empty() || dto.operations.len() > MAX_BATCH_OPERATIONS {
+        return Err(ValidationError::new("operations").with_message(
+            format!("a batch has 1 to {} operations.", MAX_BATCH_OPERATIONS).into(),
+        ));
+    }
+    Ok(())
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+#[serde(tag = "op", rename_all = "snake_case")]
+pub enum ReqCompanyOperationDto {
+    Create(ReqAddCompanyDto),
+    Update(ReqBatchUpdateCompanyDto),
+    Delete(ReqBatchDeleteCompanyDto),
+}
+
+impl Validate for ReqCompanyOperationDto {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        match self {
+            Self::Create(dto) => dto.validate(),
+            Self::Update(dto) => dto.validate(),
+            Self::Delete(_) => Ok(()),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Validate, JsonSchema)]
+pub struct ReqBatchUpdateCompanyDto {
+    pub id: Uuid,
+    /// The version an `If-Match` header would carry for a single update.
+    pub version: i32,
+    #[serde(flatten)]
+    #[validate(nested)]
+    pub company: ReqUpdateCompanyDto,
+}
+
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct ReqBatchDeleteCompanyDto {
+    pub id: Uuid,
+    pub version: i32,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct ResBatchErrorDto {
+    pub code: String,
+    pub message: String,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct ResBatchOperationDto {
+    pub index: usize,
+    /// What the operation would have responded with on its own.
+    pub status: u16,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<Uuid>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<ResBatchErrorDto>,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct ResBatchCompanyDto {
+    pub mode: BatchMode,
+    /// Whether anything was written, false when an atomic batch was rolled back.
+    pub committed: bool,
+    pub results: Vec<ResBatchOperationDto>,
+}
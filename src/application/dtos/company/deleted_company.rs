@@ -0,0 +1,24 @@
+use chrono::{DateTime, FixedOffset};
+use schemars::JsonSchema;
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::domain::organization::Company;
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct ResDeletedCompanyDto {
+    #[schemars(with = "Uuid")]
+    pub id: String,
+    pub name: String,
+    pub deleted_at: Option<DateTime<FixedOffset>>,
+}
+
+impl From<Company> for ResDeletedCompanyDto {
+    fn from(c: Company) -> Self {
+        Self {
+            id: c.id.to_string(),
+            name: c.name.into_inner(),
+            deleted_at: c.deleted_at.map(|at| at.fixed_offset()),
+        }
+    }
+}
@@ -0,0 +1,29 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::domain::organization::Company;
+
+#[derive(Debug, Deserialize, Validate, JsonSchema)]
+pub struct ReqCompanyIdDto {
+    pub id: Uuid,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct ResGetCompanyDto {
+    #[schemars(with = "Uuid")]
+    pub id: String,
+    pub name: String,
+    pub version: i32,
+}
+
+impl From<Company> for ResGetCompanyDto {
+    fn from(c: Company) -> Self {
+        Self {
+            id: c.id.to_string(),
+            name: c.name.into_inner(),
+            version: c.version,
+        }
+    }
+}
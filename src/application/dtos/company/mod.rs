@@ -1,5 +1,13 @@
 mod add_company;
+mod batch_company;
+mod deleted_company;
+mod get_company;
 mod query_company;
+mod update_company;
 
 pub use add_company::*;
+pub use batch_company::*;
+pub use deleted_company::*;
+pub use get_company::*;
 pub use query_company::*;
+pub use update_company::*;
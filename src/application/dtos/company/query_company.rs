@@ -1,24 +1,71 @@
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use uuid::Uuid;
 use validator::Validate;
 
-use crate::infrastructure::db::entities::companies;
+use crate::{
+    application::dtos::filter::{
+        FieldKind, FilterClause, FilterOp, FilterValue, Filterable, ListFilter,
+    },
+    domain::organization::{Company, CompanyCriteria},
+};
 
-#[derive(Debug, Deserialize, Validate)]
+#[derive(Debug, Default, Deserialize, Validate, JsonSchema)]
 pub struct ReqQueryCompanyDto {
+    /// Shorthand for `filter[name][ilike]`.
     pub name: Option<String>,
+    #[serde(flatten)]
+    #[validate(nested)]
+    pub filter: ListFilter<ReqQueryCompanyDto>,
 }
 
-#[derive(Serialize)]
+impl Filterable for ReqQueryCompanyDto {
+    const FILTER_FIELDS: &'static [(&'static str, FieldKind)] = &[
+        ("id", FieldKind::Uuid),
+        ("name", FieldKind::Text),
+        ("version", FieldKind::Integer),
+        ("created_at", FieldKind::DateTime),
+        ("updated_at", FieldKind::DateTime),
+        ("created_by", FieldKind::Text),
+        ("updated_by", FieldKind::Text),
+    ];
+}
+
+impl From<&ReqQueryCompanyDto> for CompanyCriteria {
+    fn from(dto: &ReqQueryCompanyDto) -> Self {
+        let name = dto
+            .name
+            .as_ref()
+            .filter(|name| !name.is_empty())
+            .map(|name| FilterClause {
+                field: "name",
+                op: FilterOp::Ilike,
+                values: vec![FilterValue::Text(name.clone())],
+            });
+
+        Self {
+            clauses: name
+                .into_iter()
+                .chain(dto.filter.clauses.iter().cloned())
+                .collect(),
+        }
+    }
+}
+
+#[derive(Serialize, JsonSchema)]
 pub struct ResQueryCompanyDto {
+    #[schemars(with = "Uuid")]
     pub id: String,
     pub name: String,
+    pub version: i32,
 }
 
-impl From<companies::Model> for ResQueryCompanyDto {
-    fn from(c: companies::Model) -> Self {
+impl From<Company> for ResQueryCompanyDto {
+    fn from(c: Company) -> Self {
         Self {
             id: c.id.to_string(),
-            name: c.name,
+            name: c.name.into_inner(),
+            version: c.version,
         }
     }
 }
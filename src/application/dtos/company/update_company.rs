@@ -0,0 +1,13 @@
+use schemars::JsonSchema;
+use serde::Deserialize;
+use validator::Validate;
+
+#[derive(Debug, Deserialize, Validate, Clone, JsonSchema)]
+pub struct ReqUpdateCompanyDto {
+    #[validate(length(
+        min = 1,
+        max = 200,
+        message = "company's is required and max 200 characters."
+    ))]
+    pub name: String,
+}
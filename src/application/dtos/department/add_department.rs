@@ -1,23 +1,27 @@
-use sea_orm::ActiveValue::Set;
+use schemars::JsonSchema;
 use serde::Deserialize;
 use uuid::Uuid;
 use validator::Validate;
 
-use crate::infrastructure::db::entities::departments;
+use crate::domain::{
+    error::DomainError,
+    organization::{Department, DepartmentName},
+};
 
-#[derive(Debug, Deserialize, Validate, Clone)]
+#[derive(Debug, Deserialize, Validate, Clone, JsonSchema)]
 pub struct ReqAddDepartmentDto {
     #[validate(length(min = 1, message = "add.department.name.required"))]
     pub name: String,
     pub company_id: Uuid,
 }
 
-impl From<ReqAddDepartmentDto> for departments::ActiveModel {
-    fn from(value: ReqAddDepartmentDto) -> Self {
-        Self {
-            id: Set(Uuid::new_v4()),
-            name: Set(value.name),
-            company_id: Set(value.company_id),
-        }
+impl TryFrom<ReqAddDepartmentDto> for Department {
+    type Error = DomainError;
+
+    fn try_from(value: ReqAddDepartmentDto) -> Result<Self, Self::Error> {
+        Ok(Department::new(
+            DepartmentName::new(value.name)?,
+            value.company_id.into(),
+        ))
     }
 }
@@ -0,0 +1,27 @@
+use chrono::{DateTime, FixedOffset};
+use schemars::JsonSchema;
+use serde::Serialize;
+use uuid::Uuid;
+
+use crate::domain::organization::Department;
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct ResDeletedDepartmentDto {
+    #[schemars(with = "Uuid")]
+    pub id: String,
+    pub name: String,
+    #[schemars(with = "Uuid")]
+    pub company_id: String,
+    pub deleted_at: Option<DateTime<FixedOffset>>,
+}
+
+impl From<Department> for ResDeletedDepartmentDto {
+    fn from(d: Department) -> Self {
+        Self {
+            id: d.id.to_string(),
+            name: d.name.into_inner(),
+            company_id: d.company_id.to_string(),
+            deleted_at: d.deleted_at.map(|at| at.fixed_offset()),
+        }
+    }
+}
@@ -0,0 +1,32 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::domain::organization::Department;
+
+#[derive(Debug, Deserialize, Validate, JsonSchema)]
+pub struct ReqDepartmentIdDto {
+    pub id: Uuid,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct ResGetDepartmentDto {
+    #[schemars(with = "Uuid")]
+    pub id: String,
+    pub name: String,
+    #[schemars(with = "Uuid")]
+    pub company_id: String,
+    pub version: i32,
+}
+
+impl From<Department> for ResGetDepartmentDto {
+    fn from(d: Department) -> Self {
+        Self {
+            id: d.id.to_string(),
+            name: d.name.into_inner(),
+            company_id: d.company_id.to_string(),
+            version: d.version,
+        }
+    }
+}
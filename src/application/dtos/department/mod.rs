@@ -1,3 +1,9 @@
 mod add_department;
+mod deleted_department;
+mod get_department;
+mod update_department;
 
-pub use add_department::*;
\ No newline at end of file
+pub use add_department::*;
+pub use deleted_department::*;
+pub use get_department::*;
+pub use update_department::*;
@@ -0,0 +1,11 @@
+use schemars::JsonSchema;
+use serde::Deserialize;
+use uuid::Uuid;
+use validator::Validate;
+
+#[derive(Debug, Deserialize, Validate, Clone, JsonSchema)]
+pub struct ReqUpdateDepartmentDto {
+    #[validate(length(min = 1, message = "update.department.name.required"))]
+    pub name: String,
+    pub company_id: Uuid,
+}
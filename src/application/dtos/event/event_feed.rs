@@ -0,0 +1,36 @@
+use chrono::{DateTime, FixedOffset};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::domain::events::LoggedEvent;
+
+#[derive(Debug, Default, Deserialize, Validate, JsonSchema)]
+pub struct ReqEventFeedDto {
+    /// For clients that can't send the `Last-Event-ID` header, which takes precedence.
+    #[validate(range(min = 0, message = "last_event_id must not be negative."))]
+    pub last_event_id: Option<i64>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ResEventLogDto {
+    pub seq: i64,
+    pub event_type: String,
+    pub aggregate_id: Uuid,
+    pub payload: Value,
+    pub created_at: DateTime<FixedOffset>,
+}
+
+impl From<LoggedEvent> for ResEventLogDto {
+    fn from(e: LoggedEvent) -> Self {
+        Self {
+            seq: e.seq,
+            event_type: e.event_type,
+            aggregate_id: e.aggregate_id,
+            payload: e.payload,
+            created_at: e.created_at,
+        }
+    }
+}
@@ -0,0 +1,3 @@
+mod event_feed;
+
+pub use event_feed::*;
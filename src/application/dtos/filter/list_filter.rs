@@ -0,0 +1,164 @@
+use std::{borrow::Cow, collections::BTreeMap, fmt, marker::PhantomData};
+
+use schemars::{JsonSchema, Schema, SchemaGenerator, json_schema};
+use serde::{Deserialize, Deserializer};
+use validator::{Validate, ValidationError, ValidationErrors};
+
+pub use crate::domain::filter::{FieldKind, FilterClause, FilterOp, FilterValue};
+
+/// The fields a list endpoint can be filtered by, which are column names of its entity.
+pub trait Filterable {
+    const FILTER_FIELDS: &'static [(&'static str, FieldKind)];
+}
+
+/// `filter[<field>][<op>]=<value>` parameters of a query string, `filter[<field>]=<value>`
+/// standing for `eq`. Other parameters are left alone, while a filter that can't be
+/// understood is reported when validating.
+pub struct ListFilter<T> {
+    pub clauses: Vec<FilterClause>,
+    errors: Vec<String>,
+    _fields: PhantomData<T>,
+}
+
+impl<T> ListFilter<T> {
+    pub fn is_empty(&self) -> bool {
+        self.clauses.is_empty()
+    }
+}
+
+impl<T> Default for ListFilter<T> {
+    fn default() -> Self {
+        Self {
+            clauses: Vec::new(),
+            errors: Vec::new(),
+            _fields: PhantomData,
+        }
+    }
+}
+
+impl<T> fmt::Debug for ListFilter<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ListFilter")
+            .field("clauses", &self.clauses)
+            .field("errors", &self.errors)
+            .finish()
+    }
+}
+
+impl<T: Filterable> ListFilter<T> {
+    fn add(&mut self, key: &str, value: &str) {
+        match clause::<T>(key, value) {
+            Ok(clause) => self.clauses.push(clause),
+            Err(e) => self.errors.push(format!("{}: {}", key, e)),
+        }
+    }
+}
+
+fn clause<T: Filterable>(key: &str, value: &str) -> Result<FilterClause, String> {
+    let path = key
+        .strip_prefix("filter[")
+        .and_then(|k| k.strip_suffix(']'))
+        .ok_or("expected filter[<field>][<op>].")?;
+
+    let (name, op) = match path.split_once("][") {
+        Some((name, op)) => (name, op),
+        None => (path, "eq"),
+    };
+
+    let &(field, kind) = T::FILTER_FIELDS
+        .iter()
+        .find(|(field, _)| *field == name)
+        .ok_or_else(|| {
+            let fields: Vec<&str> = T::FILTER_FIELDS.iter().map(|(f, _)| *f).collect();
+            format!("unknown field, expected one of {}.", fields.join(", "))
+        })?;
+
+    let op = FilterOp::parse(op)
+        .filter(|op| op.applies_to(kind))
+        .ok_or_else(|| format!("{} can't be filtered with {}.", field, op))?;
+
+    let values = match op {
+        FilterOp::IsNull => match value {
+            "true" => vec![FilterValue::Bool(true)],
+            "false" => vec![FilterValue::Bool(false)],
+            _ => return Err("is_null takes true or false.".to_string()),
+        },
+        FilterOp::In => value
+            .split(',')
+            .map(|v| FilterValue::parse(kind, v))
+            .collect::<Option<Vec<_>>>()
+            .filter(|values| !values.is_empty())
+            .ok_or_else(|| format!("{} is not a list of {:?} values.", value, kind))?,
+        _ => vec![
+            FilterValue::parse(kind, value)
+                .ok_or_else(|| format!("{} is not a {:?} value.", value, kind))?,
+        ],
+    };
+
+    Ok(FilterClause { field, op, values })
+}
+
+impl<'de, T: Filterable> Deserialize<'de> for ListFilter<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let params = BTreeMap::<String, String>::deserialize(deserializer)?;
+
+        let mut filter = Self::default();
+        for (key, value) in params.iter().filter(|(k, _)| k.starts_with("filter[")) {
+            filter.add(key, value);
+        }
+
+        Ok(filter)
+    }
+}
+
+/// Documented as a single `filter` deep object, the fields being those of `T`.
+impl<T: Filterable> JsonSchema for ListFilter<T> {
+    fn inline_schema() -> bool {
+        true
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        "ListFilter".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        let fields: Vec<&str> = T::FILTER_FIELDS.iter().map(|(f, _)| *f).collect();
+
+        json_schema!({
+            "type": "object",
+            "properties": {
+                "filter": {
+                    "type": "object",
+                    "additionalProperties": {
+                        "oneOf": [
+                            { "type": "string" },
+                            { "type": "object", "additionalProperties": { "type": "string" } },
+                        ],
+                    },
+                    "description": format!(
+                        "`filter[<field>][<op>]=<value>`, the op being one of {}. Fields are {}.",
+                        FilterOp::NAMES.join(", "),
+                        fields.join(", ")
+                    ),
+                },
+            },
+        })
+    }
+}
+
+impl<T> Validate for ListFilter<T> {
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        if self.errors.is_empty() {
+            return Ok(());
+        }
+
+        let mut errors = ValidationErrors::new();
+        for message in &self.errors {
+            errors.add(
+                "filter",
+                ValidationError::new("filter").with_message(message.clone().into()),
+            );
+        }
+        Err(errors)
+    }
+}
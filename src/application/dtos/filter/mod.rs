@@ -0,0 +1,3 @@
+mod list_filter;
+
+pub use list_filter::*;
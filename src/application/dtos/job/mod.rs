@@ -0,0 +1,3 @@
+mod query_job;
+
+pub use query_job::*;
@@ -0,0 +1,96 @@
+use chrono::{DateTime, FixedOffset};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::domain::job::{Job, JobCriteria, JobStatus};
+
+#[derive(Debug, Deserialize, Validate, JsonSchema)]
+pub struct ReqJobIdDto {
+    pub id: Uuid,
+}
+
+/// `JobStatus` as the API reads and documents it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+#[schemars(rename = "JobStatus")]
+pub enum JobStatusDto {
+    Pending,
+    Running,
+    Completed,
+    Failed,
+    Cancelled,
+}
+
+impl From<JobStatusDto> for JobStatus {
+    fn from(dto: JobStatusDto) -> Self {
+        match dto {
+            JobStatusDto::Pending => Self::Pending,
+            JobStatusDto::Running => Self::Running,
+            JobStatusDto::Completed => Self::Completed,
+            JobStatusDto::Failed => Self::Failed,
+            JobStatusDto::Cancelled => Self::Cancelled,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Validate, JsonSchema)]
+pub struct ReqQueryJobDto {
+    pub status: Option<JobStatusDto>,
+    pub name: Option<String>,
+    pub queue: Option<String>,
+    #[validate(range(min = 1, max = 500, message = "limit must be between 1 and 500."))]
+    pub limit: Option<u64>,
+    pub offset: Option<u64>,
+}
+
+impl From<&ReqQueryJobDto> for JobCriteria {
+    fn from(dto: &ReqQueryJobDto) -> Self {
+        Self {
+            status: dto.status.map(Into::into),
+            name: dto.name.clone(),
+            queue: dto.queue.clone(),
+            limit: dto.limit,
+            offset: dto.offset,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct ResJobDto {
+    #[schemars(with = "Uuid")]
+    pub id: String,
+    pub name: String,
+    pub queue: String,
+    pub payload: Value,
+    #[schemars(with = "JobStatusDto")]
+    pub status: String,
+    pub attempts: i32,
+    pub max_attempts: i32,
+    pub run_at: DateTime<FixedOffset>,
+    pub locked_at: Option<DateTime<FixedOffset>>,
+    pub last_error: Option<String>,
+    pub created_at: DateTime<FixedOffset>,
+    pub finished_at: Option<DateTime<FixedOffset>>,
+}
+
+impl From<Job> for ResJobDto {
+    fn from(j: Job) -> Self {
+        Self {
+            id: j.id.to_string(),
+            name: j.name,
+            queue: j.queue,
+            payload: j.payload,
+            status: j.status,
+            attempts: j.attempts,
+            max_attempts: j.max_attempts,
+            run_at: j.run_at,
+            locked_at: j.locked_at,
+            last_error: j.last_error,
+            created_at: j.created_at,
+            finished_at: j.finished_at,
+        }
+    }
+}
@@ -1,3 +1,9 @@
+pub mod audit;
 pub mod auth;
 pub mod company;
 pub mod department;
+pub mod event;
+pub mod filter;
+pub mod job;
+pub mod organization;
+pub mod webhook;
@@ -0,0 +1,107 @@
+use chrono::{DateTime, FixedOffset};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::{
+    application::dtos::company::ReqQueryCompanyDto,
+    domain::organization::{ExportedCompany, ExportedDepartment},
+    infrastructure::helpers::export::{ExportFormat, ExportRow, ExportValue},
+};
+
+/// Exports take the company filters, department exports applying them to the company
+/// each department belongs to.
+#[derive(Debug, Deserialize, Validate, JsonSchema)]
+pub struct ReqExportOrganizationDto {
+    #[serde(flatten)]
+    #[validate(nested)]
+    pub filter: ReqQueryCompanyDto,
+    /// Takes precedence over the `Accept` header.
+    pub format: Option<ExportFormat>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ResExportCompanyDto {
+    pub id: Uuid,
+    pub name: String,
+    /// Active departments, which is what headcount is tracked by for now.
+    pub department_count: i64,
+    pub created_at: DateTime<FixedOffset>,
+    pub updated_at: DateTime<FixedOffset>,
+}
+
+impl From<ExportedCompany> for ResExportCompanyDto {
+    fn from(c: ExportedCompany) -> Self {
+        Self {
+            id: c.id,
+            name: c.name,
+            department_count: c.department_count,
+            created_at: c.created_at,
+            updated_at: c.updated_at,
+        }
+    }
+}
+
+impl ExportRow for ResExportCompanyDto {
+    fn columns() -> &'static [&'static str] {
+        &["id", "name", "department_count", "created_at", "updated_at"]
+    }
+
+    fn values(&self) -> Vec<ExportValue> {
+        vec![
+            ExportValue::Text(self.id.to_string()),
+            ExportValue::Text(self.name.clone()),
+            ExportValue::Integer(self.department_count),
+            ExportValue::DateTime(self.created_at),
+            ExportValue::DateTime(self.updated_at),
+        ]
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct ResExportDepartmentDto {
+    pub id: Uuid,
+    pub name: String,
+    pub company_id: Uuid,
+    pub company_name: String,
+    pub created_at: DateTime<FixedOffset>,
+    pub updated_at: DateTime<FixedOffset>,
+}
+
+impl From<ExportedDepartment> for ResExportDepartmentDto {
+    fn from(d: ExportedDepartment) -> Self {
+        Self {
+            id: d.id,
+            name: d.name,
+            company_id: d.company_id,
+            company_name: d.company_name,
+            created_at: d.created_at,
+            updated_at: d.updated_at,
+        }
+    }
+}
+
+impl ExportRow for ResExportDepartmentDto {
+    fn columns() -> &'static [&'static str] {
+        &[
+            "id",
+            "name",
+            "company_id",
+            "company_name",
+            "created_at",
+            "updated_at",
+        ]
+    }
+
+    fn values(&self) -> Vec<ExportValue> {
+        vec![
+            ExportValue::Text(self.id.to_string()),
+            ExportValue::Text(self.name.clone()),
+            ExportValue::Text(self.company_id.to_string()),
+            ExportValue::Text(self.company_name.clone()),
+            ExportValue::DateTime(self.created_at),
+            ExportValue::DateTime(self.updated_at),
+        ]
+    }
+}
@@ -0,0 +1,89 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use validator::{Validate, ValidationError, ValidationErrors};
+
+use crate::{
+    application::error::AppError,
+    presentation::middlewares::validator::{MultipartForm, UploadedFile},
+};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ImportMode {
+    /// Nothing is imported unless every row is valid.
+    #[default]
+    AllOrNothing,
+    /// Valid rows are imported, the others are reported.
+    BestEffort,
+}
+
+/// Companies and departments to import, as CSV or workbook files. Departments refer to
+/// their company by name, be it one of the file or one that already exists.
+#[derive(Debug, Validate, JsonSchema)]
+#[validate(schema(function = "validate_has_file"))]
+#[schemars(extend("minProperties" = 1))]
+pub struct ReqImportOrganizationDto {
+    #[schemars(default)]
+    pub mode: ImportMode,
+    pub companies: Option<UploadedFile>,
+    pub departments: Option<UploadedFile>,
+}
+
+fn validate_has_file(dto: &ReqImportOrganizationDto) -> Result<(), ValidationError> {
+    if dto.companies.is_none() && dto.departments.is_none() {
+        return Err(ValidationError::new("file")
+            .with_message("a companies or departments file is required.".into()));
+    }
+    Ok(())
+}
+
+impl TryFrom<MultipartForm> for ReqImportOrganizationDto {
+    type Error = AppError;
+
+    fn try_from(mut form: MultipartForm) -> Result<Self, Self::Error> {
+        let mode = match form.fields.remove("mode") {
+            Some(mode) => serde_json::from_value(Value::String(mode)).map_err(|_| {
+                let mut errors = ValidationErrors::new();
+                errors.add(
+                    "mode",
+                    ValidationError::new("mode")
+                        .with_message("mode must be all_or_nothing or best_effort.".into()),
+                );
+                errors
+            })?,
+            None => ImportMode::default(),
+        };
+
+        Ok(Self {
+            mode,
+            companies: form.files.remove("companies"),
+            departments: form.files.remove("departments"),
+        })
+    }
+}
+
+#[derive(Debug, Default, Serialize, JsonSchema)]
+pub struct ResImportCountDto {
+    pub created: usize,
+    pub failed: usize,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct ResImportRowErrorDto {
+    /// `companies` or `departments`.
+    pub file: String,
+    pub row: usize,
+    pub field: Option<String>,
+    pub message: String,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct ResImportOrganizationDto {
+    pub mode: ImportMode,
+    /// Whether anything was written, false when an all-or-nothing import was rejected.
+    pub committed: bool,
+    pub companies: ResImportCountDto,
+    pub departments: ResImportCountDto,
+    pub errors: Vec<ResImportRowErrorDto>,
+}
@@ -0,0 +1,7 @@
+mod export_organization;
+mod import_organization;
+mod search_organization;
+
+pub use export_organization::*;
+pub use import_organization::*;
+pub use search_organization::*;
@@ -0,0 +1,63 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::domain::organization::{SearchHit, SearchKind};
+
+#[derive(Debug, Deserialize, Validate, JsonSchema)]
+pub struct ReqSearchOrganizationDto {
+    #[validate(length(min = 1, max = 200, message = "q is required and max 200 characters."))]
+    pub q: String,
+    #[validate(range(min = 1, max = 100, message = "limit must be between 1 and 100."))]
+    pub limit: Option<u64>,
+}
+
+/// `SearchKind` as the API writes and documents it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+#[schemars(rename = "SearchKind")]
+pub enum SearchKindDto {
+    Company,
+    Department,
+}
+
+impl From<SearchKind> for SearchKindDto {
+    fn from(value: SearchKind) -> Self {
+        match value {
+            SearchKind::Company => Self::Company,
+            SearchKind::Department => Self::Department,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct ResSearchHitDto {
+    pub kind: SearchKindDto,
+    pub id: Uuid,
+    pub name: String,
+    /// The company of a department.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub company_id: Option<Uuid>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub company_name: Option<String>,
+    /// Higher is more relevant, only comparable within the same search.
+    pub score: f64,
+    /// `name` with the matched words wrapped in `<mark>` tags, unchanged when it only
+    /// matched approximately.
+    pub highlight: String,
+}
+
+impl From<SearchHit> for ResSearchHitDto {
+    fn from(h: SearchHit) -> Self {
+        Self {
+            kind: h.kind.into(),
+            id: h.id,
+            name: h.name,
+            company_id: h.company_id,
+            company_name: h.company_name,
+            score: h.score,
+            highlight: h.highlight,
+        }
+    }
+}
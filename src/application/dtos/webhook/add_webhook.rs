@@ -0,0 +1,82 @@
+use schemars::{JsonSchema, Schema, SchemaGenerator, json_schema};
+use serde::{Deserialize, Serialize};
+use url::Url;
+use uuid::Uuid;
+use validator::{Validate, ValidationError};
+
+use crate::domain::{
+    events::DomainEvent,
+    webhook::{NewWebhook, is_known_filter},
+};
+
+#[derive(Debug, Deserialize, Validate, Clone, JsonSchema)]
+pub struct ReqAddWebhookDto {
+    #[validate(custom(function = "validate_webhook_url"))]
+    #[schemars(url)]
+    pub url: String,
+    #[validate(custom(function = "validate_event_filters"))]
+    #[schemars(schema_with = "event_filters")]
+    pub events: Vec<String>,
+    /// Generated when missing.
+    #[validate(length(min = 16, max = 128, message = "secret must be 16 to 128 characters."))]
+    pub secret: Option<String>,
+}
+
+impl ReqAddWebhookDto {
+    /// The subscription to add, with `secret` when none was given.
+    pub fn into_webhook(self, secret: impl FnOnce() -> String) -> NewWebhook {
+        NewWebhook {
+            url: self.url,
+            events: self.events,
+            secret: self.secret.unwrap_or_else(secret),
+        }
+    }
+}
+
+/// The secret is only ever shown once, when the subscription is created.
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct ResAddWebhookDto {
+    #[schemars(with = "Uuid")]
+    pub id: String,
+    pub secret: String,
+}
+
+pub(super) fn validate_event_filters(events: &[String]) -> Result<(), ValidationError> {
+    if events.is_empty() {
+        return Err(
+            ValidationError::new("events").with_message("at least one event is required.".into())
+        );
+    }
+
+    match events.iter().find(|e| !is_known_filter(e)) {
+        Some(unknown) => Err(ValidationError::new("events")
+            .with_message(format!("unknown event: {}.", unknown).into())),
+        None => Ok(()),
+    }
+}
+
+/// What `validate_event_filters` accepts.
+pub(super) fn event_filters(_: &mut SchemaGenerator) -> Schema {
+    json_schema!({
+        "type": "array",
+        "minItems": 1,
+        "items": {
+            "type": "string",
+            "description": format!(
+                "One of {}, or a prefix ending with `*`.",
+                DomainEvent::TYPES.join(", ")
+            ),
+        },
+    })
+}
+
+/// Where the addresses may point is only known once resolved, which the delivery
+/// worker checks before every attempt.
+pub(super) fn validate_webhook_url(url: &str) -> Result<(), ValidationError> {
+    match Url::parse(url) {
+        Ok(url) if matches!(url.scheme(), "http" | "https") && url.has_host() => Ok(()),
+        _ => {
+            Err(ValidationError::new("url").with_message("url must be a valid http(s) url.".into()))
+        }
+    }
+}
@@ -0,0 +1,37 @@
+use chrono::{DateTime, FixedOffset};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::domain::webhook::Webhook;
+
+#[derive(Debug, Deserialize, Validate, JsonSchema)]
+pub struct ReqWebhookIdDto {
+    pub id: Uuid,
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct ResWebhookDto {
+    #[schemars(with = "Uuid")]
+    pub id: String,
+    #[schemars(url)]
+    pub url: String,
+    pub events: Vec<String>,
+    pub active: bool,
+    pub created_at: DateTime<FixedOffset>,
+    pub updated_at: DateTime<FixedOffset>,
+}
+
+impl From<Webhook> for ResWebhookDto {
+    fn from(w: Webhook) -> Self {
+        Self {
+            id: w.id.to_string(),
+            url: w.url,
+            events: w.events,
+            active: w.active,
+            created_at: w.created_at,
+            updated_at: w.updated_at,
+        }
+    }
+}
@@ -0,0 +1,82 @@
+use chrono::{DateTime, FixedOffset};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::domain::webhook::{WebhookDelivery, WebhookDeliveryCriteria, WebhookDeliveryStatus};
+
+/// `WebhookDeliveryStatus` as the API reads and documents it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+#[schemars(rename = "WebhookDeliveryStatus")]
+pub enum WebhookDeliveryStatusDto {
+    Pending,
+    Delivered,
+    Dead,
+}
+
+impl From<WebhookDeliveryStatusDto> for WebhookDeliveryStatus {
+    fn from(dto: WebhookDeliveryStatusDto) -> Self {
+        match dto {
+            WebhookDeliveryStatusDto::Pending => Self::Pending,
+            WebhookDeliveryStatusDto::Delivered => Self::Delivered,
+            WebhookDeliveryStatusDto::Dead => Self::Dead,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Validate, JsonSchema)]
+pub struct ReqQueryWebhookDeliveryDto {
+    pub status: Option<WebhookDeliveryStatusDto>,
+    #[validate(range(min = 1, max = 500, message = "limit must be between 1 and 500."))]
+    pub limit: Option<u64>,
+    pub offset: Option<u64>,
+}
+
+impl From<&ReqQueryWebhookDeliveryDto> for WebhookDeliveryCriteria {
+    fn from(dto: &ReqQueryWebhookDeliveryDto) -> Self {
+        Self {
+            status: dto.status.map(Into::into),
+            limit: dto.limit,
+            offset: dto.offset,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, JsonSchema)]
+pub struct ResWebhookDeliveryDto {
+    #[schemars(with = "Uuid")]
+    pub id: String,
+    #[schemars(with = "Uuid")]
+    pub message_id: String,
+    pub event_type: String,
+    #[schemars(with = "WebhookDeliveryStatusDto")]
+    pub status: String,
+    pub attempts: i32,
+    pub next_attempt_at: DateTime<FixedOffset>,
+    pub last_status_code: Option<i32>,
+    pub last_error: Option<String>,
+    pub payload: Value,
+    pub created_at: DateTime<FixedOffset>,
+    pub delivered_at: Option<DateTime<FixedOffset>>,
+}
+
+impl From<WebhookDelivery> for ResWebhookDeliveryDto {
+    fn from(d: WebhookDelivery) -> Self {
+        Self {
+            id: d.id.to_string(),
+            message_id: d.message_id.to_string(),
+            event_type: d.event_type,
+            status: d.status,
+            attempts: d.attempts,
+            next_attempt_at: d.next_attempt_at,
+            last_status_code: d.last_status_code,
+            last_error: d.last_error,
+            payload: d.payload,
+            created_at: d.created_at,
+            delivered_at: d.delivered_at,
+        }
+    }
+}
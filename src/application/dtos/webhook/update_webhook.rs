@@ -0,0 +1,28 @@
+use schemars::JsonSchema;
+use serde::Deserialize;
+use validator::Validate;
+
+use crate::domain::webhook::WebhookChanges;
+
+use super::add_webhook::{event_filters, validate_event_filters, validate_webhook_url};
+
+#[derive(Debug, Deserialize, Validate, Clone, JsonSchema)]
+pub struct ReqUpdateWebhookDto {
+    #[validate(custom(function = "validate_webhook_url"))]
+    #[schemars(url)]
+    pub url: String,
+    #[validate(custom(function = "validate_event_filters"))]
+    #[schemars(schema_with = "event_filters")]
+    pub events: Vec<String>,
+    pub active: bool,
+}
+
+impl From<ReqUpdateWebhookDto> for WebhookChanges {
+    fn from(dto: ReqUpdateWebhookDto) -> Self {
+        Self {
+            url: dto.url,
+            events: dto.events,
+            active: dto.active,
+        }
+    }
+}
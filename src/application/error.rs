@@ -1,18 +1,22 @@
 use axum::{
     Json,
-    extract::rejection::{FormRejection, JsonRejection, PathRejection, QueryRejection},
-    http::StatusCode,
+    extract::{
+        multipart::{MultipartError, MultipartRejection},
+        rejection::{FormRejection, JsonRejection, PathRejection, QueryRejection},
+    },
+    http::{StatusCode, header},
     response::IntoResponse,
 };
-use sea_orm::DbErr;
+use schemars::JsonSchema;
 use serde::Serialize;
 use thiserror::Error;
 
-use crate::domain::error::DomainError;
+use crate::domain::error::{DomainError, ErrorKind};
 
-#[derive(Debug, Error, Serialize)]
+#[derive(Debug, Error, Serialize, JsonSchema)]
 #[error("[{code}]{message}")]
-struct ErrorData {
+pub(crate) struct ErrorData {
+    /// e.g. INPUT_VALIDATE_FAIL
     code: String,
     message: String,
 }
@@ -38,6 +42,10 @@ pub enum AppError {
     QueryRejection(#[from] QueryRejection),
     #[error(transparent)]
     JsonRejection(#[from] JsonRejection),
+    #[error(transparent)]
+    MultipartRejection(#[from] MultipartRejection),
+    #[error(transparent)]
+    MultipartError(#[from] MultipartError),
     #[error("Domain error: {0}")]
     Domain(#[from] DomainError),
     #[error(transparent)]
@@ -46,6 +54,11 @@ pub enum AppError {
     UnAuthorized(String),
     #[error("{0}")]
     Forbidden(String),
+    #[error("{0}")]
+    PreconditionRequired(String),
+    /// Seconds until the caller may try again.
+    #[error("too many requests, retry in {0} seconds.")]
+    TooManyRequests(u64),
 }
 
 impl IntoResponse for AppError {
@@ -54,7 +67,7 @@ impl IntoResponse for AppError {
 
         match self {
             Forbidden(c) => (
-                StatusCode::UNAUTHORIZED,
+                StatusCode::FORBIDDEN,
                 Json(ResponseBody::new(
                     StatusCode::FORBIDDEN,
                     ErrorData::new("FORBIDDEN", c.as_str()),
@@ -69,6 +82,23 @@ impl IntoResponse for AppError {
                 )),
             )
                 .into_response(),
+            PreconditionRequired(c) => (
+                StatusCode::PRECONDITION_REQUIRED,
+                Json(ResponseBody::new(
+                    StatusCode::PRECONDITION_REQUIRED,
+                    ErrorData::new("PRECONDITION_REQUIRED", c.as_str()),
+                )),
+            )
+                .into_response(),
+            TooManyRequests(retry_after) => (
+                StatusCode::TOO_MANY_REQUESTS,
+                [(header::RETRY_AFTER, retry_after.to_string())],
+                Json(ResponseBody::new(
+                    StatusCode::TOO_MANY_REQUESTS,
+                    ErrorData::new("TOO_MANY_REQUESTS", self.to_string().as_str()),
+                )),
+            )
+                .into_response(),
             ValidationError(_) => {
                 tracing::error!("{}", self);
                 (
@@ -81,7 +111,12 @@ impl IntoResponse for AppError {
                     .into_response()
             }
 
-            FormRejection(_) | PathRejection(_) | QueryRejection(_) | JsonRejection(_) => {
+            FormRejection(_)
+            | PathRejection(_)
+            | QueryRejection(_)
+            | JsonRejection(_)
+            | MultipartRejection(_)
+            | MultipartError(_) => {
                 tracing::error!("{}", self);
                 (
                     StatusCode::BAD_REQUEST,
@@ -95,40 +130,12 @@ impl IntoResponse for AppError {
 
             Domain(d) => {
                 tracing::error!("{}", d);
-                match d {
-                    DomainError::DbError(e) => {
-                        match e {
-                            DbErr::RecordNotFound(_) => (
-                                StatusCode::NOT_FOUND,
-                                Json(ResponseBody::new(
-                                    StatusCode::NOT_FOUND,
-                                    ErrorData::new("DATA_DUPPLICATED", e.to_string().as_str()),
-                                )),
-                            ),
-                            DbErr::RecordNotInserted => (
-                                StatusCode::CONFLICT,
-                                Json(ResponseBody::new(
-                                    StatusCode::CONFLICT,
-                                    ErrorData::new("DATA_DUPPLICATED", e.to_string().as_str()),
-                                )),
-                            ),
-                            _ => (
-                                StatusCode::INTERNAL_SERVER_ERROR,
-                                Json(ResponseBody::new(
-                                    StatusCode::INTERNAL_SERVER_ERROR,
-                                    ErrorData::new("DB_ERROR", e.to_string().as_str()),
-                                )),
-                            ),
-                        }
-                    }
-                    .into_response(),
-
-                    DomainError::CaseError(s, c, m) => (
-                        s,
-                        Json(ResponseBody::new(s, ErrorData::new(&c, m.as_str()))),
-                    )
-                        .into_response(),
-                }
+                let (status, code, message) = domain_error_parts(&d);
+                (
+                    status,
+                    Json(ResponseBody::new(status, ErrorData::new(&code, &message))),
+                )
+                    .into_response()
             }
 
             InternalError(_) => {
@@ -146,8 +153,39 @@ impl IntoResponse for AppError {
     }
 }
 
-#[derive(Debug, Clone, Serialize)]
-struct ResponseBody<T: Serialize> {
+/// Status, code and message a domain error is reported with, also used for the
+/// outcome of each operation of a batch.
+pub fn domain_error_parts(e: &DomainError) -> (StatusCode, String, String) {
+    let (status, code) = match e {
+        DomainError::Invalid(_) => (StatusCode::BAD_REQUEST, "INPUT_VALIDATE_FAIL"),
+        DomainError::NotFound(_) => (StatusCode::NOT_FOUND, "DATA_DUPPLICATED"),
+        DomainError::Duplicated(_) => (StatusCode::CONFLICT, "DATA_DUPPLICATED"),
+        DomainError::Storage(_) => (StatusCode::INTERNAL_SERVER_ERROR, "DB_ERROR"),
+        DomainError::CaseError(kind, code, message) => {
+            return (status_of(*kind), code.clone(), message.clone());
+        }
+    };
+    (status, code.to_string(), e.to_string())
+}
+
+fn status_of(kind: ErrorKind) -> StatusCode {
+    match kind {
+        ErrorKind::BadRequest => StatusCode::BAD_REQUEST,
+        ErrorKind::NotFound => StatusCode::NOT_FOUND,
+        ErrorKind::Conflict => StatusCode::CONFLICT,
+        ErrorKind::PreconditionFailed => StatusCode::PRECONDITION_FAILED,
+        ErrorKind::NotAcceptable => StatusCode::NOT_ACCEPTABLE,
+        ErrorKind::PayloadTooLarge => StatusCode::PAYLOAD_TOO_LARGE,
+        ErrorKind::Internal => StatusCode::INTERNAL_SERVER_ERROR,
+    }
+}
+
+/// The body every error is responded with.
+pub(crate) type ErrorResponse = ResponseBody<ErrorData>;
+
+#[derive(Debug, Clone, Serialize, JsonSchema)]
+#[schemars(rename = "ErrorResponse")]
+pub(crate) struct ResponseBody<T: Serialize> {
     status_code: u16,
     data: T,
 }
@@ -1,15 +1,20 @@
 use async_trait::async_trait;
-use serde::Serialize;
+use sea_orm::DatabaseTransaction;
 
 use crate::{
     application::error::AppError,
-    presentation::{guards::UserInfo, response::CaseResponse},
+    domain::error::DomainError,
+    infrastructure::db::RepositoryProvider,
+    presentation::{
+        guards::UserInfo,
+        response::{CaseResponse, IntoCaseBody},
+    },
 };
 
 #[async_trait]
 pub trait PublicCase {
     type Input;
-    type Output: Serialize;
+    type Output: IntoCaseBody;
 
     async fn execute(self, dto: Self::Input) -> Result<CaseResponse<Self::Output>, AppError>;
 }
@@ -17,7 +22,7 @@ pub trait PublicCase {
 #[async_trait]
 pub trait SecureCase {
     type Input;
-    type Output: Serialize;
+    type Output: IntoCaseBody;
 
     async fn execute(
         self,
@@ -26,6 +31,19 @@ pub trait SecureCase {
     ) -> Result<CaseResponse<Self::Output>, AppError>;
 }
 
+/// What a use case writes within its transaction, for another one to run within its own,
+/// typically in a savepoint with `with_savepoint!`.
+#[async_trait]
+pub trait TransactionalCase {
+    type Input: Send;
+    type Output: Send;
+
+    async fn run(
+        provider: &RepositoryProvider<'_, DatabaseTransaction>,
+        input: Self::Input,
+    ) -> Result<Self::Output, DomainError>;
+}
+
 #[macro_export]
 macro_rules! make_case {
     ($use_case:ty) => {
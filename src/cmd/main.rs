@@ -1,6 +1,19 @@
+use std::{env, sync::Arc, time::Duration};
+
+use anyhow::Context;
+
 use lib::{
     config,
-    infrastructure::db,
+    infrastructure::{
+        db::{self, DEFAULT_TENANT_ID, DbContext},
+        events::{EventBus, EventLogSink, WebhookSink, WebhookSubscriptionSink},
+        helpers::token::JwtHelper,
+        jobs::{
+            JobRegistry, JobScheduler, JobWorkerPool, OutboxDispatcher, PurgeDeletedRecords,
+            PurgeEventLog, WebhookDeliveryWorker,
+        },
+        seed::Dataset,
+    },
     presentation::{http::HttpServer, trace},
 };
 
@@ -10,9 +23,96 @@ async fn main() -> anyhow::Result<()> {
 
     trace::register();
 
-    let pool = db::init_db(&config.db_connect_str).await?;
+    let args: Vec<String> = env::args().skip(1).collect();
+    if args.first().map(String::as_str) == Some("token") {
+        let sub = args
+            .get(1)
+            .context("usage: rest_app token <subject> [role...]")?;
+        let token = JwtHelper::new(config.token_secret_key.clone()).generate_for_tenant(
+            sub.clone(),
+            Some(DEFAULT_TENANT_ID),
+            args[2..].to_vec(),
+        )?;
+        println!("{}", token);
+        return Ok(());
+    }
+
+    let pool = db::init_db(&config.db_connect_str, config.row_level_security).await?;
+
+    match args.first().map(String::as_str) {
+        None => {}
+        Some("seed") => {
+            let name = args.get(1).context("usage: rest_app seed <dataset>")?;
+            let dir = env::var("SEED_DIR").unwrap_or("fixtures".to_string());
+            let report = Dataset::load(&dir, name)?
+                .seed(&DbContext::new(pool))
+                .await?;
+            tracing::info!("seeded dataset {}: {:?}", name, report);
+            return Ok(());
+        }
+        Some(other) => anyhow::bail!(
+            "unknown command {:?}, try `rest_app seed <dataset>` or `rest_app token <subject> [role...]`",
+            other
+        ),
+    }
+
+    let registry = Arc::new(
+        JobRegistry::new()
+            .register::<PurgeDeletedRecords>()
+            .register::<PurgeEventLog>(),
+    );
+
+    JobWorkerPool::new(Arc::new(DbContext::new(pool.clone())), registry)
+        .with_concurrency(config.job_concurrency)
+        .with_lease(Duration::from_secs(config.job_lease_secs))
+        .spawn(Duration::from_millis(config.job_poll_interval_ms));
+
+    JobScheduler::new(Arc::new(DbContext::new(pool.clone())))
+        .with_interval(
+            Duration::from_secs(config.purge_interval_secs),
+            PurgeDeletedRecords {
+                retention_days: config.soft_delete_retention_days,
+            },
+        )?
+        .with_interval(
+            Duration::from_secs(config.purge_interval_secs),
+            PurgeEventLog {
+                retention_days: config.event_log_retention_days,
+            },
+        )?
+        .spawn(Duration::from_secs(1));
+
+    let event_bus = Arc::new(EventBus::new(1024));
+
+    let mut dispatcher = OutboxDispatcher::new(Arc::new(DbContext::new(pool.clone())))
+        .with_batch_size(config.outbox_batch_size)
+        .with_max_attempts(config.outbox_max_attempts)
+        .with_sink(Arc::new(EventLogSink::new(Arc::new(DbContext::new(
+            pool.clone(),
+        )))))
+        .with_sink(event_bus.clone())
+        .with_sink(Arc::new(WebhookSubscriptionSink::new(Arc::new(
+            DbContext::new(pool.clone()),
+        ))));
+    if let Some(url) = &config.outbox_webhook_url {
+        dispatcher = dispatcher.with_sink(Arc::new(WebhookSink::new(url.clone())?));
+    }
+    dispatcher.spawn(Duration::from_millis(config.outbox_poll_interval_ms));
+
+    WebhookDeliveryWorker::new(Arc::new(DbContext::new(pool.clone())))?
+        .with_batch_size(config.webhook_batch_size)
+        .with_max_attempts(config.webhook_max_attempts)
+        .with_private_destinations(config.webhook_private_destinations)?
+        .spawn(Duration::from_millis(config.webhook_poll_interval_ms));
+
+    let replicas =
+        db::init_replicas(&config.db_replica_connect_strs, config.row_level_security).await?;
+    let db_context = DbContext::new(pool)
+        .with_row_level_security(config.row_level_security)
+        .with_replicas(replicas)
+        .with_read_your_writes(Duration::from_millis(config.read_your_writes_ms));
 
-    let http_server = HttpServer::new(pool, config).await?;
+    let http_server = HttpServer::new(Arc::new(db_context), config, event_bus).await?;
 
     http_server.start().await
 }
@@ -1,23 +1,166 @@
 use anyhow::Context;
-use std::{env, sync::Arc};
+use ipnet::IpNet;
+use std::{env, net::IpAddr, sync::Arc};
 
 #[derive(Debug, Clone)]
 pub struct AppConfig {
     pub server_port: String,
+    /// Proxies, as addresses or CIDR ranges, whose `X-Forwarded-For` is believed.
+    pub trusted_proxies: Vec<IpNet>,
     pub db_connect_str: String,
     pub token_secret_key: String,
+    pub soft_delete_retention_days: i64,
+    pub purge_interval_secs: u64,
+    /// How long the change feed can be resumed from an event.
+    pub event_log_retention_days: i64,
+    pub row_level_security: bool,
+    pub db_replica_connect_strs: Vec<String>,
+    pub read_your_writes_ms: u64,
+    pub outbox_poll_interval_ms: u64,
+    pub outbox_batch_size: u64,
+    pub outbox_max_attempts: i32,
+    pub outbox_webhook_url: Option<String>,
+    pub webhook_poll_interval_ms: u64,
+    pub webhook_batch_size: u64,
+    pub webhook_max_attempts: i32,
+    pub webhook_private_destinations: bool,
+    pub job_poll_interval_ms: u64,
+    pub job_concurrency: usize,
+    pub job_lease_secs: u64,
+    pub openapi_ui: bool,
+    pub graphql_max_depth: usize,
+    pub graphql_max_complexity: usize,
+    /// Calls a caller may make to the gRPC services in a minute.
+    pub grpc_rate_limit: u32,
 }
 
 impl AppConfig {
     pub fn from_env() -> anyhow::Result<Arc<Self>> {
         let server_port = load_env("SERVER_PORT").unwrap_or("8080".to_string());
+        let trusted_proxies = load_env("TRUSTED_PROXIES")
+            .map(|v| {
+                v.split(',')
+                    .map(str::trim)
+                    .filter(|s| !s.is_empty())
+                    .map(parse_net)
+                    .collect::<anyhow::Result<_>>()
+            })
+            .unwrap_or(Ok(Vec::new()))
+            .context("TRUSTED_PROXIES must be addresses or CIDR ranges")?;
         let db_connect_str = load_env("DB_CONNECT_STR")?;
         let token_secret_key = load_env("TOKEN_SECRET_KEY")?;
+        let soft_delete_retention_days = load_env("SOFT_DELETE_RETENTION_DAYS")
+            .unwrap_or("30".to_string())
+            .parse()
+            .context("SOFT_DELETE_RETENTION_DAYS must be a number of days")?;
+        let purge_interval_secs = load_env("PURGE_INTERVAL_SECS")
+            .unwrap_or("3600".to_string())
+            .parse()
+            .context("PURGE_INTERVAL_SECS must be a number of seconds")?;
+        let event_log_retention_days = load_env("EVENT_LOG_RETENTION_DAYS")
+            .unwrap_or("7".to_string())
+            .parse()
+            .context("EVENT_LOG_RETENTION_DAYS must be a number of days")?;
+        let row_level_security = load_env("DB_ROW_LEVEL_SECURITY")
+            .unwrap_or("false".to_string())
+            .parse()
+            .context("DB_ROW_LEVEL_SECURITY must be true or false")?;
+        let db_replica_connect_strs = load_env("DB_REPLICA_CONNECT_STRS")
+            .map(|v| {
+                v.split(',')
+                    .map(|s| s.trim().to_string())
+                    .filter(|s| !s.is_empty())
+                    .collect()
+            })
+            .unwrap_or_default();
+        let read_your_writes_ms = load_env("DB_READ_YOUR_WRITES_MS")
+            .unwrap_or("5000".to_string())
+            .parse()
+            .context("DB_READ_YOUR_WRITES_MS must be a number of milliseconds")?;
+        let outbox_poll_interval_ms = load_env("OUTBOX_POLL_INTERVAL_MS")
+            .unwrap_or("1000".to_string())
+            .parse()
+            .context("OUTBOX_POLL_INTERVAL_MS must be a number of milliseconds")?;
+        let outbox_batch_size = load_env("OUTBOX_BATCH_SIZE")
+            .unwrap_or("100".to_string())
+            .parse()
+            .context("OUTBOX_BATCH_SIZE must be a number")?;
+        let outbox_max_attempts = load_env("OUTBOX_MAX_ATTEMPTS")
+            .unwrap_or("10".to_string())
+            .parse()
+            .context("OUTBOX_MAX_ATTEMPTS must be a number")?;
+        let outbox_webhook_url = load_env("OUTBOX_WEBHOOK_URL").ok();
+        let webhook_poll_interval_ms = load_env("WEBHOOK_POLL_INTERVAL_MS")
+            .unwrap_or("1000".to_string())
+            .parse()
+            .context("WEBHOOK_POLL_INTERVAL_MS must be a number of milliseconds")?;
+        let webhook_batch_size = load_env("WEBHOOK_BATCH_SIZE")
+            .unwrap_or("20".to_string())
+            .parse()
+            .context("WEBHOOK_BATCH_SIZE must be a number")?;
+        let webhook_max_attempts = load_env("WEBHOOK_MAX_ATTEMPTS")
+            .unwrap_or("8".to_string())
+            .parse()
+            .context("WEBHOOK_MAX_ATTEMPTS must be a number")?;
+        let webhook_private_destinations = load_env("WEBHOOK_PRIVATE_DESTINATIONS")
+            .unwrap_or("false".to_string())
+            .parse()
+            .context("WEBHOOK_PRIVATE_DESTINATIONS must be true or false")?;
+        let job_poll_interval_ms = load_env("JOB_POLL_INTERVAL_MS")
+            .unwrap_or("1000".to_string())
+            .parse()
+            .context("JOB_POLL_INTERVAL_MS must be a number of milliseconds")?;
+        let job_concurrency = load_env("JOB_CONCURRENCY")
+            .unwrap_or("4".to_string())
+            .parse()
+            .context("JOB_CONCURRENCY must be a number")?;
+        let job_lease_secs = load_env("JOB_LEASE_SECS")
+            .unwrap_or("300".to_string())
+            .parse()
+            .context("JOB_LEASE_SECS must be a number of seconds")?;
+        let openapi_ui = load_env("OPENAPI_UI")
+            .unwrap_or("false".to_string())
+            .parse()
+            .context("OPENAPI_UI must be true or false")?;
+        let graphql_max_depth = load_env("GRAPHQL_MAX_DEPTH")
+            .unwrap_or("8".to_string())
+            .parse()
+            .context("GRAPHQL_MAX_DEPTH must be a number")?;
+        let graphql_max_complexity = load_env("GRAPHQL_MAX_COMPLEXITY")
+            .unwrap_or("250".to_string())
+            .parse()
+            .context("GRAPHQL_MAX_COMPLEXITY must be a number")?;
+        let grpc_rate_limit = load_env("GRPC_RATE_LIMIT")
+            .unwrap_or("600".to_string())
+            .parse()
+            .context("GRPC_RATE_LIMIT must be a number")?;
 
         Ok(Arc::new(Self {
             server_port,
+            trusted_proxies,
             db_connect_str,
             token_secret_key,
+            soft_delete_retention_days,
+            purge_interval_secs,
+            event_log_retention_days,
+            row_level_security,
+            db_replica_connect_strs,
+            read_your_writes_ms,
+            outbox_poll_interval_ms,
+            outbox_batch_size,
+            outbox_max_attempts,
+            outbox_webhook_url,
+            webhook_poll_interval_ms,
+            webhook_batch_size,
+            webhook_max_attempts,
+            webhook_private_destinations,
+            job_poll_interval_ms,
+            job_concurrency,
+            job_lease_secs,
+            openapi_ui,
+            graphql_max_depth,
+            graphql_max_complexity,
+            grpc_rate_limit,
         }))
     }
 }
@@ -25,3 +168,12 @@ impl AppConfig {
 fn load_env(key: &str) -> anyhow::Result<String> {
     env::var(key).with_context(|| format!("failed to load environment variable {}", key))
 }
+
+fn parse_net(s: &str) -> anyhow::Result<IpNet> {
+    match s.parse::<IpAddr>() {
+        Ok(ip) => Ok(ip.into()),
+        Err(_) => s
+            .parse()
+            .with_context(|| format!("{} is not an address or CIDR range", s)),
+    }
+}
@@ -0,0 +1,120 @@
+use chrono::{DateTime, FixedOffset};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AuditAction {
+    Create,
+    Update,
+    Delete,
+    Restore,
+    Purge,
+}
+
+impl AuditAction {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Create => "create",
+            Self::Update => "update",
+            Self::Delete => "delete",
+            Self::Restore => "restore",
+            Self::Purge => "purge",
+        }
+    }
+}
+
+// bookkeeping fields change on every write and would only add noise to the diff
+const UNTRACKED_FIELDS: [&str; 3] = ["version", "updated_at", "updated_by"];
+
+/// A change to record, on behalf of whoever the repository is running for.
+#[derive(Debug, Clone)]
+pub struct NewAuditLog {
+    pub entity: String,
+    pub entity_id: Uuid,
+    pub action: AuditAction,
+    pub before: Option<Value>,
+    pub after: Option<Value>,
+    /// Falls back to the tenant the repository is bound to.
+    pub tenant_id: Option<Uuid>,
+}
+
+impl NewAuditLog {
+    /// Records only the fields that differ when both states are known, or the whole
+    /// state for creations and hard deletions.
+    pub fn diff<T: Serialize>(
+        entity: &str,
+        entity_id: Uuid,
+        action: AuditAction,
+        before: Option<&T>,
+        after: Option<&T>,
+    ) -> Self {
+        let before = before.and_then(|v| serde_json::to_value(v).ok());
+        let after = after.and_then(|v| serde_json::to_value(v).ok());
+
+        let (before, after) = match (before, after) {
+            (Some(Value::Object(before)), Some(Value::Object(after))) => {
+                let changed: Vec<&String> = after
+                    .iter()
+                    .filter(|(k, v)| {
+                        !UNTRACKED_FIELDS.contains(&k.as_str()) && before.get(*k) != Some(*v)
+                    })
+                    .map(|(k, _)| k)
+                    .collect();
+
+                let pick = |state: &Map<String, Value>| {
+                    changed
+                        .iter()
+                        .map(|k| ((*k).clone(), state.get(*k).cloned().unwrap_or(Value::Null)))
+                        .collect::<Map<String, Value>>()
+                };
+
+                (
+                    Some(Value::Object(pick(&before))),
+                    Some(Value::Object(pick(&after))),
+                )
+            }
+            other => other,
+        };
+
+        Self {
+            entity: entity.to_string(),
+            entity_id,
+            action,
+            before,
+            after,
+            tenant_id: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AuditLog {
+    pub id: Uuid,
+    pub entity: String,
+    pub entity_id: Uuid,
+    /// One of `AuditAction`.
+    pub action: String,
+    pub actor: Option<String>,
+    pub before: Option<Value>,
+    pub after: Option<Value>,
+    pub request_id: Option<String>,
+    pub created_at: DateTime<FixedOffset>,
+}
+
+/// Which records a query returns, the latest first.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AuditLogCriteria {
+    pub entity: Option<String>,
+    pub entity_id: Option<Uuid>,
+    pub action: Option<AuditAction>,
+    pub actor: Option<String>,
+    pub request_id: Option<String>,
+    pub from: Option<DateTime<FixedOffset>>,
+    /// Exclusive.
+    pub to: Option<DateTime<FixedOffset>>,
+    /// 100 when missing.
+    pub limit: Option<u64>,
+    pub offset: Option<u64>,
+}
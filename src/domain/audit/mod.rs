@@ -0,0 +1,4 @@
+mod audit_log;
+pub mod repositories;
+
+pub use audit_log::*;
@@ -0,0 +1,13 @@
+use async_trait::async_trait;
+
+use crate::domain::{
+    audit::{AuditLog, AuditLogCriteria, NewAuditLog},
+    error::DomainError,
+};
+
+#[async_trait]
+pub trait AuditRepository: Send + Sync {
+    async fn record(&self, log: NewAuditLog) -> Result<(), DomainError>;
+    async fn record_many(&self, logs: Vec<NewAuditLog>) -> Result<(), DomainError>;
+    async fn query(&self, criteria: &AuditLogCriteria) -> Result<Vec<AuditLog>, DomainError>;
+}
@@ -0,0 +1,2 @@
+mod audit_repo;
+pub use audit_repo::*;
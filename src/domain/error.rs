@@ -1,11 +1,32 @@
-use axum::http::StatusCode;
-use sea_orm::DbErr;
+use std::error::Error as StdError;
+
 use thiserror::Error;
 
+/// What a use case error stands for, which the presentation turns into a status.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorKind {
+    BadRequest,
+    NotFound,
+    Conflict,
+    PreconditionFailed,
+    NotAcceptable,
+    PayloadTooLarge,
+    Internal,
+}
+
 #[derive(Error, Debug)]
 pub enum DomainError {
+    /// A value breaking the invariants of a domain type.
+    #[error("{0}")]
+    Invalid(String),
+    #[error("{0}")]
+    NotFound(String),
+    /// Clashes with what is stored already, such as a name in use.
+    #[error("{0}")]
+    Duplicated(String),
+    /// The storage failed, for reasons only the infrastructure makes sense of.
     #[error(transparent)]
-    DbError(#[from] DbErr),
+    Storage(Box<dyn StdError + Send + Sync>),
     #[error("use case error: [{1}]{2}")]
-    CaseError(StatusCode, String, String),
+    CaseError(ErrorKind, String, String),
 }
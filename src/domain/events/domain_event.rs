@@ -0,0 +1,69 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+/// Something that happened to the organization, published to the outside world
+/// through the outbox once the transaction that raised it commits.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", content = "data")]
+pub enum DomainEvent {
+    #[serde(rename = "company.created")]
+    CompanyCreated { id: Uuid, name: String },
+    #[serde(rename = "company.updated")]
+    CompanyUpdated { id: Uuid, version: i32 },
+    /// Also stands for the soft deletion of the company's departments.
+    #[serde(rename = "company.deleted")]
+    CompanyDeleted { id: Uuid },
+    #[serde(rename = "company.restored")]
+    CompanyRestored { id: Uuid },
+    #[serde(rename = "department.created")]
+    DepartmentCreated {
+        id: Uuid,
+        company_id: Uuid,
+        name: String,
+    },
+    #[serde(rename = "department.updated")]
+    DepartmentUpdated { id: Uuid, version: i32 },
+    #[serde(rename = "department.deleted")]
+    DepartmentDeleted { id: Uuid },
+    #[serde(rename = "department.restored")]
+    DepartmentRestored { id: Uuid },
+}
+
+impl DomainEvent {
+    pub const TYPES: [&'static str; 8] = [
+        "company.created",
+        "company.updated",
+        "company.deleted",
+        "company.restored",
+        "department.created",
+        "department.updated",
+        "department.deleted",
+        "department.restored",
+    ];
+
+    pub fn event_type(&self) -> &'static str {
+        match self {
+            Self::CompanyCreated { .. } => "company.created",
+            Self::CompanyUpdated { .. } => "company.updated",
+            Self::CompanyDeleted { .. } => "company.deleted",
+            Self::CompanyRestored { .. } => "company.restored",
+            Self::DepartmentCreated { .. } => "department.created",
+            Self::DepartmentUpdated { .. } => "department.updated",
+            Self::DepartmentDeleted { .. } => "department.deleted",
+            Self::DepartmentRestored { .. } => "department.restored",
+        }
+    }
+
+    pub fn aggregate_id(&self) -> Uuid {
+        match self {
+            Self::CompanyCreated { id, .. }
+            | Self::CompanyUpdated { id, .. }
+            | Self::CompanyDeleted { id }
+            | Self::CompanyRestored { id }
+            | Self::DepartmentCreated { id, .. }
+            | Self::DepartmentUpdated { id, .. }
+            | Self::DepartmentDeleted { id }
+            | Self::DepartmentRestored { id } => *id,
+        }
+    }
+}
@@ -0,0 +1,6 @@
+mod domain_event;
+mod outbox_message;
+pub mod repositories;
+
+pub use domain_event::*;
+pub use outbox_message::*;
@@ -0,0 +1,28 @@
+use chrono::{DateTime, FixedOffset};
+use serde::Serialize;
+use serde_json::Value;
+use uuid::Uuid;
+
+/// Envelope of a `DomainEvent` handed to the event sinks. `id` stays the same across
+/// redeliveries so that consumers can drop duplicates.
+#[derive(Debug, Clone, Serialize)]
+pub struct OutboxMessage {
+    pub id: Uuid,
+    pub tenant_id: Option<Uuid>,
+    pub event_type: String,
+    pub aggregate_id: Uuid,
+    pub payload: Value,
+    #[serde(skip)]
+    pub attempts: i32,
+    pub created_at: DateTime<FixedOffset>,
+}
+
+/// A message as numbered by the event log, which clients resume reading from.
+#[derive(Debug, Clone)]
+pub struct LoggedEvent {
+    pub seq: i64,
+    pub event_type: String,
+    pub aggregate_id: Uuid,
+    pub payload: Value,
+    pub created_at: DateTime<FixedOffset>,
+}
@@ -0,0 +1,20 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+
+use crate::domain::{
+    error::DomainError,
+    events::{LoggedEvent, OutboxMessage},
+};
+
+#[async_trait]
+pub trait EventLogRepository: Send + Sync {
+    /// Numbers the message after the ones already logged, once however many times it
+    /// is redelivered. To be called within a transaction, other appends waiting for it
+    /// to end so that the log is committed in the order it is numbered.
+    async fn append(&self, message: &OutboxMessage) -> Result<(), DomainError>;
+    async fn since(&self, seq: i64, limit: u64) -> Result<Vec<LoggedEvent>, DomainError>;
+    /// The number of the last logged event, 0 when there is none.
+    async fn last_seq(&self) -> Result<i64, DomainError>;
+    /// Removes the events published before `before`, returning how many there were.
+    async fn purge(&self, before: DateTime<Utc>) -> Result<u64, DomainError>;
+}
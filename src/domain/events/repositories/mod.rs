@@ -0,0 +1,5 @@
+mod event_log_repo;
+mod outbox_repo;
+
+pub use event_log_repo::*;
+pub use outbox_repo::*;
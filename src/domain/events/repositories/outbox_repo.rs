@@ -0,0 +1,30 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::domain::{
+    error::DomainError,
+    events::{DomainEvent, OutboxMessage},
+};
+
+#[async_trait]
+pub trait OutboxRepository: Send + Sync {
+    async fn add(&self, event: DomainEvent) -> Result<(), DomainError>;
+    async fn add_many(&self, events: Vec<DomainEvent>) -> Result<(), DomainError>;
+    /// Leases the pending messages that are due by putting their next attempt off to
+    /// `until`, so that other dispatchers skip them without a transaction being held
+    /// while they are delivered. They are due again if the dispatcher dies meanwhile.
+    async fn claim_due(
+        &self,
+        limit: u64,
+        until: DateTime<Utc>,
+    ) -> Result<Vec<OutboxMessage>, DomainError>;
+    async fn mark_published(&self, id: Uuid) -> Result<(), DomainError>;
+    /// Schedules another attempt at `retry_at`, or gives up on the message when it is `None`.
+    async fn mark_failed(
+        &self,
+        id: Uuid,
+        error: &str,
+        retry_at: Option<DateTime<Utc>>,
+    ) -> Result<(), DomainError>;
+}
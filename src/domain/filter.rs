@@ -0,0 +1,91 @@
+use chrono::{DateTime, FixedOffset};
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FieldKind {
+    Text,
+    Uuid,
+    Integer,
+    DateTime,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FilterOp {
+    Eq,
+    Ne,
+    Gt,
+    Gte,
+    Lt,
+    Lte,
+    /// Case sensitive substring match, `%` and `_` being matched literally.
+    Like,
+    /// Case insensitive substring match, `%` and `_` being matched literally.
+    Ilike,
+    /// Comma separated values.
+    In,
+    /// `true` or `false`.
+    IsNull,
+}
+
+impl FilterOp {
+    /// What `parse` accepts.
+    pub(crate) const NAMES: [&'static str; 10] = [
+        "eq", "ne", "gt", "gte", "lt", "lte", "like", "ilike", "in", "is_null",
+    ];
+
+    pub(crate) fn parse(op: &str) -> Option<Self> {
+        Some(match op {
+            "eq" => Self::Eq,
+            "ne" => Self::Ne,
+            "gt" => Self::Gt,
+            "gte" => Self::Gte,
+            "lt" => Self::Lt,
+            "lte" => Self::Lte,
+            "like" => Self::Like,
+            "ilike" => Self::Ilike,
+            "in" => Self::In,
+            "is_null" => Self::IsNull,
+            _ => return None,
+        })
+    }
+
+    pub(crate) fn applies_to(&self, kind: FieldKind) -> bool {
+        match self {
+            Self::Gt | Self::Gte | Self::Lt | Self::Lte => {
+                matches!(kind, FieldKind::Integer | FieldKind::DateTime)
+            }
+            Self::Like | Self::Ilike => kind == FieldKind::Text,
+            _ => true,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum FilterValue {
+    Text(String),
+    Uuid(Uuid),
+    Integer(i64),
+    DateTime(DateTime<FixedOffset>),
+    Bool(bool),
+}
+
+impl FilterValue {
+    pub(crate) fn parse(kind: FieldKind, value: &str) -> Option<Self> {
+        match kind {
+            FieldKind::Text => Some(Self::Text(value.to_string())),
+            FieldKind::Uuid => value.trim().parse().ok().map(Self::Uuid),
+            FieldKind::Integer => value.trim().parse().ok().map(Self::Integer),
+            FieldKind::DateTime => DateTime::parse_from_rfc3339(value.trim())
+                .ok()
+                .map(Self::DateTime),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FilterClause {
+    pub field: &'static str,
+    pub op: FilterOp,
+    /// A single value, save for `in`.
+    pub values: Vec<FilterValue>,
+}
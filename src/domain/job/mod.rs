@@ -0,0 +1,4 @@
+mod queue;
+pub mod repositories;
+
+pub use queue::*;
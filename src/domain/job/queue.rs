@@ -0,0 +1,80 @@
+use chrono::{DateTime, FixedOffset, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum JobStatus {
+    Pending,
+    Running,
+    Completed,
+    Failed,
+    Cancelled,
+}
+
+impl JobStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Pending => "pending",
+            Self::Running => "running",
+            Self::Completed => "completed",
+            Self::Failed => "failed",
+            Self::Cancelled => "cancelled",
+        }
+    }
+}
+
+/// A job about to be enqueued.
+#[derive(Debug, Clone)]
+pub struct NewJob {
+    pub name: String,
+    pub queue: String,
+    pub payload: Value,
+    pub max_attempts: i32,
+    pub run_at: DateTime<Utc>,
+    /// Jobs sharing a key are only enqueued once.
+    pub dedupe_key: Option<String>,
+}
+
+/// A job claimed by a worker.
+#[derive(Debug, Clone)]
+pub struct DueJob {
+    pub id: Uuid,
+    pub tenant_id: Option<Uuid>,
+    pub name: String,
+    pub payload: Value,
+    /// Including the attempt the job was claimed for.
+    pub attempts: i32,
+    pub max_attempts: i32,
+    /// Token of the claim, proving the job is still held when its outcome is recorded.
+    pub lease: Uuid,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Job {
+    pub id: Uuid,
+    pub name: String,
+    pub queue: String,
+    pub payload: Value,
+    /// One of `JobStatus`.
+    pub status: String,
+    pub attempts: i32,
+    pub max_attempts: i32,
+    pub run_at: DateTime<FixedOffset>,
+    pub locked_at: Option<DateTime<FixedOffset>>,
+    pub last_error: Option<String>,
+    pub created_at: DateTime<FixedOffset>,
+    pub finished_at: Option<DateTime<FixedOffset>>,
+}
+
+/// Which jobs a query returns, the latest first.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct JobCriteria {
+    pub status: Option<JobStatus>,
+    pub name: Option<String>,
+    pub queue: Option<String>,
+    /// 100 when missing.
+    pub limit: Option<u64>,
+    pub offset: Option<u64>,
+}
@@ -0,0 +1,41 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::domain::{
+    error::DomainError,
+    job::{DueJob, Job, JobCriteria, NewJob},
+};
+
+#[async_trait]
+pub trait JobRepository: Send + Sync {
+    /// Returns `None` when a job with the same dedupe key has already been enqueued.
+    async fn add(&self, job: NewJob) -> Result<Option<Uuid>, DomainError>;
+    /// Marks up to `limit` due jobs of `queue` as running, leased until `until`, along
+    /// with the running ones whose lease ran out as their worker is presumed dead. Every
+    /// claim gets a lease token of its own.
+    async fn claim(
+        &self,
+        queue: &str,
+        limit: u64,
+        until: DateTime<Utc>,
+    ) -> Result<Vec<DueJob>, DomainError>;
+    /// Fails with `LEASE_LOST` when `lease` ran out or the job was claimed again since,
+    /// leaving the job to whoever holds it now.
+    async fn complete(&self, id: Uuid, lease: Uuid) -> Result<(), DomainError>;
+    /// Schedules another attempt at `retry_at`, or gives up on the job when it is `None`.
+    /// Fails like `complete` when the lease is no longer held.
+    async fn fail(
+        &self,
+        id: Uuid,
+        lease: Uuid,
+        error: &str,
+        retry_at: Option<DateTime<Utc>>,
+    ) -> Result<(), DomainError>;
+    async fn query(&self, criteria: &JobCriteria) -> Result<Vec<Job>, DomainError>;
+    async fn find(&self, id: Uuid) -> Result<Option<Job>, DomainError>;
+    /// Puts a failed or cancelled job back in the queue with a fresh set of attempts.
+    async fn retry(&self, id: Uuid) -> Result<(), DomainError>;
+    /// Only pending jobs can be cancelled, running ones are left to finish.
+    async fn cancel(&self, id: Uuid) -> Result<(), DomainError>;
+}
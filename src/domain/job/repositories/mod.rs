@@ -0,0 +1,3 @@
+mod job_repo;
+
+pub use job_repo::*;
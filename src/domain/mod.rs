@@ -1,2 +1,7 @@
+pub mod audit;
 pub mod error;
+pub mod events;
+pub mod filter;
+pub mod job;
 pub mod organization;
+pub mod webhook;
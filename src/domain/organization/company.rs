@@ -0,0 +1,107 @@
+use std::fmt;
+
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::domain::{error::DomainError, filter::FilterClause};
+
+pub const MAX_COMPANY_NAME_LEN: usize = 200;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct CompanyId(Uuid);
+
+impl CompanyId {
+    pub fn new() -> Self {
+        Self(Uuid::new_v4())
+    }
+
+    pub fn as_uuid(&self) -> Uuid {
+        self.0
+    }
+}
+
+impl Default for CompanyId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<Uuid> for CompanyId {
+    fn from(id: Uuid) -> Self {
+        Self(id)
+    }
+}
+
+impl From<CompanyId> for Uuid {
+    fn from(id: CompanyId) -> Self {
+        id.0
+    }
+}
+
+impl fmt::Display for CompanyId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// 1 to 200 characters, not all of them blank.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CompanyName(String);
+
+impl CompanyName {
+    pub fn new(name: impl Into<String>) -> Result<Self, DomainError> {
+        let name = name.into();
+        if name.trim().is_empty() || name.chars().count() > MAX_COMPANY_NAME_LEN {
+            return Err(DomainError::Invalid(format!(
+                "company's name is required and max {} characters.",
+                MAX_COMPANY_NAME_LEN
+            )));
+        }
+        Ok(Self(name))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
+
+impl fmt::Display for CompanyName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Company {
+    pub id: CompanyId,
+    pub name: CompanyName,
+    /// Bumped on every change, for optimistic locking.
+    pub version: i32,
+    pub deleted_at: Option<DateTime<Utc>>,
+}
+
+impl Company {
+    /// A company about to be added, under a fresh id.
+    pub fn new(name: CompanyName) -> Self {
+        Self {
+            id: CompanyId::new(),
+            name,
+            version: 1,
+            deleted_at: None,
+        }
+    }
+
+    pub fn is_deleted(&self) -> bool {
+        self.deleted_at.is_some()
+    }
+}
+
+/// Which companies a query returns, every active one when there are no clauses.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CompanyCriteria {
+    pub clauses: Vec<FilterClause>,
+}
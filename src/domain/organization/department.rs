@@ -0,0 +1,103 @@
+use std::fmt;
+
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::domain::{error::DomainError, organization::CompanyId};
+
+pub const MAX_DEPARTMENT_NAME_LEN: usize = 200;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct DepartmentId(Uuid);
+
+impl DepartmentId {
+    pub fn new() -> Self {
+        Self(Uuid::new_v4())
+    }
+
+    pub fn as_uuid(&self) -> Uuid {
+        self.0
+    }
+}
+
+impl Default for DepartmentId {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<Uuid> for DepartmentId {
+    fn from(id: Uuid) -> Self {
+        Self(id)
+    }
+}
+
+impl From<DepartmentId> for Uuid {
+    fn from(id: DepartmentId) -> Self {
+        id.0
+    }
+}
+
+impl fmt::Display for DepartmentId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// 1 to 200 characters, not all of them blank.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DepartmentName(String);
+
+impl DepartmentName {
+    pub fn new(name: impl Into<String>) -> Result<Self, DomainError> {
+        let name = name.into();
+        if name.trim().is_empty() || name.chars().count() > MAX_DEPARTMENT_NAME_LEN {
+            return Err(DomainError::Invalid(format!(
+                "department's name is required and max {} characters.",
+                MAX_DEPARTMENT_NAME_LEN
+            )));
+        }
+        Ok(Self(name))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
+
+impl fmt::Display for DepartmentName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Department {
+    pub id: DepartmentId,
+    pub name: DepartmentName,
+    pub company_id: CompanyId,
+    /// Bumped on every change, for optimistic locking.
+    pub version: i32,
+    pub deleted_at: Option<DateTime<Utc>>,
+}
+
+impl Department {
+    /// A department about to be added to `company_id`, under a fresh id.
+    pub fn new(name: DepartmentName, company_id: CompanyId) -> Self {
+        Self {
+            id: DepartmentId::new(),
+            name,
+            company_id,
+            version: 1,
+            deleted_at: None,
+        }
+    }
+
+    pub fn is_deleted(&self) -> bool {
+        self.deleted_at.is_some()
+    }
+}
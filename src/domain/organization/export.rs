@@ -0,0 +1,24 @@
+use chrono::{DateTime, FixedOffset};
+use uuid::Uuid;
+
+/// An active company as exported.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExportedCompany {
+    pub id: Uuid,
+    pub name: String,
+    /// Active departments, which is what headcount is tracked by for now.
+    pub department_count: i64,
+    pub created_at: DateTime<FixedOffset>,
+    pub updated_at: DateTime<FixedOffset>,
+}
+
+/// An active department as exported, along with the name of its company.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExportedDepartment {
+    pub id: Uuid,
+    pub name: String,
+    pub company_id: Uuid,
+    pub company_name: String,
+    pub created_at: DateTime<FixedOffset>,
+    pub updated_at: DateTime<FixedOffset>,
+}
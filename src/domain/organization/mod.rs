@@ -1 +1,10 @@
+mod company;
+mod department;
+mod export;
 pub mod repositories;
+mod search;
+
+pub use company::*;
+pub use department::*;
+pub use export::*;
+pub use search::*;
@@ -1,17 +1,43 @@
+use std::collections::HashMap;
+
 use async_trait::async_trait;
-use uuid::Uuid;
+use chrono::{DateTime, Utc};
 
-use crate::{
-    application::dtos::company::{ReqAddCompanyDto, ReqQueryCompanyDto, ResQueryCompanyDto},
-    domain::error::DomainError,
+use crate::domain::{
+    error::DomainError,
+    organization::{Company, CompanyCriteria, CompanyId, CompanyName},
 };
 
 #[async_trait]
-pub trait CompanyRepository {
-    async fn query(
+pub trait CompanyRepository: Send + Sync {
+    async fn query(&self, criteria: &CompanyCriteria) -> Result<Vec<Company>, DomainError>;
+    async fn find(&self, id: CompanyId) -> Result<Option<Company>, DomainError>;
+    /// The active ones among `ids`.
+    async fn find_many(&self, ids: &[CompanyId]) -> Result<Vec<Company>, DomainError>;
+    async fn exists(&self, id: CompanyId) -> Result<bool, DomainError>;
+    /// Ids of the active rows among `names`, keyed by name.
+    async fn find_ids_by_name(
+        &self,
+        names: &[String],
+    ) -> Result<HashMap<String, CompanyId>, DomainError>;
+    async fn add(&self, com: Company) -> Result<CompanyId, DomainError>;
+    /// Inserts all of `coms` with a single statement, returning their ids in order.
+    async fn add_many(&self, coms: Vec<Company>) -> Result<Vec<CompanyId>, DomainError>;
+    /// Renames the company, provided it is still at `version`, and returns its new version.
+    async fn update(
+        &self,
+        id: CompanyId,
+        version: i32,
+        name: CompanyName,
+    ) -> Result<i32, DomainError>;
+    async fn delete(
         &self,
-        cond: &ReqQueryCompanyDto,
-    ) -> Result<Vec<ResQueryCompanyDto>, DomainError>;
-    async fn exists(&self, id: Uuid) -> Result<bool, DomainError>;
-    async fn add(&self, com: ReqAddCompanyDto) -> Result<Uuid, DomainError>;
+        id: CompanyId,
+        version: i32,
+        at: DateTime<Utc>,
+    ) -> Result<(), DomainError>;
+    async fn query_deleted(&self) -> Result<Vec<Company>, DomainError>;
+    /// Brings a soft deleted company back and returns when it had been deleted.
+    async fn restore(&self, id: CompanyId) -> Result<DateTime<Utc>, DomainError>;
+    async fn purge(&self, deleted_before: DateTime<Utc>) -> Result<u64, DomainError>;
 }
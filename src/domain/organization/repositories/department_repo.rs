@@ -1,9 +1,58 @@
+use std::collections::HashMap;
+
 use async_trait::async_trait;
-use uuid::Uuid;
+use chrono::{DateTime, Utc};
 
-use crate::{application::dtos::department::ReqAddDepartmentDto, domain::error::DomainError};
+use crate::domain::{
+    error::DomainError,
+    organization::{CompanyId, Department, DepartmentId, DepartmentName},
+};
 
 #[async_trait]
-pub trait DepartmentRepository {
-    async fn add(&self, dep: ReqAddDepartmentDto) -> Result<Uuid, DomainError>;
+pub trait DepartmentRepository: Send + Sync {
+    async fn find(&self, id: DepartmentId) -> Result<Option<Department>, DomainError>;
+    /// Active departments of any of `company_ids`.
+    async fn find_by_companies(
+        &self,
+        company_ids: &[CompanyId],
+    ) -> Result<Vec<Department>, DomainError>;
+    /// Ids of the active rows among `names`, keyed by name.
+    async fn find_ids_by_name(
+        &self,
+        names: &[String],
+    ) -> Result<HashMap<String, DepartmentId>, DomainError>;
+    async fn add(&self, dep: Department) -> Result<DepartmentId, DomainError>;
+    /// Renames the department or moves it to another company, provided it is still at
+    /// `version`, and returns its new version.
+    async fn update(
+        &self,
+        id: DepartmentId,
+        version: i32,
+        name: DepartmentName,
+        company_id: CompanyId,
+    ) -> Result<i32, DomainError>;
+    async fn delete(
+        &self,
+        id: DepartmentId,
+        version: i32,
+        at: DateTime<Utc>,
+    ) -> Result<(), DomainError>;
+    async fn delete_by_company(
+        &self,
+        company_id: CompanyId,
+        at: DateTime<Utc>,
+    ) -> Result<u64, DomainError>;
+    async fn query_deleted(&self) -> Result<Vec<Department>, DomainError>;
+    /// Brings a soft deleted department back and returns the company it belongs to.
+    async fn restore(&self, id: DepartmentId) -> Result<CompanyId, DomainError>;
+    /// Restores the departments that were deleted together with their company.
+    async fn restore_by_company(
+        &self,
+        company_id: CompanyId,
+        deleted_at: DateTime<Utc>,
+    ) -> Result<u64, DomainError>;
+    /// Hard deletes the departments deleted before `deleted_before`, along with all those
+    /// of the companies deleted by then, so that each gets its own audit entry rather
+    /// than going silently with the cascade.
+    async fn purge(&self, deleted_before: DateTime<Utc>) -> Result<u64, DomainError>;
 }
@@ -0,0 +1,25 @@
+use std::pin::Pin;
+
+use async_trait::async_trait;
+use futures_core::Stream;
+
+use crate::domain::{
+    error::DomainError,
+    organization::{CompanyCriteria, ExportedCompany, ExportedDepartment},
+};
+
+pub type RowStream<'a, T> = Pin<Box<dyn Stream<Item = Result<T, DomainError>> + Send + 'a>>;
+
+/// Streams rows straight from the database cursor, for exports too large to be
+/// collected first.
+#[async_trait]
+pub trait ExportRepository: Send + Sync {
+    async fn export_companies<'b>(
+        &'b self,
+        criteria: &'b CompanyCriteria,
+    ) -> Result<RowStream<'b, ExportedCompany>, DomainError>;
+    async fn export_departments<'b>(
+        &'b self,
+        criteria: &'b CompanyCriteria,
+    ) -> Result<RowStream<'b, ExportedDepartment>, DomainError>;
+}
@@ -1,4 +1,8 @@
 mod company_repo;
 pub use company_repo::*;
 mod department_repo;
-pub use department_repo::*;
\ No newline at end of file
+pub use department_repo::*;
+mod export_repo;
+pub use export_repo::*;
+mod search_repo;
+pub use search_repo::*;
@@ -0,0 +1,17 @@
+use async_trait::async_trait;
+
+use crate::domain::{
+    error::DomainError,
+    organization::{SearchHit, SearchKind},
+};
+
+#[async_trait]
+pub trait SearchRepository: Send + Sync {
+    /// The `limit` best matches of `q` among the active rows of `kinds`, best first.
+    async fn search(
+        &self,
+        q: &str,
+        kinds: &[SearchKind],
+        limit: u64,
+    ) -> Result<Vec<SearchHit>, DomainError>;
+}
@@ -0,0 +1,25 @@
+use serde::Serialize;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SearchKind {
+    Company,
+    Department,
+}
+
+/// A company or department whose name matched a search.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SearchHit {
+    pub kind: SearchKind,
+    pub id: Uuid,
+    pub name: String,
+    /// The company of a department.
+    pub company_id: Option<Uuid>,
+    pub company_name: Option<String>,
+    /// Higher is more relevant, only comparable within the same search.
+    pub score: f64,
+    /// `name` with the matched words wrapped in `<mark>` tags, unchanged when it only
+    /// matched approximately.
+    pub highlight: String,
+}
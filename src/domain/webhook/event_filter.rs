@@ -0,0 +1,18 @@
+use crate::domain::events::DomainEvent;
+
+/// Whether a subscription filter (`*`, `company.*` or an exact event type) selects
+/// `event_type`.
+pub fn filter_matches(filter: &str, event_type: &str) -> bool {
+    match filter.strip_suffix('*') {
+        Some(prefix) => event_type.starts_with(prefix),
+        None => filter == event_type,
+    }
+}
+
+/// Whether `filter` can select at least one of the events we publish, to reject typos
+/// when a subscription is registered.
+pub fn is_known_filter(filter: &str) -> bool {
+    DomainEvent::TYPES
+        .iter()
+        .any(|event_type| filter_matches(filter, event_type))
+}
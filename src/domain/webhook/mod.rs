@@ -0,0 +1,8 @@
+mod event_filter;
+pub mod repositories;
+mod subscription;
+mod webhook_delivery;
+
+pub use event_filter::*;
+pub use subscription::*;
+pub use webhook_delivery::*;
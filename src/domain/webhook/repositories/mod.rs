@@ -0,0 +1,5 @@
+mod webhook_delivery_repo;
+mod webhook_repo;
+
+pub use webhook_delivery_repo::*;
+pub use webhook_repo::*;
@@ -0,0 +1,37 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::domain::{
+    error::DomainError,
+    events::OutboxMessage,
+    webhook::{DueWebhookDelivery, WebhookDelivery, WebhookDeliveryCriteria},
+};
+
+#[async_trait]
+pub trait WebhookDeliveryRepository: Send + Sync {
+    /// Queues the message for every active subscription of its tenant that asked for it.
+    /// Queuing the same message twice is a no-op.
+    async fn enqueue(&self, message: &OutboxMessage) -> Result<u64, DomainError>;
+    /// Leases the pending deliveries of active subscriptions that are due until `until`,
+    /// like `OutboxRepository::claim_due`.
+    async fn claim_due(
+        &self,
+        limit: u64,
+        until: DateTime<Utc>,
+    ) -> Result<Vec<DueWebhookDelivery>, DomainError>;
+    async fn mark_delivered(&self, id: Uuid, status_code: i32) -> Result<(), DomainError>;
+    /// Schedules another attempt at `retry_at`, or dead-letters the delivery when it is `None`.
+    async fn mark_failed(
+        &self,
+        id: Uuid,
+        status_code: Option<i32>,
+        error: &str,
+        retry_at: Option<DateTime<Utc>>,
+    ) -> Result<(), DomainError>;
+    async fn query(
+        &self,
+        subscription_id: Uuid,
+        criteria: &WebhookDeliveryCriteria,
+    ) -> Result<Vec<WebhookDelivery>, DomainError>;
+}
@@ -0,0 +1,16 @@
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::{
+    error::DomainError,
+    webhook::{NewWebhook, Webhook, WebhookChanges},
+};
+
+#[async_trait]
+pub trait WebhookRepository: Send + Sync {
+    async fn query(&self) -> Result<Vec<Webhook>, DomainError>;
+    async fn find(&self, id: Uuid) -> Result<Option<Webhook>, DomainError>;
+    async fn add(&self, hook: NewWebhook) -> Result<Uuid, DomainError>;
+    async fn update(&self, id: Uuid, changes: WebhookChanges) -> Result<(), DomainError>;
+    async fn delete(&self, id: Uuid) -> Result<(), DomainError>;
+}
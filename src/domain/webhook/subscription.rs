@@ -0,0 +1,31 @@
+use chrono::{DateTime, FixedOffset};
+use uuid::Uuid;
+
+/// A subscription of a tenant to the events selected by `events`, which are posted to
+/// `url` signed with its secret.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Webhook {
+    pub id: Uuid,
+    pub url: String,
+    /// Filters as told by `filter_matches`.
+    pub events: Vec<String>,
+    pub active: bool,
+    pub created_at: DateTime<FixedOffset>,
+    pub updated_at: DateTime<FixedOffset>,
+}
+
+/// A subscription about to be added, active from the start.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NewWebhook {
+    pub url: String,
+    pub events: Vec<String>,
+    pub secret: String,
+}
+
+/// What updating a subscription replaces, its secret being kept.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WebhookChanges {
+    pub url: String,
+    pub events: Vec<String>,
+    pub active: bool,
+}
@@ -0,0 +1,59 @@
+use chrono::{DateTime, FixedOffset};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use uuid::Uuid;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WebhookDeliveryStatus {
+    Pending,
+    Delivered,
+    Dead,
+}
+
+impl WebhookDeliveryStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Pending => "pending",
+            Self::Delivered => "delivered",
+            Self::Dead => "dead",
+        }
+    }
+}
+
+/// A message queued for a subscription, and how sending it went so far.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WebhookDelivery {
+    pub id: Uuid,
+    pub message_id: Uuid,
+    pub event_type: String,
+    /// One of `WebhookDeliveryStatus`.
+    pub status: String,
+    pub attempts: i32,
+    pub next_attempt_at: DateTime<FixedOffset>,
+    pub last_status_code: Option<i32>,
+    pub last_error: Option<String>,
+    pub payload: Value,
+    pub created_at: DateTime<FixedOffset>,
+    pub delivered_at: Option<DateTime<FixedOffset>>,
+}
+
+/// Which deliveries of a subscription a query returns, the latest first.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct WebhookDeliveryCriteria {
+    pub status: Option<WebhookDeliveryStatus>,
+    /// 100 when missing.
+    pub limit: Option<u64>,
+    pub offset: Option<u64>,
+}
+
+/// A delivery picked up by the worker, along with where and how to send it.
+#[derive(Debug, Clone)]
+pub struct DueWebhookDelivery {
+    pub id: Uuid,
+    pub event_type: String,
+    pub payload: Value,
+    pub attempts: i32,
+    pub url: String,
+    pub secret: String,
+}
@@ -0,0 +1,36 @@
+use std::future::Future;
+
+use chrono::{DateTime, FixedOffset, Utc};
+
+tokio::task_local! {
+    static AUDIT_CONTEXT: AuditContext;
+}
+
+/// Who is changing data and on behalf of which request. It is bound to the task serving
+/// the request, so entity hooks and repositories can stamp rows without every use case
+/// passing the user around.
+#[derive(Debug, Clone, Default)]
+pub struct AuditContext {
+    pub actor: Option<String>,
+    pub request_id: Option<String>,
+}
+
+impl AuditContext {
+    pub fn new(actor: Option<String>, request_id: Option<String>) -> Self {
+        Self { actor, request_id }
+    }
+
+    pub async fn scope<F: Future>(self, f: F) -> F::Output {
+        AUDIT_CONTEXT.scope(self, f).await
+    }
+
+    /// Context of the running task, empty when called outside of a request
+    /// (e.g. background jobs).
+    pub fn current() -> Self {
+        AUDIT_CONTEXT.try_with(Clone::clone).unwrap_or_default()
+    }
+
+    pub fn stamp(self) -> (DateTime<FixedOffset>, Option<String>) {
+        (Utc::now().fixed_offset(), self.actor)
+    }
+}
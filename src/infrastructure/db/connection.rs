@@ -0,0 +1,55 @@
+use async_trait::async_trait;
+use sea_orm::{ConnectionTrait, DbBackend, DbErr, ExecResult, QueryResult, Statement};
+
+use super::TenantScope;
+
+/// The connection of a repository, running every query on behalf of its tenant. When
+/// `C` is a pool, the connection checked out for a query is told about that tenant
+/// before it runs anything (see `init_db`), whether or not it is in a transaction.
+#[derive(Debug, Clone, Copy)]
+pub struct TenantConnection<'a, C> {
+    conn: &'a C,
+    tenant: TenantScope,
+}
+
+impl<'a, C: ConnectionTrait> TenantConnection<'a, C> {
+    pub fn new(conn: &'a C, tenant: TenantScope) -> Self {
+        Self { conn, tenant }
+    }
+
+    /// The underlying connection, e.g. to begin a transaction.
+    pub fn inner(&self) -> &'a C {
+        self.conn
+    }
+}
+
+#[async_trait]
+impl<C: ConnectionTrait> ConnectionTrait for TenantConnection<'_, C> {
+    fn get_database_backend(&self) -> DbBackend {
+        self.conn.get_database_backend()
+    }
+
+    async fn execute(&self, stmt: Statement) -> Result<ExecResult, DbErr> {
+        self.tenant.run(self.conn.execute(stmt)).await
+    }
+
+    async fn execute_unprepared(&self, sql: &str) -> Result<ExecResult, DbErr> {
+        self.tenant.run(self.conn.execute_unprepared(sql)).await
+    }
+
+    async fn query_one(&self, stmt: Statement) -> Result<Option<QueryResult>, DbErr> {
+        self.tenant.run(self.conn.query_one(stmt)).await
+    }
+
+    async fn query_all(&self, stmt: Statement) -> Result<Vec<QueryResult>, DbErr> {
+        self.tenant.run(self.conn.query_all(stmt)).await
+    }
+
+    fn support_returning(&self) -> bool {
+        self.conn.support_returning()
+    }
+
+    fn is_mock_connection(&self) -> bool {
+        self.conn.is_mock_connection()
+    }
+}
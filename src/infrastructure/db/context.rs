@@ -1,20 +1,110 @@
-use std::{future::Future, pin::Pin, sync::Arc};
+use std::{
+    future::Future,
+    pin::Pin,
+    sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    },
+    time::Duration,
+};
 
-use sea_orm::{DatabaseConnection, DatabaseTransaction, TransactionTrait};
+use chrono::Utc;
 
-use crate::{domain::error::DomainError, infrastructure::db::RepositoryProvider};
+use sea_orm::{
+    ConnectionTrait, DatabaseBackend, DatabaseConnection, DatabaseTransaction, DbErr, Statement,
+    TransactionTrait,
+};
+
+use crate::{
+    domain::error::DomainError,
+    infrastructure::db::{
+        LastWrite, RepositoryOverrides, RepositoryProvider, TenantScope, TxOptions, is_retryable,
+    },
+};
+
+// times the attempt number, before running a transaction again
+const RETRY_BACKOFF: Duration = Duration::from_millis(20);
 
 #[derive(Clone, Debug)]
 pub struct DbContext {
     conn: Arc<DatabaseConnection>,
+    row_level_security: bool,
+    replicas: Vec<Arc<DatabaseConnection>>,
+    next_replica: Arc<AtomicUsize>,
+    read_your_writes: Duration,
+    overrides: Option<Arc<RepositoryOverrides>>,
 }
 
 impl DbContext {
     pub fn new(conn: Arc<DatabaseConnection>) -> Self {
-        Self { conn }
+        Self {
+            conn,
+            row_level_security: false,
+            replicas: Vec::new(),
+            next_replica: Arc::new(AtomicUsize::new(0)),
+            read_your_writes: Duration::ZERO,
+            overrides: None,
+        }
+    }
+
+    /// Also hands the tenant to Postgres (`app.tenant_id`) in every transaction, so
+    /// the row-level security policies back up the filters added by the repositories,
+    /// `*` standing for every tenant. The pools of `init_db` do so on every checkout,
+    /// outside of transactions as well. The other backends have no such policies and
+    /// rely on the filters alone.
+    pub fn with_row_level_security(mut self, enabled: bool) -> Self {
+        self.row_level_security = enabled;
+        self
+    }
+
+    pub async fn transaction<T, F>(&self, tenant: TenantScope, f: F) -> Result<T, DomainError>
+    where
+        F: for<'a> FnOnce(
+            &'a RepositoryProvider<DatabaseTransaction>,
+        )
+            -> Pin<Box<dyn Future<Output = Result<T, DomainError>> + Send + 'a>>,
+        T: Send,
+    {
+        self.attempt(tenant, TxOptions::default(), f).await
+    }
+
+    /// Like `transaction`, with the isolation level and access mode of `options`, running
+    /// `f` again from the start when the database gave up on it for a serialization
+    /// failure or a deadlock, up to `options.max_attempts` times in all.
+    pub async fn transaction_with<T, F>(
+        &self,
+        tenant: TenantScope,
+        options: TxOptions,
+        f: F,
+    ) -> Result<T, DomainError>
+    where
+        F: for<'a> Fn(
+            &'a RepositoryProvider<DatabaseTransaction>,
+        ) -> Pin<Box<dyn Future<Output = Result<T, DomainError>> + Send + 'a>>,
+        T: Send,
+    {
+        let mut attempt = 1;
+        loop {
+            match self.attempt(tenant, options, |provider| f(provider)).await {
+                Err(DomainError::Storage(err))
+                    if attempt < options.max_attempts
+                        && err.downcast_ref::<DbErr>().is_some_and(is_retryable) =>
+                {
+                    tracing::warn!("transaction attempt {} failed, retrying: {}", attempt, err);
+                    tokio::time::sleep(RETRY_BACKOFF * attempt).await;
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
     }
 
-    pub async fn transaction<T, F>(&self, f: F) -> Result<T, DomainError>
+    async fn attempt<T, F>(
+        &self,
+        tenant: TenantScope,
+        options: TxOptions,
+        f: F,
+    ) -> Result<T, DomainError>
     where
         F: for<'a> FnOnce(
             &'a RepositoryProvider<DatabaseTransaction>,
@@ -22,9 +112,23 @@ impl DbContext {
             -> Pin<Box<dyn Future<Output = Result<T, DomainError>> + Send + 'a>>,
         T: Send,
     {
-        let tx = self.conn.begin().await?;
+        let tx = tenant
+            .run(
+                self.conn
+                    .begin_with_config(options.isolation, options.access_mode()),
+            )
+            .await?;
 
-        let provider = RepositoryProvider::new(&tx);
+        if self.row_level_security && tx.get_database_backend() == DatabaseBackend::Postgres {
+            tx.execute(Statement::from_sql_and_values(
+                tx.get_database_backend(),
+                "SELECT set_config('app.tenant_id', $1, true)",
+                [TenantScope::setting(Some(tenant)).into()],
+            ))
+            .await?;
+        }
+
+        let provider = RepositoryProvider::new(&tx, tenant).with_overrides(self.overrides.clone());
 
         match f(&provider).await {
             Ok(value) => {
@@ -38,16 +142,79 @@ impl DbContext {
         }
     }
 
-    pub fn provider(&self) -> RepositoryProvider<'_, DatabaseConnection> {
-        RepositoryProvider::new(&self.conn)
+    /// Serves the repositories of `overrides` from every provider and transaction instead
+    /// of the database ones, for tests to inject fakes.
+    pub fn with_repository_overrides(mut self, overrides: RepositoryOverrides) -> Self {
+        self.overrides = Some(Arc::new(overrides));
+        self
+    }
+
+    /// Pools `read_provider` spreads the reads over, in turn.
+    pub fn with_replicas(mut self, replicas: Vec<Arc<DatabaseConnection>>) -> Self {
+        self.replicas = replicas;
+        self
+    }
+
+    /// How long the reads of a client keep going to the primary after it wrote, for it
+    /// to see what it wrote despite the replication lag.
+    pub fn with_read_your_writes(mut self, window: Duration) -> Self {
+        self.read_your_writes = window;
+        self
+    }
+
+    /// The read-your-writes window, none when the reads all go to the primary anyway.
+    pub fn read_your_writes(&self) -> Option<Duration> {
+        (!self.replicas.is_empty() && !self.read_your_writes.is_zero())
+            .then_some(self.read_your_writes)
+    }
+
+    /// On the primary, for writes outside of a transaction and reads that must be
+    /// up to date.
+    pub fn provider(&self, tenant: TenantScope) -> RepositoryProvider<'_, DatabaseConnection> {
+        RepositoryProvider::new(self.conn.as_ref(), tenant).with_overrides(self.overrides.clone())
+    }
+
+    /// On a replica, unless there is none or the `LastWrite` of the client is within the
+    /// read-your-writes window. Only for reads.
+    pub fn read_provider(&self, tenant: TenantScope) -> RepositoryProvider<'_, DatabaseConnection> {
+        let wrote_recently =
+            LastWrite::current().is_some_and(|last| last.within(self.read_your_writes, Utc::now()));
+        if self.replicas.is_empty() || wrote_recently {
+            return self.provider(tenant);
+        }
+
+        let next = self.next_replica.fetch_add(1, Ordering::Relaxed);
+        RepositoryProvider::new(self.replicas[next % self.replicas.len()].as_ref(), tenant)
+            .with_overrides(self.overrides.clone())
     }
 }
 
 #[macro_export]
 macro_rules! with_transaction {
-    ($db_context:expr, $provider:ident => $body:block) => {
+    ($db_context:expr, $tenant:expr, $provider:ident => $body:block) => {
+        $db_context
+            .transaction($tenant, |$provider| Box::pin(async move { $body }))
+            .await
+    };
+    // the body may run more than once, so it gets its own clone of the listed variables
+    // on every attempt
+    ($db_context:expr, $tenant:expr, $options:expr, $provider:ident, clone($($var:ident),*) => $body:block) => {
         $db_context
-            .transaction(|$provider| Box::pin(async move { $body }))
+            .transaction_with($tenant, $options, |$provider| {
+                $(let $var = $var.clone();)*
+                Box::pin(async move { $body })
+            })
+            .await
+    };
+}
+
+/// Runs the body within a savepoint of the transaction of `$provider`, so that a use case
+/// can take part in the transaction of another one and fail alone.
+#[macro_export]
+macro_rules! with_savepoint {
+    ($provider:expr, $inner:ident => $body:block) => {
+        $provider
+            .savepoint(|$inner| Box::pin(async move { $body }))
             .await
     };
 }
@@ -1,8 +1,17 @@
 //! `SeaORM` Entity, @generated by sea-orm-codegen 2.0.0-rc.18
 
-use sea_orm::entity::prelude::*;
+use sea_orm::{ActiveValue::Set, entity::prelude::*};
+use serde::Serialize;
 
-#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+use crate::{
+    domain::{
+        error::DomainError,
+        organization::{Department, DepartmentName},
+    },
+    infrastructure::db::AuditContext,
+};
+
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel, Serialize)]
 #[sea_orm(table_name = "departments")]
 pub struct Model {
     #[sea_orm(primary_key, auto_increment = false)]
@@ -10,6 +19,13 @@ pub struct Model {
     #[sea_orm(unique)]
     pub name: String,
     pub company_id: Uuid,
+    pub version: i32,
+    pub deleted_at: Option<DateTimeWithTimeZone>,
+    pub created_at: DateTimeWithTimeZone,
+    pub updated_at: DateTimeWithTimeZone,
+    pub created_by: Option<String>,
+    pub updated_by: Option<String>,
+    pub tenant_id: Uuid,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -30,4 +46,49 @@ impl Related<super::companies::Entity> for Entity {
     }
 }
 
-impl ActiveModelBehavior for ActiveModel {}
+#[async_trait::async_trait]
+impl ActiveModelBehavior for ActiveModel {
+    async fn before_save<C>(mut self, _db: &C, insert: bool) -> Result<Self, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        let (now, actor) = AuditContext::current().stamp();
+
+        if insert {
+            self.created_at = Set(now);
+            self.created_by = Set(actor.clone());
+        }
+        self.updated_at = Set(now);
+        self.updated_by = Set(actor);
+
+        Ok(self)
+    }
+}
+
+impl TryFrom<Model> for Department {
+    type Error = DomainError;
+
+    fn try_from(m: Model) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: m.id.into(),
+            name: DepartmentName::new(m.name)?,
+            company_id: m.company_id.into(),
+            version: m.version,
+            deleted_at: m.deleted_at.map(|at| at.to_utc()),
+        })
+    }
+}
+
+/// The tenant and the audit columns are left for the repository to fill in.
+impl From<Department> for ActiveModel {
+    fn from(value: Department) -> Self {
+        Self {
+            id: Set(value.id.into()),
+            name: Set(value.name.into_inner()),
+            company_id: Set(value.company_id.into()),
+            version: Set(value.version),
+            deleted_at: Set(value.deleted_at.map(|at| at.fixed_offset())),
+            ..Default::default()
+        }
+    }
+}
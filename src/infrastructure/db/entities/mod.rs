@@ -2,5 +2,11 @@
 
 pub mod prelude;
 
+pub mod audit_log;
 pub mod companies;
 pub mod departments;
+pub mod event_log;
+pub mod jobs;
+pub mod outbox;
+pub mod webhook_deliveries;
+pub mod webhook_subscriptions;
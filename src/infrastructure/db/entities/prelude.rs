@@ -1,4 +1,10 @@
 //! `SeaORM` Entity, @generated by sea-orm-codegen 2.0.0-rc.18
 
+pub use super::audit_log::Entity as AuditLog;
 pub use super::companies::Entity as Companies;
 pub use super::departments::Entity as Departments;
+pub use super::event_log::Entity as EventLog;
+pub use super::jobs::Entity as Jobs;
+pub use super::outbox::Entity as Outbox;
+pub use super::webhook_deliveries::Entity as WebhookDeliveries;
+pub use super::webhook_subscriptions::Entity as WebhookSubscriptions;
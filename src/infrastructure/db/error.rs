@@ -0,0 +1,16 @@
+use sea_orm::{DbErr, SqlErr};
+
+use crate::domain::error::DomainError;
+
+impl From<DbErr> for DomainError {
+    fn from(e: DbErr) -> Self {
+        match e {
+            DbErr::RecordNotFound(message) => Self::NotFound(message),
+            DbErr::RecordNotInserted => Self::Duplicated(e.to_string()),
+            _ if matches!(e.sql_err(), Some(SqlErr::UniqueConstraintViolation(_))) => {
+                Self::Duplicated(e.to_string())
+            }
+            e => Self::Storage(Box::new(e)),
+        }
+    }
+}
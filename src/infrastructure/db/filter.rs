@@ -0,0 +1,104 @@
+use std::str::FromStr;
+
+use sea_orm::{
+    ColumnTrait, Condition, DatabaseBackend, DbErr, EntityTrait, Value,
+    sea_query::{Alias, Expr, Func, LikeExpr, SimpleExpr},
+};
+
+use crate::application::dtos::filter::{FilterClause, FilterOp, FilterValue};
+
+/// Turns the clauses of a list filter into a condition on the columns of `E`, which are
+/// looked up by name. `like` matches the same rows whatever the `backend`.
+pub fn filter_condition<E>(
+    backend: DatabaseBackend,
+    clauses: &[FilterClause],
+) -> Result<Condition, DbErr>
+where
+    E: EntityTrait,
+    E::Column: FromStr,
+{
+    let mut condition = Condition::all();
+
+    for clause in clauses {
+        let column = E::Column::from_str(clause.field)
+            .map_err(|_| DbErr::Custom(format!("{} is not a column", clause.field)))?;
+
+        condition = condition.add(expression(backend, column, clause));
+    }
+
+    Ok(condition)
+}
+
+fn expression<C: ColumnTrait>(
+    backend: DatabaseBackend,
+    column: C,
+    clause: &FilterClause,
+) -> SimpleExpr {
+    let mut values = clause.values.iter().cloned().map(value);
+    let first = values.next().unwrap_or(Value::String(None));
+
+    match clause.op {
+        FilterOp::Eq => column.eq(first),
+        FilterOp::Ne => column.ne(first),
+        FilterOp::Gt => column.gt(first),
+        FilterOp::Gte => column.gte(first),
+        FilterOp::Lt => column.lt(first),
+        FilterOp::Lte => column.lte(first),
+        FilterOp::Like => like(backend, column.into_expr().into(), &text(&clause.values)),
+        FilterOp::Ilike => like(
+            backend,
+            Func::lower(column.into_expr()).into(),
+            &text(&clause.values).to_lowercase(),
+        ),
+        FilterOp::In => column.is_in(std::iter::once(first).chain(values)),
+        FilterOp::IsNull => match clause.values.first() {
+            Some(FilterValue::Bool(false)) => column.is_not_null(),
+            _ => column.is_null(),
+        },
+    }
+}
+
+/// Whether `expr` contains `text`, telling the case of letters apart like Postgres does.
+fn like(backend: DatabaseBackend, expr: SimpleExpr, text: &str) -> SimpleExpr {
+    match backend {
+        // LIKE ignores the case of ASCII letters there
+        DatabaseBackend::Sqlite => Expr::expr(
+            Func::cust(Alias::new("instr"))
+                .arg(expr)
+                .arg(text.to_owned()),
+        )
+        .gt(0),
+        // and there, the case and accents of all of them with the usual collations
+        DatabaseBackend::MySql => {
+            Expr::cust_with_expr("$1 COLLATE utf8mb4_bin", expr).like(contains(text))
+        }
+        DatabaseBackend::Postgres => Expr::expr(expr).like(contains(text)),
+    }
+}
+
+/// A pattern matching `text` anywhere, wildcards in it included.
+pub(crate) fn contains(text: &str) -> LikeExpr {
+    let escaped = text
+        .replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_");
+
+    LikeExpr::new(format!("%{}%", escaped)).escape('\\')
+}
+
+fn text(values: &[FilterValue]) -> String {
+    match values.first() {
+        Some(FilterValue::Text(text)) => text.clone(),
+        _ => String::new(),
+    }
+}
+
+fn value(value: FilterValue) -> Value {
+    match value {
+        FilterValue::Text(text) => text.into(),
+        FilterValue::Uuid(id) => id.into(),
+        FilterValue::Integer(number) => number.into(),
+        FilterValue::DateTime(at) => at.into(),
+        FilterValue::Bool(flag) => flag.into(),
+    }
+}
@@ -3,10 +3,74 @@ use std::sync::Arc;
 use migration::{Migrator, MigratorTrait};
 use sea_orm::{Database, DatabaseConnection};
 
-pub async fn init_db(db_connect_str: &str) -> Result<Arc<DatabaseConnection>, anyhow::Error> {
-    let db = Database::connect(db_connect_str).await?;
+/// With `row_level_security`, every connection of a Postgres pool is handed the tenant
+/// of the query checking it out (`app.tenant_id`, see `TenantConnection`), and nothing
+/// when the query did not come from a repository, for the policies to deny it.
+pub async fn init_db(
+    db_connect_str: &str,
+    row_level_security: bool,
+) -> Result<Arc<DatabaseConnection>, anyhow::Error> {
+    let db = connect(db_connect_str, row_level_security).await?;
 
     Migrator::up(&db, None).await?;
 
     Ok(Arc::new(db))
 }
+
+/// Replicas are kept up to date by the primary, hence no migrations.
+pub async fn init_replicas(
+    db_connect_strs: &[String],
+    row_level_security: bool,
+) -> Result<Vec<Arc<DatabaseConnection>>, anyhow::Error> {
+    let mut replicas = Vec::with_capacity(db_connect_strs.len());
+    for db_connect_str in db_connect_strs {
+        replicas.push(Arc::new(connect(db_connect_str, row_level_security).await?));
+    }
+
+    Ok(replicas)
+}
+
+#[cfg(feature = "postgres")]
+async fn connect(
+    db_connect_str: &str,
+    row_level_security: bool,
+) -> Result<DatabaseConnection, anyhow::Error> {
+    use sea_orm::{
+        SqlxPostgresConnector,
+        sqlx::{PgConnection, postgres::PgPoolOptions},
+    };
+
+    use super::TenantScope;
+
+    async fn hand_over_tenant(conn: &mut PgConnection) -> Result<(), sea_orm::sqlx::Error> {
+        sea_orm::sqlx::query("SELECT set_config('app.tenant_id', $1, false)")
+            .bind(TenantScope::setting(TenantScope::checkout()))
+            .execute(conn)
+            .await?;
+
+        Ok(())
+    }
+
+    if !row_level_security || !db_connect_str.starts_with("postgres") {
+        return Ok(Database::connect(db_connect_str).await?);
+    }
+
+    // both run in the task checking the connection out, within its `TenantScope::run`
+    let pool = PgPoolOptions::new()
+        .after_connect(|conn, _| Box::pin(hand_over_tenant(conn)))
+        .before_acquire(|conn, _| {
+            Box::pin(async move { hand_over_tenant(conn).await.map(|_| true) })
+        })
+        .connect(db_connect_str)
+        .await?;
+
+    Ok(SqlxPostgresConnector::from_sqlx_postgres_pool(pool))
+}
+
+#[cfg(not(feature = "postgres"))]
+async fn connect(
+    db_connect_str: &str,
+    _row_level_security: bool,
+) -> Result<DatabaseConnection, anyhow::Error> {
+    Ok(Database::connect(db_connect_str).await?)
+}
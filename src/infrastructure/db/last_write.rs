@@ -0,0 +1,54 @@
+use std::{future::Future, time::Duration};
+
+use chrono::{DateTime, TimeDelta, Utc};
+
+// how far ahead of this instance the clock of the one that stamped a write may be
+const CLOCK_SKEW: Duration = Duration::from_secs(5);
+
+tokio::task_local! {
+    static LAST_WRITE: LastWrite;
+}
+
+/// When the client of the running request last wrote, as it told along with the
+/// request. Bound to the task serving it, so that `DbContext::read_provider` can keep its
+/// reads on the primary until the replicas have caught up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LastWrite(pub DateTime<Utc>);
+
+impl LastWrite {
+    pub fn now() -> Self {
+        Self(Utc::now())
+    }
+
+    pub async fn scope<F: Future>(self, f: F) -> F::Output {
+        LAST_WRITE.scope(self, f).await
+    }
+
+    /// The one of the running task, none outside of a request or when the client did
+    /// not tell.
+    pub fn current() -> Option<Self> {
+        LAST_WRITE.try_with(|w| *w).ok()
+    }
+
+    /// Whether it happened less than `window` before `now`. A marker slightly ahead of
+    /// `now` is taken as recent, the clocks of the instances being a little apart, but
+    /// not one further in the future, which the client made up to stay on the primary.
+    pub fn within(&self, window: Duration, now: DateTime<Utc>) -> bool {
+        let (Ok(window), Ok(skew)) = (TimeDelta::from_std(window), TimeDelta::from_std(CLOCK_SKEW))
+        else {
+            return false;
+        };
+
+        let age = now - self.0;
+        age < window && age > -skew
+    }
+
+    /// Milliseconds since the epoch, the way it is told to clients.
+    pub fn to_millis(self) -> i64 {
+        self.0.timestamp_millis()
+    }
+
+    pub fn from_millis(millis: i64) -> Option<Self> {
+        DateTime::from_timestamp_millis(millis).map(Self)
+    }
+}
@@ -1,13 +1,33 @@
+mod audit;
+pub use audit::*;
+
 mod init;
 pub use init::*;
 
+mod connection;
+pub use connection::*;
+
 pub mod entities;
 
+mod error;
+
+mod filter;
+pub use filter::*;
+
 mod repository;
 pub use repository::*;
 
 mod context;
 pub use context::*;
 
+mod last_write;
+pub use last_write::*;
+
 mod provider;
 pub use provider::*;
+
+mod tenant;
+pub use tenant::*;
+
+mod transaction;
+pub use transaction::*;
@@ -1,24 +1,148 @@
-use sea_orm::ConnectionTrait;
+use std::{fmt, sync::Arc};
+
+use sea_orm::{ConnectionTrait, DatabaseTransaction, DbErr, StreamTrait, TransactionTrait};
 
 use crate::{
-    domain::organization::repositories::{CompanyRepository, DepartmentRepository},
-    infrastructure::db::Repository,
+    domain::{
+        audit::repositories::AuditRepository,
+        events::repositories::{EventLogRepository, OutboxRepository},
+        job::repositories::JobRepository,
+        organization::repositories::{
+            CompanyRepository, DepartmentRepository, ExportRepository, SearchRepository,
+        },
+        webhook::repositories::{WebhookDeliveryRepository, WebhookRepository},
+    },
+    infrastructure::db::{Repository, TenantScope},
 };
 
+macro_rules! repository_overrides {
+    ($($name:ident, $with:ident: $repo:ident),* $(,)?) => {
+        /// Repositories served in place of the database ones, so that tests can fake the
+        /// repositories a use case depends on and leave the others to the database.
+        #[derive(Clone, Default)]
+        pub struct RepositoryOverrides {
+            $($name: Option<Arc<dyn $repo>>,)*
+        }
+
+        impl RepositoryOverrides {
+            $(
+                pub fn $with(mut self, repo: Arc<dyn $repo>) -> Self {
+                    self.$name = Some(repo);
+                    self
+                }
+            )*
+        }
+
+        impl fmt::Debug for RepositoryOverrides {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                let mut list = f.debug_list();
+                $(
+                    if self.$name.is_some() {
+                        list.entry(&stringify!($name));
+                    }
+                )*
+                list.finish()
+            }
+        }
+    };
+}
+
+repository_overrides! {
+    company_repo, with_company_repo: CompanyRepository,
+    department_repo, with_department_repo: DepartmentRepository,
+    search_repo, with_search_repo: SearchRepository,
+    export_repo, with_export_repo: ExportRepository,
+    audit_repo, with_audit_repo: AuditRepository,
+    outbox_repo, with_outbox_repo: OutboxRepository,
+    event_log_repo, with_event_log_repo: EventLogRepository,
+    job_repo, with_job_repo: JobRepository,
+    webhook_repo, with_webhook_repo: WebhookRepository,
+    webhook_delivery_repo, with_webhook_delivery_repo: WebhookDeliveryRepository,
+}
+
+// the override of `$name` if there is one, the database repository otherwise
+macro_rules! serve {
+    ($provider:ident, $name:ident) => {
+        match $provider.overrides.as_ref().and_then(|o| o.$name.clone()) {
+            Some(repo) => repo,
+            None => Arc::new(Repository::new($provider.c, $provider.tenant)),
+        }
+    };
+}
+
 pub struct RepositoryProvider<'a, C: ConnectionTrait> {
     pub c: &'a C,
+    pub tenant: TenantScope,
+    overrides: Option<Arc<RepositoryOverrides>>,
 }
 
 impl<'a, C: ConnectionTrait> RepositoryProvider<'a, C> {
-    pub fn new(c: &'a C) -> Self {
-        Self { c }
+    pub fn new(c: &'a C, tenant: TenantScope) -> Self {
+        Self {
+            c,
+            tenant,
+            overrides: None,
+        }
+    }
+
+    pub fn with_overrides(mut self, overrides: Option<Arc<RepositoryOverrides>>) -> Self {
+        self.overrides = overrides;
+        self
+    }
+
+    /// The same repositories on another connection, such as a savepoint of this one.
+    pub fn on<'b, D: ConnectionTrait>(&self, c: &'b D) -> RepositoryProvider<'b, D> {
+        RepositoryProvider::new(c, self.tenant).with_overrides(self.overrides.clone())
+    }
+
+    /// A transaction, or a savepoint when `c` already is one, with the connection it
+    /// checks out handed the tenant.
+    pub async fn begin(&self) -> Result<DatabaseTransaction, DbErr>
+    where
+        C: TransactionTrait,
+    {
+        self.tenant.run(self.c.begin()).await
+    }
+
+    pub fn company_repo(&self) -> Arc<dyn CompanyRepository + 'a> {
+        serve!(self, company_repo)
+    }
+
+    pub fn department_repo(&self) -> Arc<dyn DepartmentRepository + 'a> {
+        serve!(self, department_repo)
+    }
+
+    pub fn search_repo(&self) -> Arc<dyn SearchRepository + 'a> {
+        serve!(self, search_repo)
+    }
+
+    pub fn audit_repo(&self) -> Arc<dyn AuditRepository + 'a> {
+        serve!(self, audit_repo)
+    }
+
+    pub fn outbox_repo(&self) -> Arc<dyn OutboxRepository + 'a> {
+        serve!(self, outbox_repo)
+    }
+
+    pub fn event_log_repo(&self) -> Arc<dyn EventLogRepository + 'a> {
+        serve!(self, event_log_repo)
+    }
+
+    pub fn job_repo(&self) -> Arc<dyn JobRepository + 'a> {
+        serve!(self, job_repo)
+    }
+
+    pub fn webhook_repo(&self) -> Arc<dyn WebhookRepository + 'a> {
+        serve!(self, webhook_repo)
     }
 
-    pub fn company_repo(&self) -> impl CompanyRepository {
-        Repository::new(self.c)
+    pub fn webhook_delivery_repo(&self) -> Arc<dyn WebhookDeliveryRepository + 'a> {
+        serve!(self, webhook_delivery_repo)
     }
+}
 
-    pub fn department_repo(&self) -> impl DepartmentRepository {
-        Repository::new(self.c)
+impl<'a, C: ConnectionTrait + StreamTrait + Send + Sync> RepositoryProvider<'a, C> {
+    pub fn export_repo(&self) -> Arc<dyn ExportRepository + 'a> {
+        serve!(self, export_repo)
     }
 }
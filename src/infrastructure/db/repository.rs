@@ -1,11 +1,58 @@
-use sea_orm::ConnectionTrait;
+use sea_orm::{
+    ActiveModelTrait, ConnectionTrait, DeleteMany, EntityTrait, PrimaryKeyTrait, Select,
+    UpdateMany, UpdateOne,
+};
 
+use super::{TenantConnection, TenantOwned, TenantScope};
+
+/// Reaches the tables of a tenant only through `find`, `update_many` and the like, which
+/// restrict the statements to its rows, and runs them on a connection Postgres knows
+/// the tenant of, for row-level security to back up those filters.
 pub struct Repository<'a, C: ConnectionTrait> {
-    pub db: &'a C,
+    pub db: TenantConnection<'a, C>,
+    pub tenant: TenantScope,
 }
 
 impl<'a, C: ConnectionTrait> Repository<'a, C> {
-    pub fn new(db: &'a C) -> Self {
-        Self { db }
+    pub fn new(db: &'a C, tenant: TenantScope) -> Self {
+        Self {
+            db: TenantConnection::new(db, tenant),
+            tenant,
+        }
+    }
+
+    pub fn find<E: TenantOwned>(&self) -> Select<E> {
+        self.tenant.apply(E::find(), E::tenant_column())
+    }
+
+    pub fn find_by_id<E: TenantOwned>(
+        &self,
+        id: impl Into<<E::PrimaryKey as PrimaryKeyTrait>::ValueType>,
+    ) -> Select<E> {
+        self.tenant.apply(E::find_by_id(id), E::tenant_column())
+    }
+
+    pub fn update<A>(&self, model: A) -> UpdateOne<A>
+    where
+        A: ActiveModelTrait,
+        A::Entity: TenantOwned,
+    {
+        self.tenant
+            .apply(A::Entity::update(model), A::Entity::tenant_column())
+    }
+
+    pub fn update_many<E: TenantOwned>(&self) -> UpdateMany<E> {
+        self.tenant.apply(E::update_many(), E::tenant_column())
+    }
+
+    pub fn delete_many<E: TenantOwned>(&self) -> DeleteMany<E> {
+        self.tenant.apply(E::delete_many(), E::tenant_column())
+    }
+
+    pub fn delete_by_id<E: TenantOwned>(
+        &self,
+        id: impl Into<<E::PrimaryKey as PrimaryKeyTrait>::ValueType>,
+    ) -> DeleteMany<E> {
+        self.tenant.apply(E::delete_by_id(id), E::tenant_column())
     }
 }
@@ -0,0 +1,103 @@
+use std::future::Future;
+
+use sea_orm::{ColumnTrait, EntityTrait, QueryFilter};
+use uuid::Uuid;
+
+use crate::{
+    domain::error::{DomainError, ErrorKind},
+    infrastructure::db::entities,
+};
+
+/// Tenant owning the rows that existed before multi-tenancy was introduced.
+pub const DEFAULT_TENANT_ID: Uuid = Uuid::nil();
+
+/// Which tenant's rows repositories are allowed to see. Requests are always bound to
+/// a single tenant, `All` is meant for maintenance work such as background jobs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TenantScope {
+    Tenant(Uuid),
+    All,
+}
+
+impl TenantScope {
+    pub fn id(&self) -> Option<Uuid> {
+        match self {
+            Self::Tenant(id) => Some(*id),
+            Self::All => None,
+        }
+    }
+
+    /// Tenant new rows belong to.
+    pub fn require(&self) -> Result<Uuid, DomainError> {
+        self.id().ok_or_else(|| {
+            DomainError::CaseError(
+                ErrorKind::BadRequest,
+                "TENANT_REQUIRED".to_string(),
+                "a tenant is required to create records".to_string(),
+            )
+        })
+    }
+
+    pub fn apply<Q: QueryFilter>(&self, query: Q, column: impl ColumnTrait) -> Q {
+        match self {
+            Self::Tenant(id) => query.filter(column.eq(*id)),
+            Self::All => query,
+        }
+    }
+}
+
+tokio::task_local! {
+    // the tenant of the repository running the current query, for the pool to hand to
+    // Postgres when it checks out a connection
+    static CHECKOUT_TENANT: TenantScope;
+}
+
+impl TenantScope {
+    /// The value of `app.tenant_id` the row-level security policies compare against:
+    /// the tenant id, `*` for every tenant, empty for none at all.
+    pub fn setting(scope: Option<Self>) -> String {
+        match scope {
+            Some(Self::Tenant(id)) => id.to_string(),
+            Some(Self::All) => "*".to_string(),
+            None => String::new(),
+        }
+    }
+
+    /// The tenant `future` runs its queries for, see `TenantScope::checkout`.
+    pub fn run<F: Future>(self, future: F) -> impl Future<Output = F::Output> {
+        CHECKOUT_TENANT.scope(self, future)
+    }
+
+    /// The tenant of the query checking out a connection, if it came from a repository.
+    pub fn checkout() -> Option<Self> {
+        CHECKOUT_TENANT.try_with(|tenant| *tenant).ok()
+    }
+}
+
+/// Tables whose rows belong to a tenant, see `Repository::find` and the like.
+pub trait TenantOwned: EntityTrait {
+    fn tenant_column() -> Self::Column;
+}
+
+macro_rules! tenant_owned {
+    ($($entity:ident),* $(,)?) => {
+        $(
+            impl TenantOwned for entities::$entity::Entity {
+                fn tenant_column() -> Self::Column {
+                    entities::$entity::Column::TenantId
+                }
+            }
+        )*
+    };
+}
+
+tenant_owned!(
+    audit_log,
+    companies,
+    departments,
+    event_log,
+    jobs,
+    outbox,
+    webhook_deliveries,
+    webhook_subscriptions,
+);
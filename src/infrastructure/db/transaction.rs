@@ -0,0 +1,118 @@
+use std::{future::Future, pin::Pin};
+
+use sea_orm::{AccessMode, DatabaseTransaction, DbErr, IsolationLevel, RuntimeErr};
+
+use crate::{domain::error::DomainError, infrastructure::db::RepositoryProvider};
+
+const DEFAULT_RETRY_ATTEMPTS: u32 = 3;
+
+// SQLSTATE of serialization failures and of deadlocks
+const RETRYABLE_CODES: [&str; 2] = ["40001", "40P01"];
+
+/// How `DbContext::transaction_with` opens its transaction.
+#[derive(Clone, Copy, Debug)]
+pub struct TxOptions {
+    pub isolation: Option<IsolationLevel>,
+    pub read_only: bool,
+    pub max_attempts: u32,
+}
+
+impl Default for TxOptions {
+    fn default() -> Self {
+        Self {
+            isolation: None,
+            read_only: false,
+            max_attempts: 1,
+        }
+    }
+}
+
+impl TxOptions {
+    /// For check-then-write sequences, which the database may abort when they interleave,
+    /// hence the retries.
+    pub fn serializable() -> Self {
+        Self::default()
+            .with_isolation(IsolationLevel::Serializable)
+            .with_max_attempts(DEFAULT_RETRY_ATTEMPTS)
+    }
+
+    pub fn repeatable_read() -> Self {
+        Self::default()
+            .with_isolation(IsolationLevel::RepeatableRead)
+            .with_max_attempts(DEFAULT_RETRY_ATTEMPTS)
+    }
+
+    /// For reads that must see one snapshot.
+    pub fn read_only() -> Self {
+        Self {
+            read_only: true,
+            ..Self::repeatable_read()
+        }
+    }
+
+    pub fn with_isolation(mut self, isolation: IsolationLevel) -> Self {
+        self.isolation = Some(isolation);
+        self
+    }
+
+    pub fn with_read_only(mut self, read_only: bool) -> Self {
+        self.read_only = read_only;
+        self
+    }
+
+    pub fn with_max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    pub(crate) fn access_mode(&self) -> Option<AccessMode> {
+        self.read_only.then_some(AccessMode::ReadOnly)
+    }
+}
+
+/// Whether running the transaction again may succeed, as the database aborted it for
+/// conflicting with another one rather than for what it did.
+pub fn is_retryable(err: &DbErr) -> bool {
+    let (DbErr::Conn(RuntimeErr::SqlxError(err))
+    | DbErr::Exec(RuntimeErr::SqlxError(err))
+    | DbErr::Query(RuntimeErr::SqlxError(err))) = err
+    else {
+        return false;
+    };
+
+    err.as_database_error()
+        .and_then(|e| e.code())
+        .is_some_and(|code| RETRYABLE_CODES.contains(&code.as_ref()))
+}
+
+impl RepositoryProvider<'_, DatabaseTransaction> {
+    /// Runs `f` within a savepoint, which is released when it succeeds and rolled back,
+    /// without the rest of the transaction, when it fails. The error of `f` is its own, so
+    /// that it can carry what it did up to the failure.
+    pub async fn savepoint<T, E, F>(&self, f: F) -> Result<T, E>
+    where
+        F: for<'a> FnOnce(
+            &'a RepositoryProvider<DatabaseTransaction>,
+        ) -> Pin<Box<dyn Future<Output = Result<T, E>> + Send + 'a>>,
+        T: Send,
+        E: From<DomainError>,
+    {
+        let savepoint = self.begin().await.map_err(storage)?;
+        let provider = self.on(&savepoint);
+
+        match f(&provider).await {
+            Ok(value) => {
+                savepoint.commit().await.map_err(storage)?;
+                Ok(value)
+            }
+            Err(err) => {
+                savepoint.rollback().await.map_err(storage)?;
+                Err(err)
+            }
+        }
+    }
+}
+
+fn storage<E: From<DomainError>>(err: DbErr) -> E {
+    DomainError::from(err).into()
+}
@@ -0,0 +1,41 @@
+use async_trait::async_trait;
+
+use crate::{domain::events::OutboxMessage, infrastructure::events::EventSink};
+
+/// Client of a message broker (Kafka, NATS, RabbitMQ, ...).
+#[async_trait]
+pub trait MessageBroker: Send + Sync {
+    async fn send(&self, topic: &str, key: &str, payload: Vec<u8>) -> anyhow::Result<()>;
+}
+
+/// Publishes each message on the topic named after its event type, keyed by the
+/// aggregate so that brokers which partition by key keep its events in order.
+pub struct BrokerSink<B: MessageBroker> {
+    broker: B,
+    topic_prefix: String,
+}
+
+impl<B: MessageBroker> BrokerSink<B> {
+    pub fn new(broker: B, topic_prefix: &str) -> Self {
+        Self {
+            broker,
+            topic_prefix: topic_prefix.to_string(),
+        }
+    }
+}
+
+#[async_trait]
+impl<B: MessageBroker> EventSink for BrokerSink<B> {
+    fn name(&self) -> &str {
+        "broker"
+    }
+
+    async fn publish(&self, message: &OutboxMessage) -> anyhow::Result<()> {
+        let topic = format!("{}{}", self.topic_prefix, message.event_type);
+        let payload = serde_json::to_vec(message)?;
+
+        self.broker
+            .send(&topic, &message.aggregate_id.to_string(), payload)
+            .await
+    }
+}
@@ -0,0 +1,35 @@
+use async_trait::async_trait;
+use tokio::sync::broadcast;
+
+use crate::{domain::events::OutboxMessage, infrastructure::events::EventSink};
+
+/// Fans messages out to subscribers living in this process. Subscribers that fall
+/// behind by more than `capacity` messages miss the oldest ones.
+#[derive(Debug, Clone)]
+pub struct EventBus {
+    sender: broadcast::Sender<OutboxMessage>,
+}
+
+impl EventBus {
+    pub fn new(capacity: usize) -> Self {
+        let (sender, _) = broadcast::channel(capacity);
+        Self { sender }
+    }
+
+    pub fn subscribe(&self) -> broadcast::Receiver<OutboxMessage> {
+        self.sender.subscribe()
+    }
+}
+
+#[async_trait]
+impl EventSink for EventBus {
+    fn name(&self) -> &str {
+        "in-process"
+    }
+
+    async fn publish(&self, message: &OutboxMessage) -> anyhow::Result<()> {
+        // nobody listening is not a failure
+        let _ = self.sender.send(message.clone());
+        Ok(())
+    }
+}
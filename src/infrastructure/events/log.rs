@@ -0,0 +1,42 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::{
+    domain::events::OutboxMessage,
+    infrastructure::{
+        db::{DbContext, TenantScope},
+        events::EventSink,
+    },
+    with_transaction,
+};
+
+/// Keeps the messages in the event log the change feed is read from, numbered in the
+/// order they are published. To be registered before the `EventBus` which wakes the
+/// feed up.
+pub struct EventLogSink {
+    db_context: Arc<DbContext>,
+}
+
+impl EventLogSink {
+    pub fn new(db_context: Arc<DbContext>) -> Self {
+        Self { db_context }
+    }
+}
+
+#[async_trait]
+impl EventSink for EventLogSink {
+    fn name(&self) -> &str {
+        "event-log"
+    }
+
+    async fn publish(&self, message: &OutboxMessage) -> anyhow::Result<()> {
+        let message = message.clone();
+
+        with_transaction!(self.db_context, TenantScope::All, provider => {
+            provider.event_log_repo().append(&message).await
+        })?;
+
+        Ok(())
+    }
+}
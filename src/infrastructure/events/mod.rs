@@ -0,0 +1,13 @@
+mod broker;
+mod bus;
+mod log;
+mod sink;
+mod subscriptions;
+mod webhook;
+
+pub use broker::*;
+pub use bus::*;
+pub use log::*;
+pub use sink::*;
+pub use subscriptions::*;
+pub use webhook::*;
@@ -0,0 +1,11 @@
+use async_trait::async_trait;
+
+use crate::domain::events::OutboxMessage;
+
+/// Destination of the messages relayed from the outbox. Delivery is at-least-once:
+/// a message is handed to every sink again when any of them failed.
+#[async_trait]
+pub trait EventSink: Send + Sync {
+    fn name(&self) -> &str;
+    async fn publish(&self, message: &OutboxMessage) -> anyhow::Result<()>;
+}
@@ -0,0 +1,40 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::{
+    domain::events::OutboxMessage,
+    infrastructure::{
+        db::{DbContext, TenantScope},
+        events::EventSink,
+    },
+};
+
+/// Queues a webhook delivery for every subscription interested in the message; the
+/// `WebhookDeliveryWorker` sends them.
+pub struct WebhookSubscriptionSink {
+    db_context: Arc<DbContext>,
+}
+
+impl WebhookSubscriptionSink {
+    pub fn new(db_context: Arc<DbContext>) -> Self {
+        Self { db_context }
+    }
+}
+
+#[async_trait]
+impl EventSink for WebhookSubscriptionSink {
+    fn name(&self) -> &str {
+        "webhook-subscriptions"
+    }
+
+    async fn publish(&self, message: &OutboxMessage) -> anyhow::Result<()> {
+        self.db_context
+            .provider(TenantScope::All)
+            .webhook_delivery_repo()
+            .enqueue(message)
+            .await?;
+
+        Ok(())
+    }
+}
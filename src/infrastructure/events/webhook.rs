@@ -0,0 +1,41 @@
+use std::time::Duration;
+
+use async_trait::async_trait;
+
+use crate::{domain::events::OutboxMessage, infrastructure::events::EventSink};
+
+/// Posts every message as JSON to a single endpoint.
+pub struct WebhookSink {
+    client: reqwest::Client,
+    url: String,
+}
+
+impl WebhookSink {
+    pub fn new(url: String) -> anyhow::Result<Self> {
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(10))
+            .build()?;
+
+        Ok(Self { client, url })
+    }
+}
+
+#[async_trait]
+impl EventSink for WebhookSink {
+    fn name(&self) -> &str {
+        "webhook"
+    }
+
+    async fn publish(&self, message: &OutboxMessage) -> anyhow::Result<()> {
+        self.client
+            .post(&self.url)
+            .header("X-Event-Id", message.id.to_string())
+            .header("X-Event-Type", &message.event_type)
+            .json(message)
+            .send()
+            .await?
+            .error_for_status()?;
+
+        Ok(())
+    }
+}
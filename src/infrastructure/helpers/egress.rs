@@ -0,0 +1,77 @@
+use std::{
+    net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr},
+    sync::Arc,
+};
+
+use reqwest::dns::{Addrs, Name, Resolve, Resolving};
+
+/// Whether an outbound request may reach `ip`: anything but the loopback, private,
+/// link-local (cloud metadata endpoints included), shared, multicast and unspecified
+/// ranges, so that tenants cannot point requests at the internal network.
+pub fn is_public(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => is_public_v4(ip),
+        IpAddr::V6(ip) => match ip.to_ipv4_mapped() {
+            Some(ip) => is_public_v4(ip),
+            None => is_public_v6(ip),
+        },
+    }
+}
+
+fn is_public_v4(ip: Ipv4Addr) -> bool {
+    let [a, b, ..] = ip.octets();
+
+    !(ip.is_unspecified()
+        || ip.is_loopback()
+        || ip.is_private()
+        || ip.is_link_local()
+        || ip.is_broadcast()
+        || ip.is_multicast()
+        || ip.is_documentation()
+        // shared address space, 100.64.0.0/10
+        || (a == 100 && (b & 0b1100_0000) == 64)
+        // "this network", 0.0.0.0/8
+        || a == 0)
+}
+
+fn is_public_v6(ip: Ipv6Addr) -> bool {
+    let first = ip.segments()[0];
+
+    !(ip.is_unspecified()
+        || ip.is_loopback()
+        || ip.is_multicast()
+        // unique local, fc00::/7
+        || (first & 0xfe00) == 0xfc00
+        // link-local, fe80::/10
+        || (first & 0xffc0) == 0xfe80)
+}
+
+/// Resolves names like the system does, then drops the addresses that are not public,
+/// failing when none is left. Checking the addresses actually connected to, rather
+/// than the host of the URL, also catches names pointing at the internal network.
+#[derive(Debug, Default)]
+pub struct PublicResolver;
+
+impl PublicResolver {
+    pub fn shared() -> Arc<Self> {
+        Arc::new(Self)
+    }
+}
+
+impl Resolve for PublicResolver {
+    fn resolve(&self, name: Name) -> Resolving {
+        Box::pin(async move {
+            let host = name.as_str().to_string();
+            let addrs: Vec<SocketAddr> = tokio::net::lookup_host((host.as_str(), 0))
+                .await?
+                .filter(|addr| is_public(addr.ip()))
+                .collect();
+
+            if addrs.is_empty() {
+                return Err(format!("{} does not resolve to a public address", host).into());
+            }
+
+            Ok(Box::new(addrs.into_iter()) as Addrs)
+        })
+    }
+}
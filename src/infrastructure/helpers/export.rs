@@ -0,0 +1,205 @@
+use axum::body::Bytes;
+use chrono::{DateTime, FixedOffset};
+use rust_xlsxwriter::Workbook;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::domain::error::{DomainError, ErrorKind};
+
+// streamed formats are sent in chunks of about this size rather than row by row
+const CHUNK_SIZE: usize = 64 * 1024;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ExportFormat {
+    Csv,
+    Ndjson,
+    Xlsx,
+}
+
+impl ExportFormat {
+    const ALL: [Self; 3] = [Self::Csv, Self::Ndjson, Self::Xlsx];
+
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            Self::Csv => "text/csv; charset=utf-8",
+            Self::Ndjson => "application/x-ndjson",
+            Self::Xlsx => "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet",
+        }
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Self::Csv => "csv",
+            Self::Ndjson => "ndjson",
+            Self::Xlsx => "xlsx",
+        }
+    }
+
+    /// An explicit `format` wins over the `Accept` header, CSV being the default when
+    /// neither says anything.
+    pub fn negotiate(format: Option<Self>, accept: Option<&str>) -> Result<Self, DomainError> {
+        if let Some(format) = format {
+            return Ok(format);
+        }
+
+        let Some(accept) = accept.filter(|a| !a.trim().is_empty()) else {
+            return Ok(Self::Csv);
+        };
+
+        for media in accept.split(',') {
+            let media = media.split(';').next().unwrap_or_default().trim();
+
+            if matches!(media, "*/*" | "text/*") {
+                return Ok(Self::Csv);
+            }
+            if let Some(format) = Self::ALL.into_iter().find(|f| {
+                f.content_type()
+                    .split(';')
+                    .next()
+                    .is_some_and(|c| c.eq_ignore_ascii_case(media))
+            }) {
+                return Ok(format);
+            }
+            if media == "application/jsonl" {
+                return Ok(Self::Ndjson);
+            }
+        }
+
+        Err(DomainError::CaseError(
+            ErrorKind::NotAcceptable,
+            "EXPORT_FORMAT_UNSUPPORTED".to_string(),
+            "exports are available as text/csv, application/x-ndjson or xlsx".to_string(),
+        ))
+    }
+}
+
+pub enum ExportValue {
+    Text(String),
+    Integer(i64),
+    DateTime(DateTime<FixedOffset>),
+}
+
+impl ExportValue {
+    fn to_text(&self) -> String {
+        match self {
+            Self::Text(text) => text.clone(),
+            Self::Integer(value) => value.to_string(),
+            Self::DateTime(value) => value.to_rfc3339(),
+        }
+    }
+
+    /// Like `to_text`, with text a spreadsheet would take for a formula quoted by a
+    /// leading `'`, not to run what someone stored in a name when the file is opened.
+    fn to_csv_text(&self) -> String {
+        match self {
+            Self::Text(text) if text.starts_with(['=', '+', '-', '@', '\t', '\r']) => {
+                format!("'{}", text)
+            }
+            value => value.to_text(),
+        }
+    }
+}
+
+/// A row of an export. NDJSON lines are its serde representation, so its fields should
+/// be named after `columns`.
+pub trait ExportRow: Serialize {
+    fn columns() -> &'static [&'static str];
+    fn values(&self) -> Vec<ExportValue>;
+}
+
+/// Encodes rows one at a time. CSV and NDJSON are handed back in chunks as they fill up,
+/// while a workbook can only be produced at the end; its rows are kept in a temporary
+/// file meanwhile, not in memory.
+pub struct ExportEncoder {
+    format: ExportFormat,
+    buffer: Vec<u8>,
+    workbook: Option<Workbook>,
+    rows: u32,
+}
+
+impl ExportEncoder {
+    pub fn new<T: ExportRow>(format: ExportFormat, sheet: &str) -> anyhow::Result<Self> {
+        let mut encoder = Self {
+            format,
+            buffer: Vec::new(),
+            workbook: None,
+            rows: 0,
+        };
+
+        match format {
+            ExportFormat::Csv => encoder.write_csv_record(T::columns().iter().copied())?,
+            ExportFormat::Ndjson => {}
+            ExportFormat::Xlsx => {
+                let mut workbook = Workbook::new();
+                let worksheet = workbook.add_worksheet_with_constant_memory();
+                worksheet.set_name(sheet)?;
+
+                for (col, column) in T::columns().iter().enumerate() {
+                    worksheet.write_string(0, col as u16, *column)?;
+                }
+                encoder.workbook = Some(workbook);
+            }
+        }
+
+        Ok(encoder)
+    }
+
+    /// Returns a chunk once enough rows have been encoded.
+    pub fn write<T: ExportRow>(&mut self, row: &T) -> anyhow::Result<Option<Bytes>> {
+        self.rows += 1;
+
+        match self.format {
+            ExportFormat::Csv => {
+                let values = row.values();
+                self.write_csv_record(values.iter().map(ExportValue::to_csv_text))?;
+            }
+            ExportFormat::Ndjson => {
+                serde_json::to_writer(&mut self.buffer, row)?;
+                self.buffer.push(b'\n');
+            }
+            ExportFormat::Xlsx => {
+                let worksheet = self
+                    .workbook
+                    .as_mut()
+                    .and_then(|w| w.worksheet_from_index(0).ok())
+                    .ok_or_else(|| anyhow::anyhow!("the workbook has no sheet"))?;
+
+                for (col, value) in row.values().into_iter().enumerate() {
+                    let col = col as u16;
+                    match value {
+                        ExportValue::Integer(value) => {
+                            worksheet.write_number(self.rows, col, value as f64)?
+                        }
+                        value => worksheet.write_string(self.rows, col, value.to_text())?,
+                    };
+                }
+            }
+        }
+
+        if self.buffer.len() >= CHUNK_SIZE {
+            return Ok(Some(Bytes::from(std::mem::take(&mut self.buffer))));
+        }
+        Ok(None)
+    }
+
+    /// Returns whatever is left to send.
+    pub fn finish(mut self) -> anyhow::Result<Bytes> {
+        if let Some(mut workbook) = self.workbook.take() {
+            return Ok(Bytes::from(workbook.save_to_buffer()?));
+        }
+
+        Ok(Bytes::from(self.buffer))
+    }
+
+    fn write_csv_record<I, V>(&mut self, record: I) -> anyhow::Result<()>
+    where
+        I: IntoIterator<Item = V>,
+        V: AsRef<[u8]>,
+    {
+        let mut writer = csv::Writer::from_writer(&mut self.buffer);
+        writer.write_record(record)?;
+        writer.flush()?;
+        Ok(())
+    }
+}
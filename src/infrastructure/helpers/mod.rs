@@ -1 +1,5 @@
-pub mod token;
+pub mod egress;
+pub mod export;
+pub mod signature;
+pub mod spreadsheet;
+pub mod token;
\ No newline at end of file
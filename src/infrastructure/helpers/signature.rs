@@ -0,0 +1,32 @@
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// Signs `{timestamp}.{body}` with HMAC-SHA256, so receivers can both authenticate the
+/// payload and reject replays of old ones. Rendered as `v1=<hex digest>`.
+pub fn sign(secret: &str, timestamp: i64, body: &[u8]) -> String {
+    let digest = mac(secret, timestamp, body).finalize().into_bytes();
+
+    format!("v1={}", hex::encode(digest))
+}
+
+pub fn verify(secret: &str, timestamp: i64, body: &[u8], signature: &str) -> bool {
+    let Some(digest) = signature
+        .strip_prefix("v1=")
+        .and_then(|hex_digest| hex::decode(hex_digest).ok())
+    else {
+        return false;
+    };
+
+    mac(secret, timestamp, body).verify_slice(&digest).is_ok()
+}
+
+fn mac(secret: &str, timestamp: i64, body: &[u8]) -> HmacSha256 {
+    let mut mac =
+        HmacSha256::new_from_slice(secret.as_bytes()).expect("hmac accepts keys of any size");
+    mac.update(timestamp.to_string().as_bytes());
+    mac.update(b".");
+    mac.update(body);
+    mac
+}
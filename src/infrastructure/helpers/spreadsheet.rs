@@ -0,0 +1,115 @@
+use std::{collections::HashMap, io::Cursor};
+
+use calamine::{Reader, open_workbook_auto_from_rs};
+
+const WORKBOOK_EXTENSIONS: [&str; 5] = ["xlsx", "xlsm", "xlsb", "xls", "ods"];
+
+/// A data row keyed by its lowercased header, `number` being the line it is on in the
+/// file, headers included, so that it matches what a spreadsheet shows.
+#[derive(Debug, Clone)]
+pub struct SheetRow {
+    pub number: usize,
+    pub cells: HashMap<String, String>,
+}
+
+impl SheetRow {
+    pub fn get(&self, header: &str) -> &str {
+        self.cells
+            .get(header)
+            .map(String::as_str)
+            .unwrap_or_default()
+    }
+}
+
+/// Reads the first sheet of a workbook, or a CSV file when `file_name` has no workbook
+/// extension. Blank rows are skipped.
+pub fn read_rows(file_name: &str, data: &[u8]) -> anyhow::Result<Vec<SheetRow>> {
+    let extension = file_name
+        .rsplit_once('.')
+        .map(|(_, ext)| ext.to_ascii_lowercase())
+        .unwrap_or_default();
+
+    let lines = if WORKBOOK_EXTENSIONS.contains(&extension.as_str()) {
+        read_workbook(data)?
+    } else {
+        read_csv(data)?
+    };
+
+    let mut lines = lines.into_iter();
+    let Some((_, headers)) = lines.next() else {
+        return Ok(Vec::new());
+    };
+    let headers: Vec<String> = headers.iter().map(|h| h.trim().to_lowercase()).collect();
+
+    let rows = lines
+        .filter(|(_, line)| line.iter().any(|cell| !cell.trim().is_empty()))
+        .map(|(number, line)| SheetRow {
+            number,
+            cells: headers
+                .iter()
+                .cloned()
+                .zip(line.into_iter().map(|cell| cell.trim().to_string()))
+                .collect(),
+        })
+        .collect();
+
+    Ok(rows)
+}
+
+/// Lines of the file along with their 1-based number.
+type Lines = Vec<(usize, Vec<String>)>;
+
+fn read_csv(data: &[u8]) -> anyhow::Result<Lines> {
+    let data = data.strip_prefix(b"\xEF\xBB\xBF").unwrap_or(data);
+
+    let mut reader = csv::ReaderBuilder::new()
+        .has_headers(false)
+        .flexible(true)
+        .from_reader(data);
+
+    let mut lines = Vec::new();
+    for record in reader.records() {
+        let record = record?;
+        let number = record
+            .position()
+            .map_or(lines.len() + 1, |p| line_of(data, p));
+
+        lines.push((number, record.iter().map(str::to_string).collect()));
+    }
+
+    Ok(lines)
+}
+
+/// The reader reports where it started looking for a record, which is before the blank
+/// lines it skipped.
+fn line_of(data: &[u8], position: &csv::Position) -> usize {
+    let skipped = data[position.byte() as usize..]
+        .iter()
+        .take_while(|b| matches!(b, b'\r' | b'\n'))
+        .filter(|b| **b == b'\n')
+        .count();
+
+    position.line() as usize + skipped
+}
+
+fn read_workbook(data: &[u8]) -> anyhow::Result<Lines> {
+    let mut workbook = open_workbook_auto_from_rs(Cursor::new(data.to_vec()))?;
+
+    let range = workbook
+        .worksheet_range_at(0)
+        .ok_or_else(|| anyhow::anyhow!("the workbook has no sheet"))??;
+
+    // the range starts at the first non-empty cell rather than at A1
+    let first = range.start().map_or(0, |(row, _)| row as usize);
+
+    Ok(range
+        .rows()
+        .enumerate()
+        .map(|(idx, row)| {
+            (
+                first + idx + 1,
+                row.iter().map(|cell| cell.to_string()).collect(),
+            )
+        })
+        .collect())
+}
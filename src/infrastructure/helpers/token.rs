@@ -2,12 +2,17 @@ use anyhow::Ok;
 use chrono::{Duration, Utc};
 use jsonwebtoken::{DecodingKey, EncodingKey, Header, Validation, decode, encode};
 use serde::{Deserialize, Serialize};
+use uuid::Uuid;
 
 #[derive(Deserialize, Serialize, Debug)]
-struct TokenClaims {
+pub struct TokenClaims {
     pub exp: usize,
     pub iat: usize,
     pub sub: String,
+    #[serde(default)]
+    pub roles: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tenant_id: Option<Uuid>,
 }
 
 #[derive(Debug, Clone)]
@@ -21,12 +26,33 @@ impl JwtHelper {
     }
 
     pub fn generate(&self, sub: String) -> anyhow::Result<String> {
+        self.generate_with_roles(sub, Vec::new())
+    }
+
+    pub fn generate_with_roles(&self, sub: String, roles: Vec<String>) -> anyhow::Result<String> {
+        self.generate_for_tenant(sub, None, roles)
+    }
+
+    /// Tokens without a tenant are only accepted from services, which name the tenant
+    /// they act for in the `X-Tenant-Id` header.
+    pub fn generate_for_tenant(
+        &self,
+        sub: String,
+        tenant_id: Option<Uuid>,
+        roles: Vec<String>,
+    ) -> anyhow::Result<String> {
         let now = Utc::now();
         let expire: chrono::TimeDelta = Duration::minutes(5);
         let exp = (now + expire).timestamp() as usize;
         let iat = now.timestamp() as usize;
 
-        let claim = TokenClaims { exp, iat, sub };
+        let claim = TokenClaims {
+            exp,
+            iat,
+            sub,
+            roles,
+            tenant_id,
+        };
 
         let value = encode(
             &Header::default(),
@@ -38,12 +64,16 @@ impl JwtHelper {
     }
 
     pub fn validate(&self, token: &str) -> anyhow::Result<String> {
+        Ok(self.decode(token)?.sub)
+    }
+
+    pub fn decode(&self, token: &str) -> anyhow::Result<TokenClaims> {
         let result = decode::<TokenClaims>(
             &token,
             &DecodingKey::from_secret(self.secret.as_ref()),
             &Validation::default(),
         )?;
 
-        Ok(result.claims.sub)
+        Ok(result.claims)
     }
 }
@@ -0,0 +1,92 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use sea_orm::{
+    ActiveValue::Set, ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter, QueryOrder,
+    QuerySelect,
+};
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        audit::{AuditLog, AuditLogCriteria, NewAuditLog, repositories::AuditRepository},
+        error::DomainError,
+    },
+    infrastructure::db::{AuditContext, Repository, entities::audit_log},
+};
+
+#[async_trait]
+impl<'a, C: ConnectionTrait> AuditRepository for Repository<'a, C> {
+    async fn record(&self, log: NewAuditLog) -> Result<(), DomainError> {
+        audit_log::Entity::insert(entry(self, log))
+            .exec_without_returning(&self.db)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn record_many(&self, logs: Vec<NewAuditLog>) -> Result<(), DomainError> {
+        if logs.is_empty() {
+            return Ok(());
+        }
+
+        let entries: Vec<_> = logs.into_iter().map(|log| entry(self, log)).collect();
+
+        audit_log::Entity::insert_many(entries)
+            .exec_without_returning(&self.db)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn query(&self, criteria: &AuditLogCriteria) -> Result<Vec<AuditLog>, DomainError> {
+        let mut query = self.find::<audit_log::Entity>();
+
+        if let Some(entity) = &criteria.entity {
+            query = query.filter(audit_log::Column::Entity.eq(entity));
+        }
+        if let Some(entity_id) = criteria.entity_id {
+            query = query.filter(audit_log::Column::EntityId.eq(entity_id));
+        }
+        if let Some(action) = criteria.action {
+            query = query.filter(audit_log::Column::Action.eq(action.as_str()));
+        }
+        if let Some(actor) = &criteria.actor {
+            query = query.filter(audit_log::Column::Actor.eq(actor));
+        }
+        if let Some(request_id) = &criteria.request_id {
+            query = query.filter(audit_log::Column::RequestId.eq(request_id));
+        }
+        if let Some(from) = criteria.from {
+            query = query.filter(audit_log::Column::CreatedAt.gte(from));
+        }
+        if let Some(to) = criteria.to {
+            query = query.filter(audit_log::Column::CreatedAt.lt(to));
+        }
+
+        let result = query
+            .order_by_desc(audit_log::Column::CreatedAt)
+            .limit(criteria.limit.unwrap_or(100))
+            .offset(criteria.offset)
+            .all(&self.db)
+            .await?;
+
+        Ok(result.into_iter().map(|a| a.into()).collect())
+    }
+}
+
+fn entry<C: ConnectionTrait>(repo: &Repository<'_, C>, log: NewAuditLog) -> audit_log::ActiveModel {
+    let ctx = AuditContext::current();
+
+    audit_log::ActiveModel {
+        id: Set(Uuid::new_v4()),
+        entity: Set(log.entity),
+        entity_id: Set(log.entity_id),
+        action: Set(log.action.as_str().to_string()),
+        actor: Set(ctx.actor),
+        before: Set(log.before),
+        after: Set(log.after),
+        request_id: Set(ctx.request_id),
+        created_at: Set(Utc::now().fixed_offset()),
+        tenant_id: Set(log.tenant_id.or(repo.tenant.id())),
+    }
+}
@@ -1,44 +1,303 @@
+use std::collections::HashMap;
+
 use async_trait::async_trait;
-use sea_orm::{ActiveModelTrait, ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter};
+use chrono::{DateTime, Utc};
+use sea_orm::{
+    ActiveModelBehavior, ActiveModelTrait, ActiveValue::Set, ColumnTrait, ConnectionTrait,
+    DatabaseBackend, DbErr, EntityTrait, QueryFilter,
+};
 use uuid::Uuid;
 
 use crate::{
-    application::dtos::company::{ReqAddCompanyDto, ReqQueryCompanyDto, ResQueryCompanyDto},
-    domain::{error::DomainError, organization::repositories::CompanyRepository},
-    infrastructure::db::{Repository, entities::companies},
+    domain::{
+        audit::{AuditAction, NewAuditLog, repositories::AuditRepository},
+        error::DomainError,
+        organization::{
+            Company, CompanyCriteria, CompanyId, CompanyName, repositories::CompanyRepository,
+        },
+    },
+    infrastructure::db::{Repository, entities::companies, filter_condition},
 };
 
+use super::stale_or_missing;
+
+const ENTITY: &str = "company";
+
 #[async_trait]
 impl<'a, C: ConnectionTrait> CompanyRepository for Repository<'a, C> {
-    async fn query(
+    async fn query(&self, criteria: &CompanyCriteria) -> Result<Vec<Company>, DomainError> {
+        let query = self
+            .find::<companies::Entity>()
+            .filter(companies::Column::DeletedAt.is_null());
+
+        let result = filter_companies(self.db.get_database_backend(), query, criteria)?
+            .all(&self.db)
+            .await?;
+
+        result.into_iter().map(Company::try_from).collect()
+    }
+
+    async fn find(&self, id: CompanyId) -> Result<Option<Company>, DomainError> {
+        let result = find_active(self, id.into()).await?;
+
+        result.map(Company::try_from).transpose()
+    }
+
+    async fn find_many(&self, ids: &[CompanyId]) -> Result<Vec<Company>, DomainError> {
+        if ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let result = self
+            .find::<companies::Entity>()
+            .filter(companies::Column::DeletedAt.is_null())
+            .filter(companies::Column::Id.is_in(ids.iter().map(CompanyId::as_uuid)))
+            .all(&self.db)
+            .await?;
+
+        result.into_iter().map(Company::try_from).collect()
+    }
+
+    async fn exists(&self, id: CompanyId) -> Result<bool, DomainError> {
+        let result = find_active(self, id.into()).await?;
+
+        Ok(result.is_some())
+    }
+
+    async fn find_ids_by_name(
         &self,
-        cond: &ReqQueryCompanyDto,
-    ) -> Result<Vec<ResQueryCompanyDto>, DomainError> {
-        let mut query = companies::Entity::find();
-        if let Some(name) = &cond.name
-            && !name.is_empty()
-        {
-            query = query.filter(companies::Column::Name.like(format!("%{}%", name)));
+        names: &[String],
+    ) -> Result<HashMap<String, CompanyId>, DomainError> {
+        if names.is_empty() {
+            return Ok(HashMap::new());
         }
 
-        let result = query.all(self.db).await?;
+        let result = self
+            .find::<companies::Entity>()
+            .filter(companies::Column::DeletedAt.is_null())
+            .filter(companies::Column::Name.is_in(names))
+            .all(&self.db)
+            .await?;
+
+        Ok(result.into_iter().map(|m| (m.name, m.id.into())).collect())
+    }
+
+    async fn add(&self, com: Company) -> Result<CompanyId, DomainError> {
+        let mut company = companies::ActiveModel::from(com);
+        company.tenant_id = Set(self.tenant.require()?);
 
-        let result = result.into_iter().map(|c| c.into()).collect();
+        let company = company.insert(&self.db).await?;
 
-        Ok(result)
+        self.record(NewAuditLog::diff(
+            ENTITY,
+            company.id,
+            AuditAction::Create,
+            None,
+            Some(&company),
+        ))
+        .await?;
+
+        Ok(company.id.into())
     }
 
-    async fn exists(&self, id: Uuid) -> Result<bool, DomainError> {
-        let result = companies::Entity::find_by_id(id).one(self.db).await?;
+    async fn add_many(&self, coms: Vec<Company>) -> Result<Vec<CompanyId>, DomainError> {
+        if coms.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let tenant_id = self.tenant.require()?;
 
-        Ok(result.is_some())
+        let mut models = Vec::with_capacity(coms.len());
+        for com in coms {
+            let mut company = companies::ActiveModel::from(com);
+            company.tenant_id = Set(tenant_id);
+            // insert_many skips the active model hooks
+            models.push(company.before_save(&self.db, true).await?);
+        }
+        let ids: Vec<Uuid> = models.iter().map(|m| m.id.clone().unwrap()).collect();
+
+        let inserted = if self.db.support_returning() {
+            companies::Entity::insert_many(models)
+                .exec_with_returning_many(&self.db)
+                .await?
+        } else {
+            companies::Entity::insert_many(models)
+                .exec_without_returning(&self.db)
+                .await?;
+
+            self.find::<companies::Entity>()
+                .filter(companies::Column::Id.is_in(ids.iter().copied()))
+                .all(&self.db)
+                .await?
+        };
+
+        self.record_many(
+            inserted
+                .iter()
+                .map(|company| {
+                    NewAuditLog::diff(ENTITY, company.id, AuditAction::Create, None, Some(company))
+                })
+                .collect(),
+        )
+        .await?;
+
+        Ok(ids.into_iter().map(CompanyId::from).collect())
+    }
+
+    async fn update(
+        &self,
+        id: CompanyId,
+        version: i32,
+        name: CompanyName,
+    ) -> Result<i32, DomainError> {
+        let before = find_active(self, id.into())
+            .await?
+            .ok_or_else(|| stale_or_missing(false, ENTITY, id))?;
+
+        if before.version != version {
+            return Err(stale_or_missing(true, ENTITY, id));
+        }
+
+        let mut company: companies::ActiveModel = before.clone().into();
+        company.name = Set(name.into_inner());
+
+        let after = save(self, before, company, AuditAction::Update).await?;
+
+        Ok(after.version)
+    }
+
+    async fn delete(
+        &self,
+        id: CompanyId,
+        version: i32,
+        at: DateTime<Utc>,
+    ) -> Result<(), DomainError> {
+        let before = find_active(self, id.into())
+            .await?
+            .ok_or_else(|| stale_or_missing(false, ENTITY, id))?;
+
+        if before.version != version {
+            return Err(stale_or_missing(true, ENTITY, id));
+        }
+
+        let mut company: companies::ActiveModel = before.clone().into();
+        company.deleted_at = Set(Some(at.fixed_offset()));
+
+        save(self, before, company, AuditAction::Delete).await?;
+
+        Ok(())
     }
 
-    async fn add(&self, com: ReqAddCompanyDto) -> Result<Uuid, DomainError> {
-        let company = companies::ActiveModel::from(com);
+    async fn query_deleted(&self) -> Result<Vec<Company>, DomainError> {
+        let result = self
+            .find::<companies::Entity>()
+            .filter(companies::Column::DeletedAt.is_not_null())
+            .all(&self.db)
+            .await?;
+
+        result.into_iter().map(Company::try_from).collect()
+    }
+
+    async fn restore(&self, id: CompanyId) -> Result<DateTime<Utc>, DomainError> {
+        let before = self
+            .find_by_id::<companies::Entity>(id.as_uuid())
+            .filter(companies::Column::DeletedAt.is_not_null())
+            .one(&self.db)
+            .await?
+            .ok_or_else(|| {
+                DomainError::NotFound(format!("deleted company with id: {} is not found", id))
+            })?;
+
+        let mut company: companies::ActiveModel = before.clone().into();
+        company.deleted_at = Set(None);
+
+        let deleted_at = before.deleted_at.unwrap_or_default().to_utc();
 
-        let company = company.insert(self.db).await?;
+        save(self, before, company, AuditAction::Restore).await?;
 
-        Ok(company.id)
+        Ok(deleted_at)
     }
+
+    async fn purge(&self, deleted_before: DateTime<Utc>) -> Result<u64, DomainError> {
+        let expired = self
+            .find::<companies::Entity>()
+            .filter(companies::Column::DeletedAt.lt(deleted_before.fixed_offset()))
+            .all(&self.db)
+            .await?;
+
+        if expired.is_empty() {
+            return Ok(0);
+        }
+
+        let result = self
+            .delete_many::<companies::Entity>()
+            .filter(companies::Column::Id.is_in(expired.iter().map(|c| c.id)))
+            .exec(&self.db)
+            .await?;
+
+        for company in expired {
+            self.record(NewAuditLog {
+                tenant_id: Some(company.tenant_id),
+                ..NewAuditLog::diff(ENTITY, company.id, AuditAction::Purge, Some(&company), None)
+            })
+            .await?;
+        }
+
+        Ok(result.rows_affected)
+    }
+}
+
+/// Applies the company filters of `criteria`, shared by queries and exports.
+pub(super) fn filter_companies<Q: QueryFilter>(
+    backend: DatabaseBackend,
+    query: Q,
+    criteria: &CompanyCriteria,
+) -> Result<Q, DbErr> {
+    Ok(query.filter(filter_condition::<companies::Entity>(
+        backend,
+        &criteria.clauses,
+    )?))
+}
+
+async fn find_active<C: ConnectionTrait>(
+    repo: &Repository<'_, C>,
+    id: Uuid,
+) -> Result<Option<companies::Model>, DbErr> {
+    repo.find_by_id::<companies::Entity>(id)
+        .filter(companies::Column::DeletedAt.is_null())
+        .one(&repo.db)
+        .await
+}
+
+/// Writes the changes only if nobody else has bumped the version since `before` was read,
+/// and keeps track of them in the audit log.
+async fn save<C: ConnectionTrait>(
+    repo: &Repository<'_, C>,
+    before: companies::Model,
+    mut company: companies::ActiveModel,
+    action: AuditAction,
+) -> Result<companies::Model, DomainError> {
+    company.version = Set(before.version + 1);
+    let company = company.before_save(&repo.db, false).await?;
+
+    let after = repo
+        .update(company)
+        .filter(companies::Column::Version.eq(before.version))
+        .exec(&repo.db)
+        .await
+        .map_err(|e| match e {
+            DbErr::RecordNotUpdated => stale_or_missing(true, ENTITY, before.id),
+            e => e.into(),
+        })?;
+
+    repo.record(NewAuditLog::diff(
+        ENTITY,
+        before.id,
+        action,
+        Some(&before),
+        Some(&after),
+    ))
+    .await?;
+
+    Ok(after)
 }
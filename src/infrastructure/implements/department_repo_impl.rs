@@ -1,20 +1,301 @@
+use std::collections::HashMap;
+
 use async_trait::async_trait;
-use sea_orm::{ActiveModelTrait, ConnectionTrait};
+use chrono::{DateTime, Utc};
+use sea_orm::{
+    ActiveModelBehavior, ActiveModelTrait, ActiveValue::Set, ColumnTrait, Condition,
+    ConnectionTrait, DbErr, QueryFilter, QueryOrder, sea_query::Query,
+};
 use uuid::Uuid;
 
 use crate::{
-    application::dtos::department::ReqAddDepartmentDto,
-    domain::{error::DomainError, organization::repositories::DepartmentRepository},
-    infrastructure::db::{Repository, entities::departments},
+    domain::{
+        audit::{AuditAction, NewAuditLog, repositories::AuditRepository},
+        error::DomainError,
+        organization::{
+            CompanyId, Department, DepartmentId, DepartmentName, repositories::DepartmentRepository,
+        },
+    },
+    infrastructure::db::{
+        Repository,
+        entities::{companies, departments},
+    },
 };
 
+use super::stale_or_missing;
+
+const ENTITY: &str = "department";
+
 #[async_trait]
 impl<'a, C: ConnectionTrait> DepartmentRepository for Repository<'a, C> {
-    async fn add(&self, dep: ReqAddDepartmentDto) -> Result<Uuid, DomainError> {
-        let department = departments::ActiveModel::from(dep);
+    async fn find(&self, id: DepartmentId) -> Result<Option<Department>, DomainError> {
+        let result = find_active(self, id.into()).await?;
+
+        result.map(Department::try_from).transpose()
+    }
+
+    async fn find_by_companies(
+        &self,
+        company_ids: &[CompanyId],
+    ) -> Result<Vec<Department>, DomainError> {
+        if company_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let result = self
+            .find::<departments::Entity>()
+            .filter(departments::Column::DeletedAt.is_null())
+            .filter(
+                departments::Column::CompanyId.is_in(company_ids.iter().map(CompanyId::as_uuid)),
+            )
+            .order_by_asc(departments::Column::Name)
+            .all(&self.db)
+            .await?;
+
+        result.into_iter().map(Department::try_from).collect()
+    }
+
+    async fn find_ids_by_name(
+        &self,
+        names: &[String],
+    ) -> Result<HashMap<String, DepartmentId>, DomainError> {
+        if names.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        let result = self
+            .find::<departments::Entity>()
+            .filter(departments::Column::DeletedAt.is_null())
+            .filter(departments::Column::Name.is_in(names))
+            .all(&self.db)
+            .await?;
+
+        Ok(result.into_iter().map(|m| (m.name, m.id.into())).collect())
+    }
+
+    async fn add(&self, dep: Department) -> Result<DepartmentId, DomainError> {
+        let mut department = departments::ActiveModel::from(dep);
+        department.tenant_id = Set(self.tenant.require()?);
+
+        let department = department.insert(&self.db).await?;
 
-        let department = department.insert(self.db).await?;
+        self.record(NewAuditLog::diff(
+            ENTITY,
+            department.id,
+            AuditAction::Create,
+            None,
+            Some(&department),
+        ))
+        .await?;
 
-        Ok(department.id)
+        Ok(department.id.into())
     }
+
+    async fn update(
+        &self,
+        id: DepartmentId,
+        version: i32,
+        name: DepartmentName,
+        company_id: CompanyId,
+    ) -> Result<i32, DomainError> {
+        let before = find_active(self, id.into())
+            .await?
+            .ok_or_else(|| stale_or_missing(false, ENTITY, id))?;
+
+        if before.version != version {
+            return Err(stale_or_missing(true, ENTITY, id));
+        }
+
+        let mut department: departments::ActiveModel = before.clone().into();
+        department.name = Set(name.into_inner());
+        department.company_id = Set(company_id.into());
+
+        let after = save(self, before, department, AuditAction::Update).await?;
+
+        Ok(after.version)
+    }
+
+    async fn delete(
+        &self,
+        id: DepartmentId,
+        version: i32,
+        at: DateTime<Utc>,
+    ) -> Result<(), DomainError> {
+        let before = find_active(self, id.into())
+            .await?
+            .ok_or_else(|| stale_or_missing(false, ENTITY, id))?;
+
+        if before.version != version {
+            return Err(stale_or_missing(true, ENTITY, id));
+        }
+
+        let mut department: departments::ActiveModel = before.clone().into();
+        department.deleted_at = Set(Some(at.fixed_offset()));
+
+        save(self, before, department, AuditAction::Delete).await?;
+
+        Ok(())
+    }
+
+    async fn delete_by_company(
+        &self,
+        company_id: CompanyId,
+        at: DateTime<Utc>,
+    ) -> Result<u64, DomainError> {
+        let active = self
+            .find::<departments::Entity>()
+            .filter(departments::Column::CompanyId.eq(company_id.as_uuid()))
+            .filter(departments::Column::DeletedAt.is_null())
+            .all(&self.db)
+            .await?;
+
+        let count = active.len() as u64;
+
+        for before in active {
+            let mut department: departments::ActiveModel = before.clone().into();
+            department.deleted_at = Set(Some(at.fixed_offset()));
+
+            save(self, before, department, AuditAction::Delete).await?;
+        }
+
+        Ok(count)
+    }
+
+    async fn query_deleted(&self) -> Result<Vec<Department>, DomainError> {
+        let result = self
+            .find::<departments::Entity>()
+            .filter(departments::Column::DeletedAt.is_not_null())
+            .all(&self.db)
+            .await?;
+
+        result.into_iter().map(Department::try_from).collect()
+    }
+
+    async fn restore(&self, id: DepartmentId) -> Result<CompanyId, DomainError> {
+        let before = self
+            .find_by_id::<departments::Entity>(id.as_uuid())
+            .filter(departments::Column::DeletedAt.is_not_null())
+            .one(&self.db)
+            .await?
+            .ok_or_else(|| {
+                DomainError::NotFound(format!("deleted department with id: {} is not found", id))
+            })?;
+
+        let mut department: departments::ActiveModel = before.clone().into();
+        department.deleted_at = Set(None);
+
+        let after = save(self, before, department, AuditAction::Restore).await?;
+
+        Ok(after.company_id.into())
+    }
+
+    async fn restore_by_company(
+        &self,
+        company_id: CompanyId,
+        deleted_at: DateTime<Utc>,
+    ) -> Result<u64, DomainError> {
+        let deleted = self
+            .find::<departments::Entity>()
+            .filter(departments::Column::CompanyId.eq(company_id.as_uuid()))
+            .filter(departments::Column::DeletedAt.eq(deleted_at.fixed_offset()))
+            .all(&self.db)
+            .await?;
+
+        let count = deleted.len() as u64;
+
+        for before in deleted {
+            let mut department: departments::ActiveModel = before.clone().into();
+            department.deleted_at = Set(None);
+
+            save(self, before, department, AuditAction::Restore).await?;
+        }
+
+        Ok(count)
+    }
+
+    async fn purge(&self, deleted_before: DateTime<Utc>) -> Result<u64, DomainError> {
+        let expired_companies = Query::select()
+            .column(companies::Column::Id)
+            .from(companies::Entity)
+            .and_where(companies::Column::DeletedAt.lt(deleted_before.fixed_offset()))
+            .to_owned();
+        let expired = self
+            .find::<departments::Entity>()
+            .filter(
+                Condition::any()
+                    .add(departments::Column::DeletedAt.lt(deleted_before.fixed_offset()))
+                    .add(departments::Column::CompanyId.in_subquery(expired_companies)),
+            )
+            .all(&self.db)
+            .await?;
+
+        if expired.is_empty() {
+            return Ok(0);
+        }
+
+        let result = self
+            .delete_many::<departments::Entity>()
+            .filter(departments::Column::Id.is_in(expired.iter().map(|d| d.id)))
+            .exec(&self.db)
+            .await?;
+
+        for department in expired {
+            self.record(NewAuditLog {
+                tenant_id: Some(department.tenant_id),
+                ..NewAuditLog::diff(
+                    ENTITY,
+                    department.id,
+                    AuditAction::Purge,
+                    Some(&department),
+                    None,
+                )
+            })
+            .await?;
+        }
+
+        Ok(result.rows_affected)
+    }
+}
+
+async fn find_active<C: ConnectionTrait>(
+    repo: &Repository<'_, C>,
+    id: Uuid,
+) -> Result<Option<departments::Model>, DbErr> {
+    repo.find_by_id::<departments::Entity>(id)
+        .filter(departments::Column::DeletedAt.is_null())
+        .one(&repo.db)
+        .await
+}
+
+/// Writes the changes only if nobody else has bumped the version since `before` was read,
+/// and keeps track of them in the audit log.
+async fn save<C: ConnectionTrait>(
+    repo: &Repository<'_, C>,
+    before: departments::Model,
+    mut department: departments::ActiveModel,
+    action: AuditAction,
+) -> Result<departments::Model, DomainError> {
+    department.version = Set(before.version + 1);
+    let department = department.before_save(&repo.db, false).await?;
+
+    let after = repo
+        .update(department)
+        .filter(departments::Column::Version.eq(before.version))
+        .exec(&repo.db)
+        .await
+        .map_err(|e| match e {
+            DbErr::RecordNotUpdated => stale_or_missing(true, ENTITY, before.id),
+            e => e.into(),
+        })?;
+
+    repo.record(NewAuditLog::diff(
+        ENTITY,
+        before.id,
+        action,
+        Some(&before),
+        Some(&after),
+    ))
+    .await?;
+
+    Ok(after)
 }
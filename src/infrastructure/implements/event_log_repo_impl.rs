@@ -0,0 +1,91 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sea_orm::{
+    ActiveValue::{NotSet, Set},
+    ColumnTrait, ConnectionTrait, DatabaseBackend, EntityTrait, QueryFilter, QueryOrder,
+    QuerySelect, Statement,
+    sea_query::OnConflict,
+};
+
+use crate::{
+    domain::{
+        error::DomainError,
+        events::{LoggedEvent, OutboxMessage, repositories::EventLogRepository},
+    },
+    infrastructure::db::{Repository, entities::event_log},
+};
+
+// the key of the advisory lock appends are serialized with
+const APPEND_LOCK: i64 = 0x6576_656e_745f_6c6f;
+
+#[async_trait]
+impl<'a, C: ConnectionTrait> EventLogRepository for Repository<'a, C> {
+    async fn append(&self, message: &OutboxMessage) -> Result<(), DomainError> {
+        // a seq is taken at insert, so two appends committing out of order would let a
+        // reader past the later one skip the earlier; held until the transaction ends,
+        // the lock rules that out. SQLite serializes its writers anyway
+        let backend = self.db.get_database_backend();
+        if backend == DatabaseBackend::Postgres {
+            self.db
+                .execute(Statement::from_sql_and_values(
+                    backend,
+                    "SELECT pg_advisory_xact_lock($1)",
+                    [APPEND_LOCK.into()],
+                ))
+                .await?;
+        }
+
+        let entry = event_log::ActiveModel {
+            seq: NotSet,
+            message_id: Set(message.id),
+            tenant_id: Set(message.tenant_id),
+            event_type: Set(message.event_type.clone()),
+            aggregate_id: Set(message.aggregate_id),
+            payload: Set(message.payload.clone()),
+            created_at: Set(message.created_at),
+        };
+
+        event_log::Entity::insert(entry)
+            .on_conflict(
+                OnConflict::column(event_log::Column::MessageId)
+                    .do_nothing_on([event_log::Column::Seq])
+                    .to_owned(),
+            )
+            .exec_without_returning(&self.db)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn since(&self, seq: i64, limit: u64) -> Result<Vec<LoggedEvent>, DomainError> {
+        let result = self
+            .find::<event_log::Entity>()
+            .filter(event_log::Column::Seq.gt(seq))
+            .order_by_asc(event_log::Column::Seq)
+            .limit(limit)
+            .all(&self.db)
+            .await?;
+
+        Ok(result.into_iter().map(|e| e.into()).collect())
+    }
+
+    async fn last_seq(&self) -> Result<i64, DomainError> {
+        let last = self
+            .find::<event_log::Entity>()
+            .order_by_desc(event_log::Column::Seq)
+            .one(&self.db)
+            .await?;
+
+        Ok(last.map(|e| e.seq).unwrap_or_default())
+    }
+
+    async fn purge(&self, before: DateTime<Utc>) -> Result<u64, DomainError> {
+        let result = self
+            .delete_many::<event_log::Entity>()
+            .filter(event_log::Column::CreatedAt.lt(before.fixed_offset()))
+            .exec(&self.db)
+            .await?;
+
+        Ok(result.rows_affected)
+    }
+}
@@ -0,0 +1,148 @@
+use async_trait::async_trait;
+use chrono::{DateTime, FixedOffset};
+use futures_util::StreamExt;
+use sea_orm::{
+    ColumnTrait, ConnectionTrait, FromQueryResult, QueryFilter, QueryOrder, QuerySelect,
+    RelationTrait, StreamTrait,
+    sea_query::{Expr, Func, JoinType, Query, SimpleExpr},
+};
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        error::DomainError,
+        organization::{
+            CompanyCriteria, ExportedCompany, ExportedDepartment,
+            repositories::{ExportRepository, RowStream},
+        },
+    },
+    infrastructure::db::{
+        Repository,
+        entities::{companies, departments},
+    },
+};
+
+use super::company_repo_impl::filter_companies;
+
+#[derive(FromQueryResult)]
+struct CompanyRow {
+    id: Uuid,
+    name: String,
+    department_count: i64,
+    created_at: DateTime<FixedOffset>,
+    updated_at: DateTime<FixedOffset>,
+}
+
+impl From<CompanyRow> for ExportedCompany {
+    fn from(row: CompanyRow) -> Self {
+        Self {
+            id: row.id,
+            name: row.name,
+            department_count: row.department_count,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        }
+    }
+}
+
+#[derive(FromQueryResult)]
+struct DepartmentRow {
+    id: Uuid,
+    name: String,
+    company_id: Uuid,
+    company_name: String,
+    created_at: DateTime<FixedOffset>,
+    updated_at: DateTime<FixedOffset>,
+}
+
+impl From<DepartmentRow> for ExportedDepartment {
+    fn from(row: DepartmentRow) -> Self {
+        Self {
+            id: row.id,
+            name: row.name,
+            company_id: row.company_id,
+            company_name: row.company_name,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        }
+    }
+}
+
+#[async_trait]
+impl<'a, C> ExportRepository for Repository<'a, C>
+where
+    C: ConnectionTrait + StreamTrait + Send + Sync,
+{
+    async fn export_companies<'b>(
+        &'b self,
+        criteria: &'b CompanyCriteria,
+    ) -> Result<RowStream<'b, ExportedCompany>, DomainError> {
+        let department_count = Query::select()
+            .expr(Func::count(Expr::col((
+                departments::Entity,
+                departments::Column::Id,
+            ))))
+            .from(departments::Entity)
+            .and_where(
+                Expr::col((departments::Entity, departments::Column::CompanyId))
+                    .equals((companies::Entity, companies::Column::Id)),
+            )
+            .and_where(Expr::col((departments::Entity, departments::Column::DeletedAt)).is_null())
+            .to_owned();
+
+        let query = self
+            .find::<companies::Entity>()
+            .filter(companies::Column::DeletedAt.is_null());
+
+        let select = filter_companies(self.db.get_database_backend(), query, criteria)?
+            .select_only()
+            .columns([
+                companies::Column::Id,
+                companies::Column::Name,
+                companies::Column::CreatedAt,
+                companies::Column::UpdatedAt,
+            ])
+            .expr_as(
+                SimpleExpr::SubQuery(None, Box::new(department_count.into_sub_query_statement())),
+                "department_count",
+            )
+            .order_by_asc(companies::Column::Name)
+            .into_model::<CompanyRow>();
+        // a stream checks its connection out by itself, outside of `self.db`
+        let rows = self.tenant.run(select.stream(self.db.inner())).await?;
+
+        Ok(Box::pin(
+            rows.map(|row| row.map(Into::into).map_err(DomainError::from)),
+        ))
+    }
+
+    async fn export_departments<'b>(
+        &'b self,
+        criteria: &'b CompanyCriteria,
+    ) -> Result<RowStream<'b, ExportedDepartment>, DomainError> {
+        let query = self
+            .find::<departments::Entity>()
+            .join(JoinType::InnerJoin, departments::Relation::Companies.def())
+            .filter(departments::Column::DeletedAt.is_null())
+            .filter(companies::Column::DeletedAt.is_null());
+
+        let select = filter_companies(self.db.get_database_backend(), query, criteria)?
+            .select_only()
+            .columns([
+                departments::Column::Id,
+                departments::Column::Name,
+                departments::Column::CompanyId,
+                departments::Column::CreatedAt,
+                departments::Column::UpdatedAt,
+            ])
+            .column_as(companies::Column::Name, "company_name")
+            .order_by_asc(companies::Column::Name)
+            .order_by_asc(departments::Column::Name)
+            .into_model::<DepartmentRow>();
+        let rows = self.tenant.run(select.stream(self.db.inner())).await?;
+
+        Ok(Box::pin(
+            rows.map(|row| row.map(Into::into).map_err(DomainError::from)),
+        ))
+    }
+}
@@ -0,0 +1,305 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sea_orm::{
+    ActiveValue::Set,
+    ColumnTrait, Condition, ConnectionTrait, EntityTrait, QueryFilter, QueryOrder, QuerySelect,
+    UpdateMany,
+    sea_query::{Expr, LockBehavior, LockType, OnConflict},
+};
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        error::{DomainError, ErrorKind},
+        job::{DueJob, Job, JobCriteria, JobStatus, NewJob, repositories::JobRepository},
+    },
+    infrastructure::db::{Repository, entities::jobs},
+};
+
+#[async_trait]
+impl<'a, C: ConnectionTrait> JobRepository for Repository<'a, C> {
+    async fn add(&self, job: NewJob) -> Result<Option<Uuid>, DomainError> {
+        let id = Uuid::new_v4();
+
+        let model = jobs::ActiveModel {
+            id: Set(id),
+            tenant_id: Set(self.tenant.id()),
+            name: Set(job.name),
+            queue: Set(job.queue),
+            payload: Set(job.payload),
+            status: Set(JobStatus::Pending.as_str().to_string()),
+            attempts: Set(0),
+            max_attempts: Set(job.max_attempts),
+            run_at: Set(job.run_at.fixed_offset()),
+            locked_at: Set(None),
+            locked_by: Set(None),
+            locked_until: Set(None),
+            dedupe_key: Set(job.dedupe_key),
+            last_error: Set(None),
+            created_at: Set(Utc::now().fixed_offset()),
+            finished_at: Set(None),
+        };
+
+        let inserted = jobs::Entity::insert(model)
+            .on_conflict(
+                OnConflict::column(jobs::Column::DedupeKey)
+                    .do_nothing_on([jobs::Column::Id])
+                    .to_owned(),
+            )
+            .exec_without_returning(&self.db)
+            .await?;
+
+        Ok((inserted > 0).then_some(id))
+    }
+
+    async fn claim(
+        &self,
+        queue: &str,
+        limit: u64,
+        until: DateTime<Utc>,
+    ) -> Result<Vec<DueJob>, DomainError> {
+        let now = Utc::now().fixed_offset();
+        let lease = Uuid::new_v4();
+
+        let claimed = self
+            .find::<jobs::Entity>()
+            .filter(jobs::Column::Queue.eq(queue))
+            .filter(
+                Condition::any()
+                    .add(
+                        Condition::all()
+                            .add(jobs::Column::Status.eq(JobStatus::Pending.as_str()))
+                            .add(jobs::Column::RunAt.lte(now)),
+                    )
+                    .add(
+                        Condition::all()
+                            .add(jobs::Column::Status.eq(JobStatus::Running.as_str()))
+                            .add(
+                                Condition::any()
+                                    .add(jobs::Column::LockedUntil.lt(now))
+                                    // claimed before leases had an end
+                                    .add(jobs::Column::LockedUntil.is_null()),
+                            ),
+                    ),
+            )
+            .order_by_asc(jobs::Column::RunAt)
+            .limit(limit)
+            .lock_with_behavior(LockType::Update, LockBehavior::SkipLocked)
+            .all(&self.db)
+            .await?;
+
+        if claimed.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        self.update_many::<jobs::Entity>()
+            .col_expr(
+                jobs::Column::Status,
+                Expr::value(JobStatus::Running.as_str()),
+            )
+            .col_expr(jobs::Column::LockedAt, Expr::value(now))
+            .col_expr(jobs::Column::LockedBy, Expr::value(lease))
+            .col_expr(jobs::Column::LockedUntil, Expr::value(until.fixed_offset()))
+            .col_expr(
+                jobs::Column::Attempts,
+                Expr::col(jobs::Column::Attempts).add(1),
+            )
+            .filter(jobs::Column::Id.is_in(claimed.iter().map(|j| j.id)))
+            .exec(&self.db)
+            .await?;
+
+        let result = claimed
+            .into_iter()
+            .map(|j| DueJob {
+                id: j.id,
+                tenant_id: j.tenant_id,
+                name: j.name,
+                payload: j.payload,
+                attempts: j.attempts + 1,
+                max_attempts: j.max_attempts,
+                lease,
+            })
+            .collect();
+
+        Ok(result)
+    }
+
+    async fn complete(&self, id: Uuid, lease: Uuid) -> Result<(), DomainError> {
+        let result = self
+            .release(id, lease)
+            .col_expr(
+                jobs::Column::Status,
+                Expr::value(JobStatus::Completed.as_str()),
+            )
+            .col_expr(
+                jobs::Column::FinishedAt,
+                Expr::value(Utc::now().fixed_offset()),
+            )
+            .exec(&self.db)
+            .await?;
+
+        held(id, result.rows_affected)
+    }
+
+    async fn fail(
+        &self,
+        id: Uuid,
+        lease: Uuid,
+        error: &str,
+        retry_at: Option<DateTime<Utc>>,
+    ) -> Result<(), DomainError> {
+        let mut query = self
+            .release(id, lease)
+            .col_expr(jobs::Column::LastError, Expr::value(error));
+
+        query = match retry_at {
+            Some(at) => query
+                .col_expr(
+                    jobs::Column::Status,
+                    Expr::value(JobStatus::Pending.as_str()),
+                )
+                .col_expr(jobs::Column::RunAt, Expr::value(at.fixed_offset())),
+            None => query
+                .col_expr(
+                    jobs::Column::Status,
+                    Expr::value(JobStatus::Failed.as_str()),
+                )
+                .col_expr(
+                    jobs::Column::FinishedAt,
+                    Expr::value(Utc::now().fixed_offset()),
+                ),
+        };
+
+        let result = query.exec(&self.db).await?;
+
+        held(id, result.rows_affected)
+    }
+
+    async fn query(&self, criteria: &JobCriteria) -> Result<Vec<Job>, DomainError> {
+        let mut query = self.find::<jobs::Entity>();
+
+        if let Some(status) = criteria.status {
+            query = query.filter(jobs::Column::Status.eq(status.as_str()));
+        }
+        if let Some(name) = &criteria.name {
+            query = query.filter(jobs::Column::Name.eq(name));
+        }
+        if let Some(queue) = &criteria.queue {
+            query = query.filter(jobs::Column::Queue.eq(queue));
+        }
+
+        let result = query
+            .order_by_desc(jobs::Column::CreatedAt)
+            .limit(criteria.limit.unwrap_or(100))
+            .offset(criteria.offset)
+            .all(&self.db)
+            .await?;
+
+        Ok(result.into_iter().map(|j| j.into()).collect())
+    }
+
+    async fn find(&self, id: Uuid) -> Result<Option<Job>, DomainError> {
+        let result = self.find_by_id::<jobs::Entity>(id).one(&self.db).await?;
+
+        Ok(result.map(|j| j.into()))
+    }
+
+    async fn retry(&self, id: Uuid) -> Result<(), DomainError> {
+        let result = self
+            .update_many::<jobs::Entity>()
+            .col_expr(
+                jobs::Column::Status,
+                Expr::value(JobStatus::Pending.as_str()),
+            )
+            .col_expr(jobs::Column::Attempts, Expr::value(0))
+            .col_expr(jobs::Column::RunAt, Expr::value(Utc::now().fixed_offset()))
+            .col_expr(
+                jobs::Column::FinishedAt,
+                Expr::value(Option::<DateTime<Utc>>::None),
+            )
+            .filter(jobs::Column::Id.eq(id))
+            .filter(
+                jobs::Column::Status
+                    .is_in([JobStatus::Failed.as_str(), JobStatus::Cancelled.as_str()]),
+            )
+            .exec(&self.db)
+            .await?;
+
+        if result.rows_affected == 0 {
+            return Err(self.conflict_or_missing(id, "JOB_NOT_RETRYABLE").await);
+        }
+
+        Ok(())
+    }
+
+    async fn cancel(&self, id: Uuid) -> Result<(), DomainError> {
+        let result = self
+            .update_many::<jobs::Entity>()
+            .col_expr(
+                jobs::Column::Status,
+                Expr::value(JobStatus::Cancelled.as_str()),
+            )
+            .col_expr(
+                jobs::Column::FinishedAt,
+                Expr::value(Utc::now().fixed_offset()),
+            )
+            .filter(jobs::Column::Id.eq(id))
+            .filter(jobs::Column::Status.eq(JobStatus::Pending.as_str()))
+            .exec(&self.db)
+            .await?;
+
+        if result.rows_affected == 0 {
+            return Err(self.conflict_or_missing(id, "JOB_NOT_CANCELLABLE").await);
+        }
+
+        Ok(())
+    }
+}
+
+impl<'a, C: ConnectionTrait> Repository<'a, C> {
+    /// Tells apart a job in the wrong state from one that doesn't exist after a
+    /// conditional update matched nothing.
+    async fn conflict_or_missing(&self, id: Uuid, code: &str) -> DomainError {
+        match JobRepository::find(self, id).await {
+            Ok(Some(job)) => DomainError::CaseError(
+                ErrorKind::Conflict,
+                code.to_string(),
+                format!("job with id: {} is {}", id, job.status),
+            ),
+            Ok(None) => DomainError::NotFound(format!("job with id: {} is not found", id)),
+            Err(e) => e,
+        }
+    }
+}
+
+impl<C: ConnectionTrait> Repository<'_, C> {
+    // clears the lease of job `id`, provided `lease` still holds it
+    fn release(&self, id: Uuid, lease: Uuid) -> UpdateMany<jobs::Entity> {
+        self.update_many::<jobs::Entity>()
+            .col_expr(
+                jobs::Column::LockedAt,
+                Expr::value(Option::<DateTime<Utc>>::None),
+            )
+            .col_expr(jobs::Column::LockedBy, Expr::value(Option::<Uuid>::None))
+            .col_expr(
+                jobs::Column::LockedUntil,
+                Expr::value(Option::<DateTime<Utc>>::None),
+            )
+            .filter(jobs::Column::Id.eq(id))
+            .filter(jobs::Column::Status.eq(JobStatus::Running.as_str()))
+            .filter(jobs::Column::LockedBy.eq(lease))
+            .filter(jobs::Column::LockedUntil.gt(Utc::now().fixed_offset()))
+    }
+}
+
+fn held(id: Uuid, rows_affected: u64) -> Result<(), DomainError> {
+    if rows_affected == 0 {
+        return Err(DomainError::CaseError(
+            ErrorKind::Conflict,
+            "LEASE_LOST".to_string(),
+            format!("the lease on job {} is no longer held", id),
+        ));
+    }
+
+    Ok(())
+}
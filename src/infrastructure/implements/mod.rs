@@ -1,2 +1,29 @@
+mod audit_repo_impl;
 mod company_repo_impl;
-mod department_repo_impl;
\ No newline at end of file
+mod department_repo_impl;
+mod event_log_repo_impl;
+mod export_repo_impl;
+mod job_repo_impl;
+mod outbox_repo_impl;
+mod search_repo_impl;
+mod webhook_delivery_repo_impl;
+mod webhook_repo_impl;
+
+use std::fmt;
+
+use crate::domain::error::{DomainError, ErrorKind};
+
+fn stale_or_missing(exists: bool, entity: &str, id: impl fmt::Display) -> DomainError {
+    if exists {
+        DomainError::CaseError(
+            ErrorKind::PreconditionFailed,
+            "VERSION_MISMATCH".to_string(),
+            format!(
+                "{} with id: {} has been modified by someone else",
+                entity, id
+            ),
+        )
+    } else {
+        DomainError::NotFound(format!("{} with id: {} is not found", entity, id))
+    }
+}
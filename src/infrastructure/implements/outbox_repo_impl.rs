@@ -0,0 +1,149 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sea_orm::{
+    ActiveValue::Set,
+    ColumnTrait, ConnectionTrait, DbErr, EntityTrait, QueryFilter, QueryOrder, QuerySelect,
+    sea_query::{Expr, LockBehavior, LockType},
+};
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        error::DomainError,
+        events::{DomainEvent, OutboxMessage, repositories::OutboxRepository},
+    },
+    infrastructure::db::{Repository, entities::outbox},
+};
+
+const PENDING: &str = "pending";
+const PUBLISHED: &str = "published";
+const DEAD: &str = "dead";
+
+#[async_trait]
+impl<'a, C: ConnectionTrait> OutboxRepository for Repository<'a, C> {
+    async fn add(&self, event: DomainEvent) -> Result<(), DomainError> {
+        outbox::Entity::insert(message(self, event)?)
+            .exec_without_returning(&self.db)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn add_many(&self, events: Vec<DomainEvent>) -> Result<(), DomainError> {
+        if events.is_empty() {
+            return Ok(());
+        }
+
+        let messages = events
+            .into_iter()
+            .map(|event| message(self, event))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        outbox::Entity::insert_many(messages)
+            .exec_without_returning(&self.db)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn claim_due(
+        &self,
+        limit: u64,
+        until: DateTime<Utc>,
+    ) -> Result<Vec<OutboxMessage>, DomainError> {
+        let due = self
+            .find::<outbox::Entity>()
+            .filter(outbox::Column::Status.eq(PENDING))
+            .filter(outbox::Column::NextAttemptAt.lte(Utc::now().fixed_offset()))
+            .order_by_asc(outbox::Column::CreatedAt)
+            .limit(limit)
+            .lock_with_behavior(LockType::Update, LockBehavior::SkipLocked)
+            .all(&self.db)
+            .await?;
+
+        if due.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        self.update_many::<outbox::Entity>()
+            .col_expr(
+                outbox::Column::NextAttemptAt,
+                Expr::value(until.fixed_offset()),
+            )
+            .filter(outbox::Column::Id.is_in(due.iter().map(|m| m.id)))
+            .exec(&self.db)
+            .await?;
+
+        Ok(due.into_iter().map(|m| m.into()).collect())
+    }
+
+    async fn mark_published(&self, id: Uuid) -> Result<(), DomainError> {
+        self.update_many::<outbox::Entity>()
+            .col_expr(outbox::Column::Status, Expr::value(PUBLISHED))
+            .col_expr(
+                outbox::Column::PublishedAt,
+                Expr::value(Utc::now().fixed_offset()),
+            )
+            .col_expr(
+                outbox::Column::LastError,
+                Expr::value(Option::<String>::None),
+            )
+            .filter(outbox::Column::Id.eq(id))
+            .exec(&self.db)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn mark_failed(
+        &self,
+        id: Uuid,
+        error: &str,
+        retry_at: Option<DateTime<Utc>>,
+    ) -> Result<(), DomainError> {
+        let mut query = self
+            .update_many::<outbox::Entity>()
+            .col_expr(
+                outbox::Column::Attempts,
+                Expr::col(outbox::Column::Attempts).add(1),
+            )
+            .col_expr(outbox::Column::LastError, Expr::value(error));
+
+        query = match retry_at {
+            Some(at) => query.col_expr(
+                outbox::Column::NextAttemptAt,
+                Expr::value(at.fixed_offset()),
+            ),
+            None => query.col_expr(outbox::Column::Status, Expr::value(DEAD)),
+        };
+
+        query
+            .filter(outbox::Column::Id.eq(id))
+            .exec(&self.db)
+            .await?;
+
+        Ok(())
+    }
+}
+
+fn message<C: ConnectionTrait>(
+    repo: &Repository<'_, C>,
+    event: DomainEvent,
+) -> Result<outbox::ActiveModel, DbErr> {
+    let now = Utc::now().fixed_offset();
+    let payload = serde_json::to_value(&event).map_err(|e| DbErr::Custom(e.to_string()))?;
+
+    Ok(outbox::ActiveModel {
+        id: Set(Uuid::new_v4()),
+        tenant_id: Set(repo.tenant.id()),
+        event_type: Set(event.event_type().to_string()),
+        aggregate_id: Set(event.aggregate_id()),
+        payload: Set(payload),
+        status: Set(PENDING.to_string()),
+        attempts: Set(0),
+        next_attempt_at: Set(now),
+        last_error: Set(None),
+        created_at: Set(now),
+        published_at: Set(None),
+    })
+}
@@ -0,0 +1,236 @@
+use async_trait::async_trait;
+use sea_orm::{
+    ColumnTrait, Condition, ConnectionTrait, DatabaseBackend, FromQueryResult, JoinType,
+    QueryFilter, QueryOrder, QuerySelect, RelationTrait,
+    sea_query::{Alias, Expr, Func, SimpleExpr},
+};
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        error::DomainError,
+        organization::{SearchHit, SearchKind, repositories::SearchRepository},
+    },
+    infrastructure::db::{
+        Repository, contains,
+        entities::{companies, departments},
+    },
+};
+
+// Where `ts_headline` starts and stops a match, characters of the private use area so
+// that the marks can be told apart from the name once it is escaped.
+const START_SEL: char = '\u{E000}';
+const STOP_SEL: char = '\u{E001}';
+
+#[derive(FromQueryResult)]
+struct SearchRow {
+    id: Uuid,
+    name: String,
+    company_id: Option<Uuid>,
+    company_name: Option<String>,
+    score: f64,
+    highlight: String,
+}
+
+#[async_trait]
+impl<'a, C: ConnectionTrait> SearchRepository for Repository<'a, C> {
+    async fn search(
+        &self,
+        q: &str,
+        kinds: &[SearchKind],
+        limit: u64,
+    ) -> Result<Vec<SearchHit>, DomainError> {
+        let backend = self.db.get_database_backend();
+        let mut hits = Vec::new();
+
+        if kinds.contains(&SearchKind::Company) {
+            let rows = self
+                .find::<companies::Entity>()
+                .filter(companies::Column::DeletedAt.is_null())
+                .filter(matches(backend, "companies", q))
+                .select_only()
+                .columns([companies::Column::Id, companies::Column::Name])
+                .expr_as(Expr::value(Option::<Uuid>::None), "company_id")
+                .expr_as(Expr::value(Option::<String>::None), "company_name")
+                .expr_as(score(backend, "companies", q), "score")
+                .expr_as(highlight(backend, "companies", q), "highlight")
+                .order_by_desc(Expr::cust("score"))
+                .limit(limit)
+                .into_model::<SearchRow>()
+                .all(&self.db)
+                .await?;
+
+            hits.extend(
+                rows.into_iter()
+                    .map(|row| hit(backend, SearchKind::Company, row, q)),
+            );
+        }
+
+        if kinds.contains(&SearchKind::Department) {
+            let rows = self
+                .find::<departments::Entity>()
+                .join(JoinType::InnerJoin, departments::Relation::Companies.def())
+                .filter(departments::Column::DeletedAt.is_null())
+                .filter(companies::Column::DeletedAt.is_null())
+                .filter(matches(backend, "departments", q))
+                .select_only()
+                .columns([
+                    departments::Column::Id,
+                    departments::Column::Name,
+                    departments::Column::CompanyId,
+                ])
+                .column_as(companies::Column::Name, "company_name")
+                .expr_as(score(backend, "departments", q), "score")
+                .expr_as(highlight(backend, "departments", q), "highlight")
+                .order_by_desc(Expr::cust("score"))
+                .limit(limit)
+                .into_model::<SearchRow>()
+                .all(&self.db)
+                .await?;
+
+            hits.extend(
+                rows.into_iter()
+                    .map(|row| hit(backend, SearchKind::Department, row, q)),
+            );
+        }
+
+        hits.sort_by(|a, b| b.score.total_cmp(&a.score));
+        hits.truncate(limit as usize);
+
+        Ok(hits)
+    }
+}
+
+// Whole words are found through the `search_vector` column, misspelled or partial ones
+// through the trigram index on `name`. The other backends have neither, there the name has
+// to contain every word of `q`, whatever their case.
+fn matches(backend: DatabaseBackend, table: &str, q: &str) -> Condition {
+    if backend != DatabaseBackend::Postgres {
+        return q.split_whitespace().fold(Condition::all(), |cond, word| {
+            cond.add(Expr::expr(lower_name(table)).like(contains(&word.to_lowercase())))
+        });
+    }
+
+    Condition::all().add(Expr::cust_with_values(
+        format!(
+            r#"("{table}"."search_vector" @@ websearch_to_tsquery('simple', $1) OR "{table}"."name" % $2 OR $3 <% "{table}"."name")"#
+        ),
+        [q, q, q],
+    ))
+}
+
+// whole names first, then those starting with `q`
+fn score(backend: DatabaseBackend, table: &str, q: &str) -> SimpleExpr {
+    if backend != DatabaseBackend::Postgres {
+        let q = q.trim().to_lowercase();
+        return Expr::case(Expr::expr(lower_name(table)).eq(q.clone()), 1.0)
+            .case(Expr::expr(lower_name(table)).like(format!("{}%", q)), 0.75)
+            .finally(0.5)
+            .into();
+    }
+
+    Expr::cust_with_values(
+        format!(
+            r#"(ts_rank("{table}"."search_vector", websearch_to_tsquery('simple', $1)) + greatest(similarity("{table}"."name", $2), word_similarity($3, "{table}"."name")))::float8"#
+        ),
+        [q, q, q],
+    )
+}
+
+// marked by `hit`, on the other backends from the name alone
+fn highlight(backend: DatabaseBackend, table: &str, q: &str) -> SimpleExpr {
+    if backend != DatabaseBackend::Postgres {
+        return Expr::col((Alias::new(table), Alias::new("name"))).into();
+    }
+
+    Expr::cust_with_values(
+        format!(
+            r#"ts_headline('simple', "{table}"."name", websearch_to_tsquery('simple', $1), $2)"#
+        ),
+        [
+            q.to_owned(),
+            format!(r#"StartSel="{START_SEL}", StopSel="{STOP_SEL}", HighlightAll=true"#),
+        ],
+    )
+}
+
+fn lower_name(table: &str) -> SimpleExpr {
+    Func::lower(Expr::col((Alias::new(table), Alias::new("name")))).into()
+}
+
+fn hit(backend: DatabaseBackend, kind: SearchKind, row: SearchRow, q: &str) -> SearchHit {
+    let highlight = match backend {
+        DatabaseBackend::Postgres => escape(&row.highlight)
+            .replace(START_SEL, "<mark>")
+            .replace(STOP_SEL, "</mark>"),
+        _ => mark(&row.name, q),
+    };
+
+    SearchHit {
+        kind,
+        id: row.id,
+        name: row.name,
+        company_id: row.company_id,
+        company_name: row.company_name,
+        score: row.score,
+        highlight,
+    }
+}
+
+/// Puts the words of `q` found in `name` between the marks used on Postgres, the name
+/// being escaped as the marks make it HTML.
+fn mark(name: &str, q: &str) -> String {
+    let words: Vec<Vec<char>> = q
+        .split_whitespace()
+        .map(|word| word.to_lowercase().chars().collect())
+        .collect();
+
+    let mut marked = String::with_capacity(name.len());
+    let mut rest = name;
+    'next: while let Some(c) = rest.chars().next() {
+        for word in &words {
+            if let Some(len) = match_len(rest, word) {
+                marked.push_str("<mark>");
+                marked.push_str(&escape(&rest[..len]));
+                marked.push_str("</mark>");
+                rest = &rest[len..];
+                continue 'next;
+            }
+        }
+
+        marked.push_str(&escape(&rest[..c.len_utf8()]));
+        rest = &rest[c.len_utf8()..];
+    }
+
+    marked
+}
+
+/// `text` with the characters HTML gives a meaning to replaced by their entities.
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            c => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+/// The length of the start of `text` that is `word` once lowercased.
+fn match_len(text: &str, word: &[char]) -> Option<usize> {
+    let mut lowered = Vec::with_capacity(word.len());
+    for (i, c) in text.char_indices() {
+        lowered.extend(c.to_lowercase());
+        if lowered.len() >= word.len() {
+            return (lowered == word).then_some(i + c.len_utf8());
+        }
+    }
+
+    None
+}
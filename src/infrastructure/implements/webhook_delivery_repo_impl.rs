@@ -0,0 +1,243 @@
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sea_orm::{
+    ActiveValue::Set,
+    ColumnTrait, ConnectionTrait, EntityTrait, QueryFilter, QueryOrder, QuerySelect,
+    sea_query::{Expr, LockBehavior, LockType, OnConflict, Query},
+};
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        error::DomainError,
+        events::OutboxMessage,
+        webhook::{
+            DueWebhookDelivery, WebhookDelivery, WebhookDeliveryCriteria, WebhookDeliveryStatus,
+            filter_matches, repositories::WebhookDeliveryRepository,
+        },
+    },
+    infrastructure::db::{
+        Repository,
+        entities::{webhook_deliveries, webhook_subscriptions},
+    },
+};
+
+#[async_trait]
+impl<'a, C: ConnectionTrait> WebhookDeliveryRepository for Repository<'a, C> {
+    async fn enqueue(&self, message: &OutboxMessage) -> Result<u64, DomainError> {
+        let Some(tenant_id) = message.tenant_id else {
+            return Ok(0);
+        };
+
+        let subscriptions = self
+            .find::<webhook_subscriptions::Entity>()
+            .filter(webhook_subscriptions::Column::TenantId.eq(tenant_id))
+            .filter(webhook_subscriptions::Column::Active.eq(true))
+            .all(&self.db)
+            .await?;
+
+        let now = Utc::now().fixed_offset();
+        let deliveries: Vec<_> = subscriptions
+            .into_iter()
+            .filter(|s| {
+                serde_json::from_value::<Vec<String>>(s.events.clone())
+                    .unwrap_or_default()
+                    .iter()
+                    .any(|filter| filter_matches(filter, &message.event_type))
+            })
+            .map(|s| webhook_deliveries::ActiveModel {
+                id: Set(Uuid::new_v4()),
+                tenant_id: Set(tenant_id),
+                subscription_id: Set(s.id),
+                message_id: Set(message.id),
+                event_type: Set(message.event_type.clone()),
+                payload: Set(serde_json::to_value(message).unwrap_or_default()),
+                status: Set(WebhookDeliveryStatus::Pending.as_str().to_string()),
+                attempts: Set(0),
+                next_attempt_at: Set(now),
+                last_status_code: Set(None),
+                last_error: Set(None),
+                created_at: Set(now),
+                delivered_at: Set(None),
+            })
+            .collect();
+
+        if deliveries.is_empty() {
+            return Ok(0);
+        }
+
+        let result = webhook_deliveries::Entity::insert_many(deliveries)
+            .on_conflict(
+                OnConflict::columns([
+                    webhook_deliveries::Column::SubscriptionId,
+                    webhook_deliveries::Column::MessageId,
+                ])
+                .do_nothing_on([webhook_deliveries::Column::Id])
+                .to_owned(),
+            )
+            .exec_without_returning(&self.db)
+            .await?;
+
+        Ok(result)
+    }
+
+    async fn claim_due(
+        &self,
+        limit: u64,
+        until: DateTime<Utc>,
+    ) -> Result<Vec<DueWebhookDelivery>, DomainError> {
+        let deliveries = self
+            .find::<webhook_deliveries::Entity>()
+            .filter(webhook_deliveries::Column::Status.eq(WebhookDeliveryStatus::Pending.as_str()))
+            .filter(webhook_deliveries::Column::NextAttemptAt.lte(Utc::now().fixed_offset()))
+            .filter(
+                webhook_deliveries::Column::SubscriptionId.in_subquery(
+                    Query::select()
+                        .column(webhook_subscriptions::Column::Id)
+                        .from(webhook_subscriptions::Entity)
+                        .and_where(webhook_subscriptions::Column::Active.eq(true))
+                        .to_owned(),
+                ),
+            )
+            .order_by_asc(webhook_deliveries::Column::CreatedAt)
+            .limit(limit)
+            .lock_with_behavior(LockType::Update, LockBehavior::SkipLocked)
+            .all(&self.db)
+            .await?;
+
+        if deliveries.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        self.update_many::<webhook_deliveries::Entity>()
+            .col_expr(
+                webhook_deliveries::Column::NextAttemptAt,
+                Expr::value(until.fixed_offset()),
+            )
+            .filter(webhook_deliveries::Column::Id.is_in(deliveries.iter().map(|d| d.id)))
+            .exec(&self.db)
+            .await?;
+
+        let subscriptions: HashMap<Uuid, webhook_subscriptions::Model> = self
+            .find::<webhook_subscriptions::Entity>()
+            .filter(
+                webhook_subscriptions::Column::Id
+                    .is_in(deliveries.iter().map(|d| d.subscription_id)),
+            )
+            .all(&self.db)
+            .await?
+            .into_iter()
+            .map(|s| (s.id, s))
+            .collect();
+
+        let result = deliveries
+            .into_iter()
+            .filter_map(|d| {
+                let subscription = subscriptions.get(&d.subscription_id)?;
+
+                Some(DueWebhookDelivery {
+                    id: d.id,
+                    event_type: d.event_type,
+                    payload: d.payload,
+                    attempts: d.attempts,
+                    url: subscription.url.clone(),
+                    secret: subscription.secret.clone(),
+                })
+            })
+            .collect();
+
+        Ok(result)
+    }
+
+    async fn mark_delivered(&self, id: Uuid, status_code: i32) -> Result<(), DomainError> {
+        self.update_many::<webhook_deliveries::Entity>()
+            .col_expr(
+                webhook_deliveries::Column::Status,
+                Expr::value(WebhookDeliveryStatus::Delivered.as_str()),
+            )
+            .col_expr(
+                webhook_deliveries::Column::Attempts,
+                Expr::col(webhook_deliveries::Column::Attempts).add(1),
+            )
+            .col_expr(
+                webhook_deliveries::Column::LastStatusCode,
+                Expr::value(status_code),
+            )
+            .col_expr(
+                webhook_deliveries::Column::LastError,
+                Expr::value(Option::<String>::None),
+            )
+            .col_expr(
+                webhook_deliveries::Column::DeliveredAt,
+                Expr::value(Utc::now().fixed_offset()),
+            )
+            .filter(webhook_deliveries::Column::Id.eq(id))
+            .exec(&self.db)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn mark_failed(
+        &self,
+        id: Uuid,
+        status_code: Option<i32>,
+        error: &str,
+        retry_at: Option<DateTime<Utc>>,
+    ) -> Result<(), DomainError> {
+        let mut query = self
+            .update_many::<webhook_deliveries::Entity>()
+            .col_expr(
+                webhook_deliveries::Column::Attempts,
+                Expr::col(webhook_deliveries::Column::Attempts).add(1),
+            )
+            .col_expr(
+                webhook_deliveries::Column::LastStatusCode,
+                Expr::value(status_code),
+            )
+            .col_expr(webhook_deliveries::Column::LastError, Expr::value(error));
+
+        query = match retry_at {
+            Some(at) => query.col_expr(
+                webhook_deliveries::Column::NextAttemptAt,
+                Expr::value(at.fixed_offset()),
+            ),
+            None => query.col_expr(
+                webhook_deliveries::Column::Status,
+                Expr::value(WebhookDeliveryStatus::Dead.as_str()),
+            ),
+        };
+
+        query
+            .filter(webhook_deliveries::Column::Id.eq(id))
+            .exec(&self.db)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn query(
+        &self,
+        subscription_id: Uuid,
+        criteria: &WebhookDeliveryCriteria,
+    ) -> Result<Vec<WebhookDelivery>, DomainError> {
+        let mut query = self
+            .find::<webhook_deliveries::Entity>()
+            .filter(webhook_deliveries::Column::SubscriptionId.eq(subscription_id));
+
+        if let Some(status) = criteria.status {
+            query = query.filter(webhook_deliveries::Column::Status.eq(status.as_str()));
+        }
+
+        let result = query
+            .order_by_desc(webhook_deliveries::Column::CreatedAt)
+            .limit(criteria.limit.unwrap_or(100))
+            .offset(criteria.offset)
+            .all(&self.db)
+            .await?;
+
+        Ok(result.into_iter().map(|d| d.into()).collect())
+    }
+}
@@ -0,0 +1,90 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use sea_orm::{ActiveModelTrait, ActiveValue::Set, ConnectionTrait, DbErr, QueryOrder};
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        error::DomainError,
+        webhook::{NewWebhook, Webhook, WebhookChanges, repositories::WebhookRepository},
+    },
+    infrastructure::db::{Repository, entities::webhook_subscriptions},
+};
+
+#[async_trait]
+impl<'a, C: ConnectionTrait> WebhookRepository for Repository<'a, C> {
+    async fn query(&self) -> Result<Vec<Webhook>, DomainError> {
+        let result = self
+            .find::<webhook_subscriptions::Entity>()
+            .order_by_asc(webhook_subscriptions::Column::CreatedAt)
+            .all(&self.db)
+            .await?;
+
+        Ok(result.into_iter().map(|w| w.into()).collect())
+    }
+
+    async fn find(&self, id: Uuid) -> Result<Option<Webhook>, DomainError> {
+        let result = find_active(self, id).await?;
+
+        Ok(result.map(|w| w.into()))
+    }
+
+    async fn add(&self, hook: NewWebhook) -> Result<Uuid, DomainError> {
+        let now = Utc::now().fixed_offset();
+
+        let subscription = webhook_subscriptions::ActiveModel {
+            id: Set(Uuid::new_v4()),
+            tenant_id: Set(self.tenant.require()?),
+            url: Set(hook.url),
+            events: Set(hook.events.into()),
+            secret: Set(hook.secret),
+            active: Set(true),
+            created_at: Set(now),
+            updated_at: Set(now),
+        };
+
+        let subscription = subscription.insert(&self.db).await?;
+
+        Ok(subscription.id)
+    }
+
+    async fn update(&self, id: Uuid, changes: WebhookChanges) -> Result<(), DomainError> {
+        let subscription = find_active(self, id).await?.ok_or_else(|| not_found(id))?;
+
+        let mut subscription: webhook_subscriptions::ActiveModel = subscription.into();
+        subscription.url = Set(changes.url);
+        subscription.events = Set(changes.events.into());
+        subscription.active = Set(changes.active);
+        subscription.updated_at = Set(Utc::now().fixed_offset());
+
+        subscription.update(&self.db).await?;
+
+        Ok(())
+    }
+
+    async fn delete(&self, id: Uuid) -> Result<(), DomainError> {
+        let result = self
+            .delete_by_id::<webhook_subscriptions::Entity>(id)
+            .exec(&self.db)
+            .await?;
+
+        if result.rows_affected == 0 {
+            return Err(not_found(id));
+        }
+
+        Ok(())
+    }
+}
+
+async fn find_active<C: ConnectionTrait>(
+    repo: &Repository<'_, C>,
+    id: Uuid,
+) -> Result<Option<webhook_subscriptions::Model>, DbErr> {
+    repo.find_by_id::<webhook_subscriptions::Entity>(id)
+        .one(&repo.db)
+        .await
+}
+
+fn not_found(id: Uuid) -> DomainError {
+    DomainError::NotFound(format!("webhook with id: {} is not found", id))
+}
@@ -0,0 +1,30 @@
+mod outbox;
+mod purge;
+mod queue;
+mod scheduler;
+mod webhook;
+mod worker;
+
+pub use outbox::*;
+pub use purge::*;
+pub use queue::*;
+pub use scheduler::*;
+pub use webhook::*;
+pub use worker::*;
+
+use chrono::TimeDelta;
+
+const MAX_BACKOFF_SECS: i64 = 3600;
+
+/// Delay before the next attempt of something that failed `attempts` times.
+fn backoff(attempts: i32) -> TimeDelta {
+    TimeDelta::seconds(2i64.saturating_pow(attempts as u32).min(MAX_BACKOFF_SECS))
+}
+
+/// Cuts `text` down to at most `max` characters.
+fn truncate(text: &str, max: usize) -> &str {
+    match text.char_indices().nth(max) {
+        Some((idx, _)) => &text[..idx],
+        None => text,
+    }
+}
@@ -0,0 +1,134 @@
+use std::{sync::Arc, time::Duration};
+
+use chrono::Utc;
+use tokio::task::JoinHandle;
+
+use crate::{
+    domain::{error::DomainError, events::OutboxMessage},
+    infrastructure::{
+        db::{DbContext, TenantScope},
+        events::EventSink,
+    },
+    with_transaction,
+};
+
+use super::backoff;
+
+/// Relays the events written to the outbox to the registered sinks. Messages that
+/// cannot be delivered are retried with an exponential backoff until `max_attempts`.
+pub struct OutboxDispatcher {
+    db_context: Arc<DbContext>,
+    sinks: Arc<Vec<Arc<dyn EventSink>>>,
+    batch_size: u64,
+    max_attempts: i32,
+    lease: Duration,
+}
+
+impl OutboxDispatcher {
+    pub fn new(db_context: Arc<DbContext>) -> Self {
+        Self {
+            db_context,
+            sinks: Arc::new(Vec::new()),
+            batch_size: 100,
+            max_attempts: 10,
+            lease: Duration::from_secs(300),
+        }
+    }
+
+    pub fn with_sink(mut self, sink: Arc<dyn EventSink>) -> Self {
+        Arc::make_mut(&mut self.sinks).push(sink);
+        self
+    }
+
+    pub fn with_batch_size(mut self, batch_size: u64) -> Self {
+        self.batch_size = batch_size;
+        self
+    }
+
+    pub fn with_max_attempts(mut self, max_attempts: i32) -> Self {
+        self.max_attempts = max_attempts;
+        self
+    }
+
+    /// How long a claimed batch is kept from other dispatchers, which must cover
+    /// delivering all of it.
+    pub fn with_lease(mut self, lease: Duration) -> Self {
+        self.lease = lease;
+        self
+    }
+
+    /// Returns how many messages were handled, delivered or not. The batch is claimed
+    /// in a short transaction and delivered outside of it, each outcome being recorded
+    /// on its own so that one failing write does not send the others again.
+    pub async fn run_once(&self) -> Result<usize, DomainError> {
+        let batch_size = self.batch_size;
+        let until = Utc::now() + self.lease;
+
+        let messages = with_transaction!(self.db_context, TenantScope::All, provider => {
+            provider.outbox_repo().claim_due(batch_size, until).await
+        })?;
+        let count = messages.len();
+
+        let provider = self.db_context.provider(TenantScope::All);
+        let repo = provider.outbox_repo();
+        for message in messages {
+            let recorded = match publish(&self.sinks, &message).await {
+                Ok(()) => repo.mark_published(message.id).await,
+                Err(e) => {
+                    let attempts = message.attempts + 1;
+                    let retry_at =
+                        (attempts < self.max_attempts).then(|| Utc::now() + backoff(attempts));
+
+                    tracing::warn!(
+                        "failed to deliver {} {} (attempt {}): {}",
+                        message.event_type,
+                        message.id,
+                        attempts,
+                        e
+                    );
+
+                    repo.mark_failed(message.id, &e.to_string(), retry_at).await
+                }
+            };
+
+            // the message is delivered again once its lease runs out
+            if let Err(e) = recorded {
+                tracing::error!("failed to record the delivery of {}: {}", message.id, e);
+            }
+        }
+
+        Ok(count)
+    }
+
+    pub fn spawn(self, every: Duration) -> JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(every);
+
+            loop {
+                interval.tick().await;
+
+                // keep draining while there is a backlog
+                loop {
+                    match self.run_once().await {
+                        Ok(count) if count as u64 == self.batch_size => continue,
+                        Ok(_) => break,
+                        Err(e) => {
+                            tracing::error!("failed to dispatch outbox messages: {}", e);
+                            break;
+                        }
+                    }
+                }
+            }
+        })
+    }
+}
+
+async fn publish(sinks: &[Arc<dyn EventSink>], message: &OutboxMessage) -> anyhow::Result<()> {
+    for sink in sinks {
+        sink.publish(message)
+            .await
+            .map_err(|e| anyhow::anyhow!("{}: {}", sink.name(), e))?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,115 @@
+use std::{sync::Arc, time::Duration};
+
+use async_trait::async_trait;
+use chrono::{TimeDelta, Utc};
+use serde::{Deserialize, Serialize};
+use tokio::task::JoinHandle;
+
+use crate::{
+    domain::error::DomainError,
+    infrastructure::db::{DbContext, TenantScope},
+    with_transaction,
+};
+
+use super::{Job, JobContext};
+
+/// Hard deletes organization rows that have been soft deleted for longer than the
+/// retention window, for every tenant.
+pub struct PurgeJob {
+    db_context: Arc<DbContext>,
+    retention: TimeDelta,
+}
+
+impl PurgeJob {
+    pub fn new(db_context: Arc<DbContext>, retention_days: i64) -> Self {
+        Self {
+            db_context,
+            retention: TimeDelta::days(retention_days),
+        }
+    }
+
+    /// Returns how many companies and departments were removed.
+    pub async fn run_once(&self) -> Result<(u64, u64), DomainError> {
+        let deleted_before = Utc::now() - self.retention;
+
+        with_transaction!(self.db_context, TenantScope::All, provider => {
+            // departments go first, those of the purged companies included, so that
+            // the cascade on their company has nothing left to remove unaudited
+            let departments = provider.department_repo().purge(deleted_before).await?;
+            let companies = provider.company_repo().purge(deleted_before).await?;
+
+            Ok((companies, departments))
+        })
+    }
+
+    pub fn spawn(self, every: Duration) -> JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(every);
+
+            loop {
+                interval.tick().await;
+
+                match self.run_once().await {
+                    Ok((companies, departments)) => tracing::info!(
+                        "purged {} companies and {} departments",
+                        companies,
+                        departments
+                    ),
+                    Err(e) => tracing::error!("failed to purge deleted records: {}", e),
+                }
+            }
+        })
+    }
+}
+
+/// `PurgeJob` run through the job queue, so that a single instance purges at a time.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PurgeDeletedRecords {
+    pub retention_days: i64,
+}
+
+#[async_trait]
+impl Job for PurgeDeletedRecords {
+    const NAME: &'static str = "purge_deleted_records";
+    const MAX_ATTEMPTS: i32 = 3;
+
+    async fn run(self, ctx: JobContext) -> anyhow::Result<()> {
+        let (companies, departments) = PurgeJob::new(ctx.db_context, self.retention_days)
+            .run_once()
+            .await?;
+
+        tracing::info!(
+            "purged {} companies and {} departments",
+            companies,
+            departments
+        );
+        Ok(())
+    }
+}
+
+/// Drops the events of the change feed published longer than `retention_days` ago, a
+/// client resuming from before then being sent what is left.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PurgeEventLog {
+    pub retention_days: i64,
+}
+
+#[async_trait]
+impl Job for PurgeEventLog {
+    const NAME: &'static str = "purge_event_log";
+    const MAX_ATTEMPTS: i32 = 3;
+
+    async fn run(self, ctx: JobContext) -> anyhow::Result<()> {
+        let before = Utc::now() - TimeDelta::days(self.retention_days);
+
+        let events = ctx
+            .db_context
+            .provider(TenantScope::All)
+            .event_log_repo()
+            .purge(before)
+            .await?;
+
+        tracing::info!("purged {} events from the event log", events);
+        Ok(())
+    }
+}
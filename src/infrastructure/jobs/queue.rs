@@ -0,0 +1,86 @@
+use std::{collections::HashMap, future::Future, pin::Pin, sync::Arc};
+
+use async_trait::async_trait;
+use chrono::Utc;
+use sea_orm::DbErr;
+use serde::{Serialize, de::DeserializeOwned};
+use serde_json::Value;
+use uuid::Uuid;
+
+use crate::{
+    domain::error::DomainError,
+    domain::job::NewJob,
+    infrastructure::db::{DbContext, TenantScope},
+};
+
+pub const DEFAULT_QUEUE: &str = "default";
+
+/// A unit of background work, persisted as its serialized payload until a worker of
+/// `QUEUE` picks it up. Register it on the `JobRegistry` of those workers.
+#[async_trait]
+pub trait Job: Serialize + DeserializeOwned + Send + Sync + 'static {
+    const NAME: &'static str;
+    const QUEUE: &'static str = DEFAULT_QUEUE;
+    const MAX_ATTEMPTS: i32 = 5;
+
+    async fn run(self, ctx: JobContext) -> anyhow::Result<()>;
+
+    /// Enqueues the job to run right away once passed to `JobRepository::add`.
+    fn request(&self) -> Result<NewJob, DomainError> {
+        let payload = serde_json::to_value(self).map_err(|e| DbErr::Custom(e.to_string()))?;
+
+        Ok(NewJob {
+            name: Self::NAME.to_string(),
+            queue: Self::QUEUE.to_string(),
+            payload,
+            max_attempts: Self::MAX_ATTEMPTS,
+            run_at: Utc::now(),
+            dedupe_key: None,
+        })
+    }
+}
+
+#[derive(Clone)]
+pub struct JobContext {
+    pub db_context: Arc<DbContext>,
+    pub job_id: Uuid,
+    /// Tenant that enqueued the job, `All` for jobs enqueued by the system.
+    pub tenant: TenantScope,
+    pub attempt: i32,
+}
+
+type JobFuture = Pin<Box<dyn Future<Output = anyhow::Result<()>> + Send>>;
+type Handler = Arc<dyn Fn(Value, JobContext) -> JobFuture + Send + Sync>;
+
+/// Maps the job names stored in the queue back to their `Job` implementations.
+#[derive(Clone, Default)]
+pub struct JobRegistry {
+    handlers: HashMap<&'static str, Handler>,
+}
+
+impl JobRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn register<J: Job>(mut self) -> Self {
+        let handler: Handler = Arc::new(|payload, ctx| {
+            Box::pin(async move {
+                let job: J = serde_json::from_value(payload)?;
+                job.run(ctx).await
+            })
+        });
+
+        self.handlers.insert(J::NAME, handler);
+        self
+    }
+
+    pub async fn run(&self, name: &str, payload: Value, ctx: JobContext) -> anyhow::Result<()> {
+        let handler = self
+            .handlers
+            .get(name)
+            .ok_or_else(|| anyhow::anyhow!("no job registered as {}", name))?;
+
+        handler(payload, ctx).await
+    }
+}
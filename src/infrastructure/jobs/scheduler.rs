@@ -0,0 +1,127 @@
+use std::{str::FromStr, sync::Arc, time::Duration};
+
+use chrono::{DateTime, TimeDelta, Utc};
+use tokio::task::JoinHandle;
+
+use crate::{
+    domain::error::DomainError,
+    domain::job::NewJob,
+    infrastructure::db::{DbContext, TenantScope},
+};
+
+use super::Job;
+
+enum Schedule {
+    Cron(Box<cron::Schedule>),
+    Every(TimeDelta),
+}
+
+impl Schedule {
+    /// First occurrence strictly after `t`.
+    fn after(&self, t: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        match self {
+            Self::Cron(schedule) => schedule.after(&t).next(),
+            Self::Every(every) => Some(slot(t, *every) + *every),
+        }
+    }
+}
+
+/// Start of the interval slot `t` falls into, slots being aligned on the unix epoch
+/// so that every instance agrees on them.
+fn slot(t: DateTime<Utc>, every: TimeDelta) -> DateTime<Utc> {
+    let every = every.num_milliseconds().max(1);
+    let millis = t.timestamp_millis();
+
+    DateTime::from_timestamp_millis(millis - millis.rem_euclid(every)).unwrap_or(t)
+}
+
+struct Entry {
+    schedule: Schedule,
+    request: NewJob,
+    next: Option<DateTime<Utc>>,
+}
+
+/// Enqueues recurring jobs. Every occurrence is enqueued under a dedupe key made of the
+/// job name and its time, so that running the scheduler on several instances doesn't
+/// run the job more than once; a job name can therefore only have one schedule.
+/// Occurrences missed while no scheduler was running are skipped.
+pub struct JobScheduler {
+    db_context: Arc<DbContext>,
+    entries: Vec<Entry>,
+}
+
+impl JobScheduler {
+    pub fn new(db_context: Arc<DbContext>) -> Self {
+        Self {
+            db_context,
+            entries: Vec::new(),
+        }
+    }
+
+    /// `expr` is a cron expression with a leading seconds field, e.g. `0 30 2 * * *`.
+    pub fn with_cron<J: Job>(mut self, expr: &str, job: J) -> anyhow::Result<Self> {
+        let schedule = Schedule::Cron(Box::new(cron::Schedule::from_str(expr)?));
+        let next = schedule.after(Utc::now());
+
+        self.entries.push(Entry {
+            schedule,
+            request: job.request()?,
+            next,
+        });
+        Ok(self)
+    }
+
+    /// Runs the job right away, then every `every`.
+    pub fn with_interval<J: Job>(mut self, every: Duration, job: J) -> anyhow::Result<Self> {
+        let every = TimeDelta::from_std(every)?;
+        if every <= TimeDelta::zero() {
+            anyhow::bail!("the interval of {} must not be zero", J::NAME);
+        }
+
+        self.entries.push(Entry {
+            schedule: Schedule::Every(every),
+            request: job.request()?,
+            next: Some(slot(Utc::now(), every)),
+        });
+        Ok(self)
+    }
+
+    /// Returns how many jobs were enqueued.
+    pub async fn run_once(&mut self) -> Result<usize, DomainError> {
+        let now = Utc::now();
+        let provider = self.db_context.provider(TenantScope::All);
+        let repo = provider.job_repo();
+        let mut enqueued = 0;
+
+        for entry in self.entries.iter_mut() {
+            let Some(at) = entry.next.filter(|at| *at <= now) else {
+                continue;
+            };
+
+            let mut request = entry.request.clone();
+            request.run_at = at;
+            request.dedupe_key = Some(format!("{}@{}", request.name, at.timestamp()));
+
+            if repo.add(request).await?.is_some() {
+                enqueued += 1;
+            }
+            entry.next = entry.schedule.after(now);
+        }
+
+        Ok(enqueued)
+    }
+
+    pub fn spawn(mut self, every: Duration) -> JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(every);
+
+            loop {
+                interval.tick().await;
+
+                if let Err(e) = self.run_once().await {
+                    tracing::error!("failed to enqueue scheduled jobs: {}", e);
+                }
+            }
+        })
+    }
+}
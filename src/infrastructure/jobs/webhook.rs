@@ -0,0 +1,225 @@
+use std::{net::IpAddr, sync::Arc, time::Duration};
+
+use chrono::Utc;
+use tokio::task::JoinHandle;
+use url::{Host, Url};
+
+use crate::{
+    domain::{error::DomainError, webhook::DueWebhookDelivery},
+    infrastructure::{
+        db::{DbContext, TenantScope},
+        helpers::{
+            egress::{self, PublicResolver},
+            signature,
+        },
+    },
+    with_transaction,
+};
+
+use super::{backoff, truncate};
+
+pub const SIGNATURE_HEADER: &str = "X-Webhook-Signature";
+pub const TIMESTAMP_HEADER: &str = "X-Webhook-Timestamp";
+
+// keeps the delivery history readable when receivers answer with whole HTML pages
+const MAX_ERROR_LEN: usize = 500;
+
+/// Sends the queued webhook deliveries, retrying failures with an exponential backoff
+/// and dead-lettering them after `max_attempts`.
+pub struct WebhookDeliveryWorker {
+    db_context: Arc<DbContext>,
+    client: reqwest::Client,
+    batch_size: u64,
+    max_attempts: i32,
+    lease: Duration,
+    private_destinations: bool,
+}
+
+struct Failure {
+    status_code: Option<i32>,
+    error: String,
+}
+
+impl WebhookDeliveryWorker {
+    pub fn new(db_context: Arc<DbContext>) -> anyhow::Result<Self> {
+        Ok(Self {
+            db_context,
+            client: client(false)?,
+            batch_size: 20,
+            max_attempts: 8,
+            lease: Duration::from_secs(300),
+            private_destinations: false,
+        })
+    }
+
+    pub fn with_batch_size(mut self, batch_size: u64) -> Self {
+        self.batch_size = batch_size;
+        self
+    }
+
+    pub fn with_max_attempts(mut self, max_attempts: i32) -> Self {
+        self.max_attempts = max_attempts;
+        self
+    }
+
+    /// How long a claimed batch is kept from other workers, which must cover sending
+    /// all of it.
+    pub fn with_lease(mut self, lease: Duration) -> Self {
+        self.lease = lease;
+        self
+    }
+
+    /// Lets the deliveries reach loopback and private addresses, which are refused by
+    /// default as any tenant picks the URLs. Only for local development and tests.
+    pub fn with_private_destinations(mut self, allowed: bool) -> anyhow::Result<Self> {
+        self.client = client(allowed)?;
+        self.private_destinations = allowed;
+        Ok(self)
+    }
+
+    /// Returns how many deliveries were attempted. They are claimed in a short
+    /// transaction and sent outside of it, each outcome being recorded on its own.
+    pub async fn run_once(&self) -> Result<usize, DomainError> {
+        let batch_size = self.batch_size;
+        let until = Utc::now() + self.lease;
+
+        let deliveries = with_transaction!(self.db_context, TenantScope::All, provider => {
+            provider.webhook_delivery_repo().claim_due(batch_size, until).await
+        })?;
+        let count = deliveries.len();
+
+        let provider = self.db_context.provider(TenantScope::All);
+        let repo = provider.webhook_delivery_repo();
+        for delivery in deliveries {
+            let recorded = match send(&self.client, self.private_destinations, &delivery).await {
+                Ok(status_code) => repo.mark_delivered(delivery.id, status_code).await,
+                Err(failure) => {
+                    let attempts = delivery.attempts + 1;
+                    let retry_at =
+                        (attempts < self.max_attempts).then(|| Utc::now() + backoff(attempts));
+
+                    tracing::warn!(
+                        "failed to deliver webhook {} to {} (attempt {}): {}",
+                        delivery.id,
+                        delivery.url,
+                        attempts,
+                        failure.error
+                    );
+
+                    repo.mark_failed(delivery.id, failure.status_code, &failure.error, retry_at)
+                        .await
+                }
+            };
+
+            // the delivery is sent again once its lease runs out
+            if let Err(e) = recorded {
+                tracing::error!("failed to record webhook delivery {}: {}", delivery.id, e);
+            }
+        }
+
+        Ok(count)
+    }
+
+    pub fn spawn(self, every: Duration) -> JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(every);
+
+            loop {
+                interval.tick().await;
+
+                loop {
+                    match self.run_once().await {
+                        Ok(count) if count as u64 == self.batch_size => continue,
+                        Ok(_) => break,
+                        Err(e) => {
+                            tracing::error!("failed to deliver webhooks: {}", e);
+                            break;
+                        }
+                    }
+                }
+            }
+        })
+    }
+}
+
+// redirects are not followed, they could lead anywhere
+fn client(private_destinations: bool) -> reqwest::Result<reqwest::Client> {
+    let builder = reqwest::Client::builder()
+        .timeout(Duration::from_secs(10))
+        .redirect(reqwest::redirect::Policy::none());
+
+    if private_destinations {
+        builder.build()
+    } else {
+        builder.dns_resolver(PublicResolver::shared()).build()
+    }
+}
+
+async fn send(
+    client: &reqwest::Client,
+    private_destinations: bool,
+    delivery: &DueWebhookDelivery,
+) -> Result<i32, Failure> {
+    check_destination(&delivery.url, private_destinations).map_err(|error| Failure {
+        status_code: None,
+        error,
+    })?;
+
+    let body = serde_json::to_vec(&delivery.payload).map_err(|e| Failure {
+        status_code: None,
+        error: e.to_string(),
+    })?;
+    let timestamp = Utc::now().timestamp();
+
+    let res = client
+        .post(&delivery.url)
+        .header(reqwest::header::CONTENT_TYPE, "application/json")
+        .header("X-Webhook-Id", delivery.id.to_string())
+        .header("X-Webhook-Event", &delivery.event_type)
+        .header(TIMESTAMP_HEADER, timestamp.to_string())
+        .header(
+            SIGNATURE_HEADER,
+            signature::sign(&delivery.secret, timestamp, &body),
+        )
+        .body(body)
+        .send()
+        .await
+        .map_err(|e| Failure {
+            status_code: None,
+            error: e.to_string(),
+        })?;
+
+    let status = res.status();
+    if status.is_success() {
+        return Ok(status.as_u16() as i32);
+    }
+
+    let text = res.text().await.unwrap_or_default();
+
+    Err(Failure {
+        status_code: Some(status.as_u16() as i32),
+        error: format!("{}: {}", status, truncate(&text, MAX_ERROR_LEN)),
+    })
+}
+
+// names are checked once resolved, by `PublicResolver`, addresses never go through it
+fn check_destination(url: &str, private_destinations: bool) -> Result<(), String> {
+    let url = Url::parse(url).map_err(|e| format!("invalid url: {}", e))?;
+
+    if !matches!(url.scheme(), "http" | "https") {
+        return Err(format!("unsupported scheme {}", url.scheme()));
+    }
+
+    let ip = match url.host() {
+        Some(Host::Ipv4(ip)) => IpAddr::V4(ip),
+        Some(Host::Ipv6(ip)) => IpAddr::V6(ip),
+        Some(Host::Domain(_)) => return Ok(()),
+        None => return Err("url without a host".to_string()),
+    };
+
+    if private_destinations || egress::is_public(ip) {
+        Ok(())
+    } else {
+        Err(format!("{} is not a public address", ip))
+    }
+}
@@ -0,0 +1,185 @@
+use std::{sync::Arc, time::Duration};
+
+use chrono::Utc;
+use tokio::{sync::Semaphore, task::JoinHandle, task::JoinSet};
+
+use crate::{
+    domain::error::DomainError,
+    domain::job::DueJob,
+    infrastructure::db::{DbContext, TenantScope},
+    with_transaction,
+};
+
+use super::{DEFAULT_QUEUE, JobContext, JobRegistry, backoff, truncate};
+
+const MAX_ERROR_LEN: usize = 2000;
+
+/// Runs the jobs of one queue, at most `concurrency` at a time. A job still running
+/// after `lease` is abandoned and becomes claimable again, so the lease has to outlast
+/// the slowest job of the queue. The outcome of a job whose lease was lost meanwhile
+/// is dropped, the worker holding it now records its own.
+pub struct JobWorkerPool {
+    db_context: Arc<DbContext>,
+    registry: Arc<JobRegistry>,
+    queue: String,
+    concurrency: usize,
+    lease: Duration,
+}
+
+impl JobWorkerPool {
+    pub fn new(db_context: Arc<DbContext>, registry: Arc<JobRegistry>) -> Self {
+        Self {
+            db_context,
+            registry,
+            queue: DEFAULT_QUEUE.to_string(),
+            concurrency: 4,
+            lease: Duration::from_secs(300),
+        }
+    }
+
+    pub fn with_queue(mut self, queue: &str) -> Self {
+        self.queue = queue.to_string();
+        self
+    }
+
+    pub fn with_concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    pub fn with_lease(mut self, lease: Duration) -> Self {
+        self.lease = lease;
+        self
+    }
+
+    /// Runs a batch of due jobs to completion and returns how many were claimed.
+    pub async fn run_once(&self) -> Result<usize, DomainError> {
+        let jobs = self.claim(self.concurrency).await?;
+        let count = jobs.len();
+
+        let mut running = JoinSet::new();
+        for job in jobs {
+            running.spawn(execute(
+                self.db_context.clone(),
+                self.registry.clone(),
+                self.lease,
+                job,
+            ));
+        }
+        running.join_all().await;
+
+        Ok(count)
+    }
+
+    pub fn spawn(self, every: Duration) -> JoinHandle<()> {
+        let pool = Arc::new(self);
+        let permits = Arc::new(Semaphore::new(pool.concurrency));
+
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(every);
+
+            loop {
+                interval.tick().await;
+
+                // keep claiming while there are both idle workers and due jobs
+                loop {
+                    let idle = permits.available_permits();
+                    if idle == 0 {
+                        break;
+                    }
+
+                    let jobs = match pool.claim(idle).await {
+                        Ok(jobs) => jobs,
+                        Err(e) => {
+                            tracing::error!("failed to claim jobs of {}: {}", pool.queue, e);
+                            break;
+                        }
+                    };
+                    let claimed = jobs.len();
+
+                    for job in jobs {
+                        let Ok(permit) = permits.clone().acquire_owned().await else {
+                            return;
+                        };
+                        let (db_context, registry) =
+                            (pool.db_context.clone(), pool.registry.clone());
+                        let lease = pool.lease;
+
+                        tokio::spawn(async move {
+                            execute(db_context, registry, lease, job).await;
+                            drop(permit);
+                        });
+                    }
+
+                    if claimed < idle {
+                        break;
+                    }
+                }
+            }
+        })
+    }
+
+    async fn claim(&self, limit: usize) -> Result<Vec<DueJob>, DomainError> {
+        let queue = self.queue.clone();
+        let until = Utc::now() + self.lease;
+
+        with_transaction!(self.db_context, TenantScope::All, provider => {
+            provider.job_repo().claim(&queue, limit as u64, until).await
+        })
+    }
+}
+
+async fn execute(
+    db_context: Arc<DbContext>,
+    registry: Arc<JobRegistry>,
+    lease: Duration,
+    job: DueJob,
+) {
+    let ctx = JobContext {
+        db_context: db_context.clone(),
+        job_id: job.id,
+        tenant: job.tenant_id.map_or(TenantScope::All, TenantScope::Tenant),
+        attempt: job.attempts,
+    };
+
+    // a separate task keeps a panicking job from taking the worker down with it
+    let (name, payload) = (job.name.clone(), job.payload.clone());
+    let handle = tokio::spawn(async move { registry.run(&name, payload, ctx).await });
+    let abort = handle.abort_handle();
+
+    let result = match tokio::time::timeout(lease, handle).await {
+        Ok(Ok(result)) => result,
+        Ok(Err(e)) => Err(anyhow::anyhow!("job panicked: {}", e)),
+        Err(_) => {
+            abort.abort();
+            Err(anyhow::anyhow!("job timed out after {:?}", lease))
+        }
+    };
+
+    let provider = db_context.provider(TenantScope::All);
+    let repo = provider.job_repo();
+
+    let saved = match result {
+        Ok(()) => repo.complete(job.id, job.lease).await,
+        Err(e) => {
+            let error = format!("{:#}", e);
+            let retry_at =
+                (job.attempts < job.max_attempts).then(|| Utc::now() + backoff(job.attempts));
+
+            tracing::warn!(
+                "job {} ({}) failed on attempt {}: {}",
+                job.id,
+                job.name,
+                job.attempts,
+                error
+            );
+
+            repo.fail(job.id, job.lease, truncate(&error, MAX_ERROR_LEN), retry_at)
+                .await
+        }
+    };
+
+    if let Err(e) = saved {
+        tracing::error!("failed to record the outcome of job {}: {}", job.id, e);
+    }
+}
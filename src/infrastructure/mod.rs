@@ -1,3 +1,6 @@
 pub mod db;
+pub mod events;
 pub mod helpers;
 mod implements;
+pub mod jobs;
+pub mod seed;
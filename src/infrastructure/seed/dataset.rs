@@ -0,0 +1,206 @@
+use std::path::Path;
+
+use anyhow::Context;
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+use serde_json::Value;
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::{
+    application::dtos::webhook::ReqAddWebhookDto,
+    domain::error::DomainError,
+    infrastructure::db::{DEFAULT_TENANT_ID, DbContext, TenantScope},
+    with_transaction,
+};
+
+use super::{CompanyFactory, DepartmentFactory, JobFactory, WebhookFactory};
+
+/// A named set of fixtures, read from `<dir>/<name>.yaml`, `.yml` or `.json`. Whatever a
+/// fixture leaves out is filled in by its factory.
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Dataset {
+    /// The default tenant when missing.
+    pub tenant_id: Option<Uuid>,
+    #[serde(default)]
+    pub companies: Vec<CompanyFixture>,
+    #[serde(default)]
+    pub webhooks: Vec<WebhookFixture>,
+    #[serde(default)]
+    pub jobs: Vec<JobFixture>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CompanyFixture {
+    pub id: Option<Uuid>,
+    pub name: Option<String>,
+    #[serde(default)]
+    pub deleted: bool,
+    #[serde(default)]
+    pub departments: Vec<DepartmentFixture>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct DepartmentFixture {
+    pub id: Option<Uuid>,
+    pub name: Option<String>,
+    #[serde(default)]
+    pub deleted: bool,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct WebhookFixture {
+    pub url: Option<String>,
+    pub events: Option<Vec<String>>,
+    pub secret: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct JobFixture {
+    pub name: Option<String>,
+    pub queue: Option<String>,
+    pub payload: Option<Value>,
+    pub max_attempts: Option<i32>,
+    pub run_at: Option<DateTime<Utc>>,
+    pub dedupe_key: Option<String>,
+}
+
+/// How many rows a dataset added.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct SeedReport {
+    pub companies: usize,
+    pub departments: usize,
+    pub webhooks: usize,
+    pub jobs: usize,
+}
+
+impl Dataset {
+    pub fn load(dir: impl AsRef<Path>, name: &str) -> anyhow::Result<Self> {
+        if name.is_empty() || name.contains(['/', '\\', '.']) {
+            anyhow::bail!("invalid dataset name {:?}", name);
+        }
+
+        let dir = dir.as_ref();
+        for ext in ["yaml", "yml", "json"] {
+            let path = dir.join(format!("{}.{}", name, ext));
+            if !path.exists() {
+                continue;
+            }
+            let text = std::fs::read_to_string(&path)
+                .with_context(|| format!("failed to read {}", path.display()))?;
+            let dataset = if ext == "json" {
+                Self::from_json(&text)
+            } else {
+                Self::from_yaml(&text)
+            };
+            return dataset.with_context(|| format!("invalid dataset {}", path.display()));
+        }
+
+        anyhow::bail!("no dataset named {:?} in {}", name, dir.display())
+    }
+
+    pub fn from_yaml(text: &str) -> anyhow::Result<Self> {
+        Ok(serde_yaml::from_str(text)?)
+    }
+
+    pub fn from_json(text: &str) -> anyhow::Result<Self> {
+        Ok(serde_json::from_str(text)?)
+    }
+
+    /// Adds the whole dataset in a single transaction, so a bad fixture leaves nothing
+    /// behind. It goes through the repositories rather than the use cases: no events
+    /// are published for the seeded rows.
+    pub async fn seed(self, db_context: &DbContext) -> Result<SeedReport, DomainError> {
+        let tenant = TenantScope::Tenant(self.tenant_id.unwrap_or(DEFAULT_TENANT_ID));
+
+        with_transaction!(db_context, tenant, provider => {
+            let mut report = SeedReport::default();
+
+            for fixture in self.companies {
+                let mut factory = CompanyFactory::new();
+                if let Some(id) = fixture.id {
+                    factory = factory.id(id.into());
+                }
+                if let Some(name) = fixture.name {
+                    factory = factory.name(name);
+                }
+                if fixture.deleted {
+                    factory = factory.deleted();
+                }
+                let company = factory.create(provider).await?;
+                report.companies += 1;
+
+                for fixture in fixture.departments {
+                    let mut factory = DepartmentFactory::new().company(company.id);
+                    if let Some(id) = fixture.id {
+                        factory = factory.id(id.into());
+                    }
+                    if let Some(name) = fixture.name {
+                        factory = factory.name(name);
+                    }
+                    if fixture.deleted {
+                        factory = factory.deleted();
+                    }
+                    factory.create(provider).await?;
+                    report.departments += 1;
+                }
+            }
+
+            for fixture in self.webhooks {
+                let mut factory = WebhookFactory::new();
+                if let Some(url) = fixture.url {
+                    factory = factory.url(url);
+                }
+                if let Some(events) = fixture.events {
+                    factory = factory.events(events);
+                }
+                if let Some(secret) = fixture.secret {
+                    factory = factory.secret(secret);
+                }
+                // the repository takes the subscription as given, the checks are the use case's
+                let hook = factory.clone().build();
+                ReqAddWebhookDto {
+                    url: hook.url,
+                    events: hook.events,
+                    secret: Some(hook.secret),
+                }
+                .validate()
+                .map_err(|e| DomainError::Invalid(e.to_string()))?;
+                factory.create(provider).await?;
+                report.webhooks += 1;
+            }
+
+            for fixture in self.jobs {
+                let mut factory = JobFactory::new();
+                if let Some(name) = fixture.name {
+                    factory = factory.name(name);
+                }
+                if let Some(queue) = fixture.queue {
+                    factory = factory.queue(queue);
+                }
+                if let Some(payload) = fixture.payload {
+                    factory = factory.payload(payload);
+                }
+                if let Some(max_attempts) = fixture.max_attempts {
+                    factory = factory.max_attempts(max_attempts);
+                }
+                if let Some(run_at) = fixture.run_at {
+                    factory = factory.run_at(run_at);
+                }
+                if let Some(dedupe_key) = fixture.dedupe_key {
+                    factory = factory.dedupe_key(dedupe_key);
+                }
+                if factory.create(provider).await?.is_some() {
+                    report.jobs += 1;
+                }
+            }
+
+            Ok(report)
+        })
+    }
+}
@@ -0,0 +1,247 @@
+use chrono::{DateTime, Utc};
+use sea_orm::ConnectionTrait;
+use serde_json::Value;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        error::DomainError,
+        job::NewJob,
+        organization::{Company, CompanyId, CompanyName, Department, DepartmentId, DepartmentName},
+        webhook::NewWebhook,
+    },
+    infrastructure::{db::RepositoryProvider, jobs::DEFAULT_QUEUE},
+};
+
+/// A short random suffix keeping the default names apart, as most of them are unique.
+fn unique() -> String {
+    Uuid::new_v4().simple().to_string()[..8].to_string()
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct CompanyFactory {
+    id: Option<CompanyId>,
+    name: Option<String>,
+    deleted_at: Option<DateTime<Utc>>,
+}
+
+impl CompanyFactory {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn id(mut self, id: CompanyId) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Soft deleted just now.
+    pub fn deleted(mut self) -> Self {
+        self.deleted_at = Some(Utc::now());
+        self
+    }
+
+    pub fn build(self) -> Result<Company, DomainError> {
+        let name = self.name.unwrap_or_else(|| format!("Company {}", unique()));
+        let mut company = Company::new(CompanyName::new(name)?);
+        if let Some(id) = self.id {
+            company.id = id;
+        }
+        company.deleted_at = self.deleted_at;
+
+        Ok(company)
+    }
+
+    pub async fn create<C: ConnectionTrait>(
+        self,
+        provider: &RepositoryProvider<'_, C>,
+    ) -> Result<Company, DomainError> {
+        let company = self.build()?;
+        provider.company_repo().add(company.clone()).await?;
+
+        Ok(company)
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct DepartmentFactory {
+    id: Option<DepartmentId>,
+    name: Option<String>,
+    company_id: Option<CompanyId>,
+    deleted_at: Option<DateTime<Utc>>,
+}
+
+impl DepartmentFactory {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn id(mut self, id: DepartmentId) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    pub fn company(mut self, company_id: CompanyId) -> Self {
+        self.company_id = Some(company_id);
+        self
+    }
+
+    /// Soft deleted just now.
+    pub fn deleted(mut self) -> Self {
+        self.deleted_at = Some(Utc::now());
+        self
+    }
+
+    /// Without a company, the department belongs to a random id nothing refers to.
+    pub fn build(self) -> Result<Department, DomainError> {
+        let name = self
+            .name
+            .unwrap_or_else(|| format!("Department {}", unique()));
+        let company_id = self.company_id.unwrap_or_default();
+        let mut department = Department::new(DepartmentName::new(name)?, company_id);
+        if let Some(id) = self.id {
+            department.id = id;
+        }
+        department.deleted_at = self.deleted_at;
+
+        Ok(department)
+    }
+
+    /// Without a company, one is created first.
+    pub async fn create<C: ConnectionTrait>(
+        mut self,
+        provider: &RepositoryProvider<'_, C>,
+    ) -> Result<Department, DomainError> {
+        if self.company_id.is_none() {
+            self.company_id = Some(CompanyFactory::new().create(provider).await?.id);
+        }
+        let department = self.build()?;
+        provider.department_repo().add(department.clone()).await?;
+
+        Ok(department)
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct WebhookFactory {
+    url: Option<String>,
+    events: Option<Vec<String>>,
+    secret: Option<String>,
+}
+
+impl WebhookFactory {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn url(mut self, url: impl Into<String>) -> Self {
+        self.url = Some(url.into());
+        self
+    }
+
+    pub fn events<I: IntoIterator<Item = impl Into<String>>>(mut self, events: I) -> Self {
+        self.events = Some(events.into_iter().map(Into::into).collect());
+        self
+    }
+
+    pub fn secret(mut self, secret: impl Into<String>) -> Self {
+        self.secret = Some(secret.into());
+        self
+    }
+
+    /// Subscribed to every event by default.
+    pub fn build(self) -> NewWebhook {
+        NewWebhook {
+            url: self
+                .url
+                .unwrap_or_else(|| format!("https://example.com/hooks/{}", unique())),
+            events: self.events.unwrap_or_else(|| vec!["*".to_string()]),
+            secret: self
+                .secret
+                .unwrap_or_else(|| Uuid::new_v4().simple().to_string()),
+        }
+    }
+
+    pub async fn create<C: ConnectionTrait>(
+        self,
+        provider: &RepositoryProvider<'_, C>,
+    ) -> Result<Uuid, DomainError> {
+        provider.webhook_repo().add(self.build()).await
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct JobFactory {
+    name: Option<String>,
+    queue: Option<String>,
+    payload: Option<Value>,
+    max_attempts: Option<i32>,
+    run_at: Option<DateTime<Utc>>,
+    dedupe_key: Option<String>,
+}
+
+impl JobFactory {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    pub fn queue(mut self, queue: impl Into<String>) -> Self {
+        self.queue = Some(queue.into());
+        self
+    }
+
+    pub fn payload(mut self, payload: Value) -> Self {
+        self.payload = Some(payload);
+        self
+    }
+
+    pub fn max_attempts(mut self, max_attempts: i32) -> Self {
+        self.max_attempts = Some(max_attempts);
+        self
+    }
+
+    pub fn run_at(mut self, run_at: DateTime<Utc>) -> Self {
+        self.run_at = Some(run_at);
+        self
+    }
+
+    pub fn dedupe_key(mut self, dedupe_key: impl Into<String>) -> Self {
+        self.dedupe_key = Some(dedupe_key.into());
+        self
+    }
+
+    /// Due right away on the default queue.
+    pub fn build(self) -> NewJob {
+        NewJob {
+            name: self.name.unwrap_or_else(|| format!("job-{}", unique())),
+            queue: self.queue.unwrap_or_else(|| DEFAULT_QUEUE.to_string()),
+            payload: self.payload.unwrap_or(Value::Null),
+            max_attempts: self.max_attempts.unwrap_or(3),
+            run_at: self.run_at.unwrap_or_else(Utc::now),
+            dedupe_key: self.dedupe_key,
+        }
+    }
+
+    /// `None` when a job with the same dedupe key is already enqueued.
+    pub async fn create<C: ConnectionTrait>(
+        self,
+        provider: &RepositoryProvider<'_, C>,
+    ) -> Result<Option<Uuid>, DomainError> {
+        provider.job_repo().add(self.build()).await
+    }
+}
@@ -0,0 +1,5 @@
+mod dataset;
+pub use dataset::*;
+
+mod factories;
+pub use factories::*;
@@ -1,5 +1,10 @@
+#[cfg(not(any(feature = "postgres", feature = "mysql", feature = "sqlite")))]
+compile_error!("one of the postgres, mysql or sqlite features must be enabled");
+
 pub mod application;
 pub mod config;
 pub mod domain;
 pub mod infrastructure;
 pub mod presentation;
+#[cfg(feature = "test-util")]
+pub mod test_util;
@@ -0,0 +1,44 @@
+use async_graphql::{Error, ErrorExtensions};
+use axum::http::StatusCode;
+
+use crate::{
+    application::error::{AppError, domain_error_parts},
+    domain::error::DomainError,
+};
+
+/// Reports an error of a use case with the code and status the REST API would use.
+pub fn case_error(e: AppError) -> Error {
+    if let AppError::Domain(d) = e {
+        return domain_error(d);
+    }
+
+    let (status, code) = match &e {
+        AppError::UnAuthorized(_) => (StatusCode::UNAUTHORIZED, "UNAUTHORIZED"),
+        AppError::Forbidden(_) => (StatusCode::FORBIDDEN, "FORBIDDEN"),
+        AppError::PreconditionRequired(_) => {
+            (StatusCode::PRECONDITION_REQUIRED, "PRECONDITION_REQUIRED")
+        }
+        AppError::TooManyRequests(_) => (StatusCode::TOO_MANY_REQUESTS, "TOO_MANY_REQUESTS"),
+        AppError::ValidationError(_) => (StatusCode::BAD_REQUEST, "INPUT_VALIDATE_FAIL"),
+        AppError::InternalError(_) => {
+            tracing::error!("{}", e);
+            (StatusCode::INTERNAL_SERVER_ERROR, "UNKNOWN_INTERNAL_ERROR")
+        }
+        _ => (StatusCode::BAD_REQUEST, "INPUT_PARSE_FAIL"),
+    };
+
+    extended(e.to_string(), status, code)
+}
+
+pub fn domain_error(e: DomainError) -> Error {
+    tracing::error!("{}", e);
+    let (status, code, message) = domain_error_parts(&e);
+    extended(message, status, &code)
+}
+
+fn extended(message: String, status: StatusCode, code: &str) -> Error {
+    Error::new(message).extend_with(|_, extensions| {
+        extensions.set("code", code);
+        extensions.set("status", status.as_u16());
+    })
+}
@@ -0,0 +1,77 @@
+use std::collections::HashMap;
+
+use async_graphql::{Error, dataloader::Loader};
+use uuid::Uuid;
+
+use crate::{
+    application::dtos::{company::ResGetCompanyDto, department::ResGetDepartmentDto},
+    domain::organization::CompanyId,
+    infrastructure::db::TenantScope,
+    presentation::http::AppState,
+};
+
+use super::{
+    error::domain_error,
+    types::{Company, Department},
+};
+
+/// Companies by id, for the `company` of departments.
+pub struct CompanyLoader {
+    pub state: AppState,
+    pub tenant: TenantScope,
+}
+
+impl Loader<Uuid> for CompanyLoader {
+    type Value = Company;
+    type Error = Error;
+
+    async fn load(&self, keys: &[Uuid]) -> Result<HashMap<Uuid, Company>, Error> {
+        let ids: Vec<CompanyId> = keys.iter().copied().map(CompanyId::from).collect();
+        let provider = self.state.db_context.provider(self.tenant);
+        let companies = provider
+            .company_repo()
+            .find_many(&ids)
+            .await
+            .map_err(domain_error)?;
+
+        Ok(companies
+            .into_iter()
+            .map(|c| Company::from(ResGetCompanyDto::from(c)))
+            .map(|c| (c.id, c))
+            .collect())
+    }
+}
+
+/// Departments by the id of their company, for the `departments` of companies.
+pub struct DepartmentsByCompanyLoader {
+    pub state: AppState,
+    pub tenant: TenantScope,
+}
+
+impl Loader<Uuid> for DepartmentsByCompanyLoader {
+    type Value = Vec<Department>;
+    type Error = Error;
+
+    async fn load(&self, keys: &[Uuid]) -> Result<HashMap<Uuid, Vec<Department>>, Error> {
+        let ids: Vec<CompanyId> = keys.iter().copied().map(CompanyId::from).collect();
+        let provider = self.state.db_context.provider(self.tenant);
+        let departments = provider
+            .department_repo()
+            .find_by_companies(&ids)
+            .await
+            .map_err(domain_error)?;
+
+        let mut by_company: HashMap<Uuid, Vec<Department>> = HashMap::new();
+        for department in departments
+            .into_iter()
+            .map(|d| Department::from(ResGetDepartmentDto::from(d)))
+        {
+            by_company
+                .entry(department.company_id)
+                .or_default()
+                .push(department);
+        }
+
+        Ok(by_company)
+    }
+}
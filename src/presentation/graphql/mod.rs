@@ -0,0 +1,53 @@
+mod error;
+mod loaders;
+mod query;
+mod types;
+
+use async_graphql::{
+    EmptyMutation, EmptySubscription, Request, Response, Schema, dataloader::DataLoader,
+};
+use axum::{Extension, Json, extract::State};
+
+use crate::presentation::{guards::UserInfo, http::AppState};
+
+pub use loaders::*;
+pub use query::*;
+pub use types::*;
+
+pub type AppSchema = Schema<QueryRoot, EmptyMutation, EmptySubscription>;
+
+pub fn build_schema(max_depth: usize, max_complexity: usize) -> AppSchema {
+    Schema::build(QueryRoot, EmptyMutation, EmptySubscription)
+        .limit_depth(max_depth)
+        .limit_complexity(max_complexity)
+        .finish()
+}
+
+/// Behind `guards::auth`, the loaders being made for each request so that they only
+/// ever see the tenant of its user.
+pub async fn graphql_handler(
+    State(state): State<AppState>,
+    Extension(schema): Extension<AppSchema>,
+    Extension(user): Extension<UserInfo>,
+    Json(req): Json<Request>,
+) -> Json<Response> {
+    let tenant = user.tenant();
+    let companies = DataLoader::new(
+        CompanyLoader {
+            state: state.clone(),
+            tenant,
+        },
+        tokio::spawn,
+    );
+    let departments = DataLoader::new(
+        DepartmentsByCompanyLoader {
+            state: state.clone(),
+            tenant,
+        },
+        tokio::spawn,
+    );
+
+    let req = req.data(state).data(user).data(companies).data(departments);
+
+    Json(schema.execute(req).await)
+}
@@ -0,0 +1,95 @@
+use std::sync::Arc;
+
+use async_graphql::{Context, Guard, Object, Result};
+use uuid::Uuid;
+
+use crate::{
+    application::{
+        SecureCase,
+        cases::{
+            company::{GetCompanyUseCase, QueryCompanyUseCase},
+            department::GetDepartmentUseCase,
+        },
+        dtos::{
+            company::{ReqCompanyIdDto, ReqQueryCompanyDto},
+            department::ReqDepartmentIdDto,
+        },
+        error::AppError,
+    },
+    presentation::{
+        guards::UserInfo,
+        http::AppState,
+        middlewares::validator::{PathParams, QueryParams},
+    },
+};
+
+use super::{
+    error::case_error,
+    types::{Company, Department},
+};
+
+const ADMIN: &[&str] = &["admin"];
+
+/// Mirrors `guards::roles` on the REST route of the same use case.
+struct RoleGuard(&'static [&'static str]);
+
+impl Guard for RoleGuard {
+    async fn check(&self, ctx: &Context<'_>) -> Result<()> {
+        let roles: Vec<String> = self.0.iter().map(|r| r.to_string()).collect();
+
+        match ctx.data_opt::<UserInfo>() {
+            Some(user) if user.has_any_role(&roles) => Ok(()),
+            _ => Err(case_error(AppError::Forbidden(
+                "miss.permission".to_string(),
+            ))),
+        }
+    }
+}
+
+pub struct QueryRoot;
+
+#[Object]
+impl QueryRoot {
+    #[graphql(guard = "RoleGuard(ADMIN)")]
+    async fn companies(&self, ctx: &Context<'_>, name: Option<String>) -> Result<Vec<Company>> {
+        let dto = ReqQueryCompanyDto {
+            name,
+            ..Default::default()
+        };
+        let companies = QueryCompanyUseCase::new(state(ctx)?)
+            .execute(QueryParams(dto), user(ctx)?)
+            .await
+            .map_err(case_error)?
+            .data;
+
+        Ok(companies.into_iter().map(Company::from).collect())
+    }
+
+    async fn company(&self, ctx: &Context<'_>, id: Uuid) -> Result<Company> {
+        let company = GetCompanyUseCase::new(state(ctx)?)
+            .execute(PathParams(ReqCompanyIdDto { id }), user(ctx)?)
+            .await
+            .map_err(case_error)?
+            .data;
+
+        Ok(company.into())
+    }
+
+    async fn department(&self, ctx: &Context<'_>, id: Uuid) -> Result<Department> {
+        let department = GetDepartmentUseCase::new(state(ctx)?)
+            .execute(PathParams(ReqDepartmentIdDto { id }), user(ctx)?)
+            .await
+            .map_err(case_error)?
+            .data;
+
+        Ok(department.into())
+    }
+}
+
+fn state(ctx: &Context<'_>) -> Result<Arc<AppState>> {
+    Ok(Arc::new(ctx.data::<AppState>()?.clone()))
+}
+
+fn user(ctx: &Context<'_>) -> Result<UserInfo> {
+    Ok(ctx.data::<UserInfo>()?.clone())
+}
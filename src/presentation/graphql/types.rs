@@ -0,0 +1,84 @@
+use async_graphql::{ComplexObject, Context, Result, SimpleObject, dataloader::DataLoader};
+use uuid::Uuid;
+
+use crate::application::dtos::{
+    company::{ResGetCompanyDto, ResQueryCompanyDto},
+    department::ResGetDepartmentDto,
+};
+
+use super::loaders::{CompanyLoader, DepartmentsByCompanyLoader};
+
+#[derive(Debug, Clone, SimpleObject)]
+#[graphql(complex)]
+pub struct Company {
+    pub id: Uuid,
+    pub name: String,
+    pub version: i32,
+}
+
+#[ComplexObject]
+impl Company {
+    /// Active departments, loaded together for every company of the query.
+    async fn departments(&self, ctx: &Context<'_>) -> Result<Vec<Department>> {
+        let departments = ctx
+            .data_unchecked::<DataLoader<DepartmentsByCompanyLoader>>()
+            .load_one(self.id)
+            .await?;
+
+        Ok(departments.unwrap_or_default())
+    }
+}
+
+#[derive(Debug, Clone, SimpleObject)]
+#[graphql(complex)]
+pub struct Department {
+    pub id: Uuid,
+    pub name: String,
+    pub company_id: Uuid,
+    pub version: i32,
+}
+
+#[ComplexObject]
+impl Department {
+    async fn company(&self, ctx: &Context<'_>) -> Result<Option<Company>> {
+        ctx.data_unchecked::<DataLoader<CompanyLoader>>()
+            .load_one(self.company_id)
+            .await
+    }
+}
+
+// the DTOs carry ids already formatted from a Uuid
+fn uuid(id: &str) -> Uuid {
+    Uuid::parse_str(id).unwrap_or_default()
+}
+
+impl From<ResQueryCompanyDto> for Company {
+    fn from(c: ResQueryCompanyDto) -> Self {
+        Self {
+            id: uuid(&c.id),
+            name: c.name,
+            version: c.version,
+        }
+    }
+}
+
+impl From<ResGetCompanyDto> for Company {
+    fn from(c: ResGetCompanyDto) -> Self {
+        Self {
+            id: uuid(&c.id),
+            name: c.name,
+            version: c.version,
+        }
+    }
+}
+
+impl From<ResGetDepartmentDto> for Department {
+    fn from(d: ResGetDepartmentDto) -> Self {
+        Self {
+            id: uuid(&d.id),
+            name: d.name,
+            company_id: uuid(&d.company_id),
+            version: d.version,
+        }
+    }
+}
@@ -0,0 +1,52 @@
+use std::sync::Arc;
+
+use tonic::{Request, Status, service::Interceptor};
+
+use crate::{
+    application::error::AppError,
+    infrastructure::helpers::token::JwtHelper,
+    presentation::guards::{self, UserInfo},
+};
+
+use super::error::case_status;
+
+/// `guards::auth` for the gRPC services, the token and tenant being read from the
+/// `authorization` and `x-tenant-id` metadata.
+#[derive(Clone)]
+pub struct AuthInterceptor {
+    jwt_helper: Arc<JwtHelper>,
+}
+
+impl AuthInterceptor {
+    pub fn new(jwt_helper: Arc<JwtHelper>) -> Self {
+        Self { jwt_helper }
+    }
+}
+
+impl Interceptor for AuthInterceptor {
+    fn call(&mut self, mut req: Request<()>) -> Result<Request<()>, Status> {
+        let user =
+            guards::authenticate(&self.jwt_helper, req.metadata().as_ref()).map_err(case_status)?;
+        req.extensions_mut().insert(user);
+        Ok(req)
+    }
+}
+
+/// The user `AuthInterceptor` let through, who must have any of `roles` like with
+/// `guards::roles`. No one is let through without `roles`, the use case having no route
+/// to take them from.
+pub fn user_of<T>(req: &Request<T>, roles: Option<&[String]>) -> Result<UserInfo, Status> {
+    let user = req.extensions().get::<UserInfo>().cloned().ok_or_else(|| {
+        case_status(AppError::UnAuthorized(
+            "token invalid or expired.".to_string(),
+        ))
+    })?;
+
+    if !roles.is_some_and(|roles| user.has_any_role(roles)) {
+        return Err(case_status(AppError::Forbidden(
+            "miss.permission".to_string(),
+        )));
+    }
+
+    Ok(user)
+}
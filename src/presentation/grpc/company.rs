@@ -0,0 +1,180 @@
+use std::sync::Arc;
+
+use tonic::{Request, Response, Status};
+
+use crate::{
+    application::{
+        cases::company::{
+            AddCompanyUseCase, DeleteCompanyUseCase, GetCompanyUseCase, QueryCompanyUseCase,
+            UpdateCompanyUseCase,
+        },
+        dtos::company::{
+            ReqAddCompanyDto, ReqCompanyIdDto, ReqQueryCompanyDto, ReqUpdateCompanyDto,
+            ResGetCompanyDto, ResQueryCompanyDto,
+        },
+    },
+    presentation::{
+        http::{AppState, routes::CaseRoles},
+        middlewares::{
+            conditional::{IfMatch, IfMatchParams},
+            validator::{JsonParams, PathAndJsonParams, PathParams, QueryParams},
+        },
+    },
+};
+
+use super::{
+    audit_context,
+    auth::user_of,
+    execute, parse_id,
+    proto::{
+        Company, CompanyList, CreateCompanyRequest, CreatedResponse, DeleteRequest, Empty,
+        IdRequest, ListCompaniesRequest, UpdateCompanyRequest,
+        company_service_server::CompanyService,
+    },
+    respond, validated, written,
+};
+
+pub struct CompanyGrpcService {
+    state: Arc<AppState>,
+    roles: Arc<CaseRoles>,
+}
+
+impl CompanyGrpcService {
+    pub fn new(state: Arc<AppState>, roles: Arc<CaseRoles>) -> Self {
+        Self { state, roles }
+    }
+}
+
+#[tonic::async_trait]
+impl CompanyService for CompanyGrpcService {
+    async fn list_companies(
+        &self,
+        req: Request<ListCompaniesRequest>,
+    ) -> Result<Response<CompanyList>, Status> {
+        let user = user_of(&req, self.roles.of::<QueryCompanyUseCase>())?;
+        let audit = audit_context(&req, &user);
+        let dto = validated(ReqQueryCompanyDto {
+            name: req.into_inner().name,
+            ..Default::default()
+        })?;
+
+        let res = execute(
+            QueryCompanyUseCase::new(self.state.clone()),
+            QueryParams(dto),
+            user,
+            audit,
+        )
+        .await?;
+
+        Ok(respond(res, |companies| CompanyList {
+            companies: companies.into_iter().map(Company::from).collect(),
+        }))
+    }
+
+    async fn get_company(&self, req: Request<IdRequest>) -> Result<Response<Company>, Status> {
+        let user = user_of(&req, self.roles.of::<GetCompanyUseCase>())?;
+        let audit = audit_context(&req, &user);
+        let id = parse_id(&req.into_inner().id)?;
+
+        let res = execute(
+            GetCompanyUseCase::new(self.state.clone()),
+            PathParams(ReqCompanyIdDto { id }),
+            user,
+            audit,
+        )
+        .await?;
+
+        Ok(respond(res, Company::from))
+    }
+
+    async fn create_company(
+        &self,
+        req: Request<CreateCompanyRequest>,
+    ) -> Result<Response<CreatedResponse>, Status> {
+        let user = user_of(&req, self.roles.of::<AddCompanyUseCase>())?;
+        let audit = audit_context(&req, &user);
+        let dto = validated(ReqAddCompanyDto {
+            name: req.into_inner().name,
+        })?;
+
+        let res = execute(
+            AddCompanyUseCase::new(self.state.clone()),
+            JsonParams(dto),
+            user,
+            audit,
+        )
+        .await?;
+        Ok(written(
+            &self.state,
+            respond(res, |id| CreatedResponse { id }),
+        ))
+    }
+
+    async fn update_company(
+        &self,
+        req: Request<UpdateCompanyRequest>,
+    ) -> Result<Response<Empty>, Status> {
+        let user = user_of(&req, self.roles.of::<UpdateCompanyUseCase>())?;
+        let audit = audit_context(&req, &user);
+        let req = req.into_inner();
+        let params = PathAndJsonParams {
+            p: ReqCompanyIdDto {
+                id: parse_id(&req.id)?,
+            },
+            b: validated(ReqUpdateCompanyDto { name: req.name })?,
+        };
+
+        let res = execute(
+            UpdateCompanyUseCase::new(self.state.clone()),
+            IfMatchParams {
+                if_match: IfMatch::version(req.version),
+                params,
+            },
+            user,
+            audit,
+        )
+        .await?;
+        Ok(written(&self.state, respond(res, |()| Empty {})))
+    }
+
+    async fn delete_company(&self, req: Request<DeleteRequest>) -> Result<Response<Empty>, Status> {
+        let user = user_of(&req, self.roles.of::<DeleteCompanyUseCase>())?;
+        let audit = audit_context(&req, &user);
+        let req = req.into_inner();
+        let params = PathParams(ReqCompanyIdDto {
+            id: parse_id(&req.id)?,
+        });
+
+        let res = execute(
+            DeleteCompanyUseCase::new(self.state.clone()),
+            IfMatchParams {
+                if_match: IfMatch::version(req.version),
+                params,
+            },
+            user,
+            audit,
+        )
+        .await?;
+        Ok(written(&self.state, respond(res, |()| Empty {})))
+    }
+}
+
+impl From<ResGetCompanyDto> for Company {
+    fn from(c: ResGetCompanyDto) -> Self {
+        Self {
+            id: c.id,
+            name: c.name,
+            version: c.version,
+        }
+    }
+}
+
+impl From<ResQueryCompanyDto> for Company {
+    fn from(c: ResQueryCompanyDto) -> Self {
+        Self {
+            id: c.id,
+            name: c.name,
+            version: c.version,
+        }
+    }
+}
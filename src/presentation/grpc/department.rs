@@ -0,0 +1,156 @@
+use std::sync::Arc;
+
+use tonic::{Request, Response, Status};
+
+use crate::{
+    application::{
+        cases::department::{
+            AddDepartmentUseCase, DeleteDepartmentUseCase, GetDepartmentUseCase,
+            UpdateDepartmentUseCase,
+        },
+        dtos::department::{
+            ReqAddDepartmentDto, ReqDepartmentIdDto, ReqUpdateDepartmentDto, ResGetDepartmentDto,
+        },
+    },
+    presentation::{
+        http::{AppState, routes::CaseRoles},
+        middlewares::{
+            conditional::{IfMatch, IfMatchParams},
+            validator::{JsonParams, PathAndJsonParams, PathParams},
+        },
+    },
+};
+
+use super::{
+    audit_context,
+    auth::user_of,
+    execute, parse_id,
+    proto::{
+        CreateDepartmentRequest, CreatedResponse, DeleteRequest, Department, Empty, IdRequest,
+        UpdateDepartmentRequest, department_service_server::DepartmentService,
+    },
+    respond, validated, written,
+};
+
+pub struct DepartmentGrpcService {
+    state: Arc<AppState>,
+    roles: Arc<CaseRoles>,
+}
+
+impl DepartmentGrpcService {
+    pub fn new(state: Arc<AppState>, roles: Arc<CaseRoles>) -> Self {
+        Self { state, roles }
+    }
+}
+
+#[tonic::async_trait]
+impl DepartmentService for DepartmentGrpcService {
+    async fn get_department(
+        &self,
+        req: Request<IdRequest>,
+    ) -> Result<Response<Department>, Status> {
+        let user = user_of(&req, self.roles.of::<GetDepartmentUseCase>())?;
+        let audit = audit_context(&req, &user);
+        let id = parse_id(&req.into_inner().id)?;
+
+        let res = execute(
+            GetDepartmentUseCase::new(self.state.clone()),
+            PathParams(ReqDepartmentIdDto { id }),
+            user,
+            audit,
+        )
+        .await?;
+
+        Ok(respond(res, Department::from))
+    }
+
+    async fn create_department(
+        &self,
+        req: Request<CreateDepartmentRequest>,
+    ) -> Result<Response<CreatedResponse>, Status> {
+        let user = user_of(&req, self.roles.of::<AddDepartmentUseCase>())?;
+        let audit = audit_context(&req, &user);
+        let req = req.into_inner();
+        let dto = validated(ReqAddDepartmentDto {
+            name: req.name,
+            company_id: parse_id(&req.company_id)?,
+        })?;
+
+        let res = execute(
+            AddDepartmentUseCase::new(self.state.clone()),
+            JsonParams(dto),
+            user,
+            audit,
+        )
+        .await?;
+        Ok(written(
+            &self.state,
+            respond(res, |id| CreatedResponse { id }),
+        ))
+    }
+
+    async fn update_department(
+        &self,
+        req: Request<UpdateDepartmentRequest>,
+    ) -> Result<Response<Empty>, Status> {
+        let user = user_of(&req, self.roles.of::<UpdateDepartmentUseCase>())?;
+        let audit = audit_context(&req, &user);
+        let req = req.into_inner();
+        let params = PathAndJsonParams {
+            p: ReqDepartmentIdDto {
+                id: parse_id(&req.id)?,
+            },
+            b: validated(ReqUpdateDepartmentDto {
+                name: req.name,
+                company_id: parse_id(&req.company_id)?,
+            })?,
+        };
+
+        let res = execute(
+            UpdateDepartmentUseCase::new(self.state.clone()),
+            IfMatchParams {
+                if_match: IfMatch::version(req.version),
+                params,
+            },
+            user,
+            audit,
+        )
+        .await?;
+        Ok(written(&self.state, respond(res, |()| Empty {})))
+    }
+
+    async fn delete_department(
+        &self,
+        req: Request<DeleteRequest>,
+    ) -> Result<Response<Empty>, Status> {
+        let user = user_of(&req, self.roles.of::<DeleteDepartmentUseCase>())?;
+        let audit = audit_context(&req, &user);
+        let req = req.into_inner();
+        let params = PathParams(ReqDepartmentIdDto {
+            id: parse_id(&req.id)?,
+        });
+
+        let res = execute(
+            DeleteDepartmentUseCase::new(self.state.clone()),
+            IfMatchParams {
+                if_match: IfMatch::version(req.version),
+                params,
+            },
+            user,
+            audit,
+        )
+        .await?;
+        Ok(written(&self.state, respond(res, |()| Empty {})))
+    }
+}
+
+impl From<ResGetDepartmentDto> for Department {
+    fn from(d: ResGetDepartmentDto) -> Self {
+        Self {
+            id: d.id,
+            name: d.name,
+            company_id: d.company_id,
+            version: d.version,
+        }
+    }
+}
@@ -0,0 +1,69 @@
+use axum::http::StatusCode;
+use tonic::{Code, Status, metadata::MetadataValue};
+
+use crate::{
+    application::error::{AppError, domain_error_parts},
+    domain::error::DomainError,
+};
+
+pub const ERROR_CODE_METADATA: &str = "x-error-code";
+
+/// Reports an error of a use case with the status matching the one the REST API would
+/// respond with, its code being in the `x-error-code` metadata.
+pub fn case_status(e: AppError) -> Status {
+    if let AppError::Domain(d) = e {
+        return domain_status(d);
+    }
+
+    let (code, error_code) = match &e {
+        AppError::UnAuthorized(_) => (Code::Unauthenticated, "UNAUTHORIZED"),
+        AppError::Forbidden(_) => (Code::PermissionDenied, "FORBIDDEN"),
+        AppError::PreconditionRequired(_) => (Code::FailedPrecondition, "PRECONDITION_REQUIRED"),
+        AppError::TooManyRequests(_) => (Code::ResourceExhausted, "TOO_MANY_REQUESTS"),
+        AppError::ValidationError(_) => (Code::InvalidArgument, "INPUT_VALIDATE_FAIL"),
+        AppError::InternalError(_) => {
+            tracing::error!("{}", e);
+            (Code::Internal, "UNKNOWN_INTERNAL_ERROR")
+        }
+        _ => (Code::InvalidArgument, "INPUT_PARSE_FAIL"),
+    };
+
+    let mut status = with_code(Status::new(code, e.to_string()), error_code);
+    // the Retry-After header the REST API would respond with
+    if let AppError::TooManyRequests(secs) = e {
+        status.metadata_mut().insert("retry-after", secs.into());
+    }
+    status
+}
+
+pub fn domain_status(e: DomainError) -> Status {
+    tracing::error!("{}", e);
+    let (status, error_code, message) = domain_error_parts(&e);
+
+    let code = match status {
+        StatusCode::BAD_REQUEST | StatusCode::UNPROCESSABLE_ENTITY => Code::InvalidArgument,
+        StatusCode::NOT_FOUND => Code::NotFound,
+        StatusCode::CONFLICT if error_code == "DATA_DUPPLICATED" => Code::AlreadyExists,
+        StatusCode::CONFLICT => Code::Aborted,
+        StatusCode::PRECONDITION_FAILED => Code::FailedPrecondition,
+        StatusCode::FORBIDDEN => Code::PermissionDenied,
+        _ => Code::Internal,
+    };
+
+    with_code(Status::new(code, message), &error_code)
+}
+
+/// A malformed id, which the REST API would have rejected while extracting the path.
+pub fn invalid_id(id: &str) -> Status {
+    with_code(
+        Status::invalid_argument(format!("{} is not a valid id", id)),
+        "INPUT_PARSE_FAIL",
+    )
+}
+
+fn with_code(mut status: Status, error_code: &str) -> Status {
+    if let Ok(value) = MetadataValue::try_from(error_code) {
+        status.metadata_mut().insert(ERROR_CODE_METADATA, value);
+    }
+    status
+}
@@ -0,0 +1,138 @@
+mod auth;
+mod company;
+mod department;
+mod error;
+
+use std::{sync::Arc, time::Instant};
+
+use axum::{
+    Router,
+    extract::{Request as HttpRequest, State},
+    middleware::{Next, from_fn_with_state},
+    response::Response as HttpResponse,
+};
+use tonic::{Request, Response, Status, metadata::MetadataMap, service::Routes};
+use tower_http::request_id::RequestId;
+use uuid::Uuid;
+use validator::Validate;
+
+use crate::{
+    application::SecureCase,
+    infrastructure::{
+        db::{AuditContext, LastWrite},
+        helpers::token::JwtHelper,
+    },
+    presentation::{
+        guards::{self, UserInfo},
+        http::{AppState, routes::v1::v1_registry},
+        middlewares::{
+            consistency::LAST_WRITE_HEADER,
+            rate_limit::{RateLimit, RateLimiter},
+        },
+        response::{CaseResponse, IntoCaseBody},
+    },
+};
+
+pub use auth::*;
+pub use company::*;
+pub use department::*;
+pub use error::*;
+
+/// The messages, servers and clients build.rs generates from
+/// proto/organization/v1/organization.proto.
+pub mod proto {
+    tonic::include_proto!("organization.v1");
+}
+
+/// The services under their `/organization.v1.<Service>/<Method>` paths, to be served
+/// over HTTP/2 along with the REST API. Every call counts toward `rate_limit`.
+pub fn grpc_routes(state: AppState, rate_limit: RateLimit) -> Router {
+    let auth = AuthInterceptor::new(state.jwt_helper.clone());
+    let limiter = (RateLimiter::new(rate_limit), state.jwt_helper.clone());
+    let state = Arc::new(state);
+    // the roles of the REST routes of the same use cases
+    let roles = Arc::new(v1_registry().case_roles());
+
+    Routes::new(
+        proto::company_service_server::CompanyServiceServer::with_interceptor(
+            CompanyGrpcService::new(state.clone(), roles.clone()),
+            auth.clone(),
+        ),
+    )
+    .add_service(
+        proto::department_service_server::DepartmentServiceServer::with_interceptor(
+            DepartmentGrpcService::new(state, roles),
+            auth,
+        ),
+    )
+    .into_axum_router()
+    .layer(from_fn_with_state(limiter, limit_calls))
+}
+
+/// `rate_limit::rate_limit` for the gRPC services, the caller being told from the token
+/// as the interceptors have not run yet. It fails the call with `RESOURCE_EXHAUSTED`
+/// rather than a 429, which gRPC clients would not make sense of.
+async fn limit_calls(
+    State((limiter, jwt_helper)): State<(RateLimiter, Arc<JwtHelper>)>,
+    req: HttpRequest,
+    next: Next,
+) -> HttpResponse {
+    let user = guards::authenticate(&jwt_helper, req.headers()).ok();
+
+    match limiter.check(&req, user.as_ref(), Instant::now()) {
+        Ok(()) => next.run(req).await,
+        Err(e) => case_status(e).into_http(),
+    }
+}
+
+/// What `secure_case_handler` runs the use case of a REST call within, the user and the
+/// request being known from `req`.
+fn audit_context<T>(req: &Request<T>, user: &UserInfo) -> AuditContext {
+    let request_id = req
+        .extensions()
+        .get::<RequestId>()
+        .and_then(|id| id.header_value().to_str().ok().map(str::to_string));
+    AuditContext::new(Some(user.id.clone()), request_id)
+}
+
+/// Runs `uc` within `audit`, so that what it writes is stamped and audited as for the
+/// REST API.
+async fn execute<U: SecureCase>(
+    uc: U,
+    input: U::Input,
+    user: UserInfo,
+    audit: AuditContext,
+) -> Result<CaseResponse<U::Output>, Status> {
+    audit
+        .scope(uc.execute(input, user))
+        .await
+        .map_err(case_status)
+}
+
+/// The message along with the headers of the use case, such as `ETag`, as metadata.
+fn respond<T: IntoCaseBody, M>(res: CaseResponse<T>, message: impl FnOnce(T) -> M) -> Response<M> {
+    let mut response = Response::new(message(res.data));
+    *response.metadata_mut() = MetadataMap::from_headers(res.headers);
+    response
+}
+
+/// `consistency::track_writes` for the gRPC services, the client being told when it
+/// wrote in the `x-last-write` metadata.
+fn written<M>(state: &AppState, mut response: Response<M>) -> Response<M> {
+    if state.db_context.read_your_writes().is_some() {
+        response
+            .metadata_mut()
+            .insert(LAST_WRITE_HEADER, LastWrite::now().to_millis().into());
+    }
+    response
+}
+
+fn parse_id(id: &str) -> Result<Uuid, Status> {
+    Uuid::parse_str(id).map_err(|_| invalid_id(id))
+}
+
+/// What `JsonParams` and friends check while extracting the REST input.
+fn validated<T: Validate>(dto: T) -> Result<T, Status> {
+    dto.validate().map_err(|e| case_status(e.into()))?;
+    Ok(dto)
+}
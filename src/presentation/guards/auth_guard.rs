@@ -1,16 +1,45 @@
 use axum::{
     extract::{Request, State},
-    http,
+    http::{self, HeaderMap},
     middleware::Next,
     response::Response,
 };
 use serde::{Deserialize, Serialize};
+use uuid::Uuid;
 
-use crate::{application::error::AppError, presentation::http::AppState};
+use crate::{
+    application::error::AppError,
+    infrastructure::{
+        db::TenantScope,
+        helpers::token::{JwtHelper, TokenClaims},
+    },
+    presentation::http::AppState,
+};
+
+pub const TENANT_HEADER: &str = "x-tenant-id";
+pub const SERVICE_ROLE: &str = "service";
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct UserInfo {
     pub id: String,
+    #[serde(default)]
+    pub roles: Vec<String>,
+    pub tenant_id: Uuid,
+}
+
+impl UserInfo {
+    pub fn has_any_role(&self, roles: &[String]) -> bool {
+        roles.is_empty() || roles.iter().any(|r| self.roles.contains(r))
+    }
+
+    pub fn tenant(&self) -> TenantScope {
+        TenantScope::Tenant(self.tenant_id)
+    }
+
+    /// Tells users of different tenants apart, unlike `id`.
+    pub fn caller(&self) -> String {
+        format!("{}:{}", self.tenant_id, self.id)
+    }
 }
 
 #[derive(Debug)]
@@ -24,20 +53,48 @@ pub async fn auth(
     mut req: Request,
     next: Next,
 ) -> Result<Response, AppError> {
-    let status = req
-        .headers()
+    let user = authenticate(&state.jwt_helper, req.headers())?;
+    req.extensions_mut().insert(user);
+    Ok(next.run(req).await)
+}
+
+/// The user of the bearer token in `headers`, shared with the gRPC services which read
+/// it from the metadata.
+pub fn authenticate(jwt_helper: &JwtHelper, headers: &HeaderMap) -> Result<UserInfo, AppError> {
+    let claims = headers
         .get(http::header::AUTHORIZATION)
         .and_then(|h| h.to_str().ok())
         .and_then(|v| v.strip_prefix("Bearer "))
-        .and_then(|token| state.jwt_helper.validate(token).ok())
-        .map(|v| AuthStatus::Authenticated(super::UserInfo { id: v }))
-        .unwrap_or(AuthStatus::Anonymous);
+        .and_then(|token| jwt_helper.decode(token).ok());
+
+    let status = match claims {
+        Some(claims) => AuthStatus::Authenticated(UserInfo {
+            tenant_id: tenant_of(&claims, headers)?,
+            id: claims.sub,
+            roles: claims.roles,
+        }),
+        None => AuthStatus::Anonymous,
+    };
 
     match status {
-        AuthStatus::Anonymous => Err(AppError::UnAuthorized("token invalid or expired.".to_string())),
-        AuthStatus::Authenticated(user) => {
-            req.extensions_mut().insert(user);
-            Ok(next.run(req).await)
-        }
+        AuthStatus::Anonymous => Err(AppError::UnAuthorized(
+            "token invalid or expired.".to_string(),
+        )),
+        AuthStatus::Authenticated(user) => Ok(user),
+    }
+}
+
+/// Users belong to the tenant in their token, services act for the one in the
+/// `X-Tenant-Id` header.
+fn tenant_of(claims: &TokenClaims, headers: &HeaderMap) -> Result<Uuid, AppError> {
+    if let Some(tenant_id) = claims.tenant_id {
+        return Ok(tenant_id);
     }
+
+    headers
+        .get(TENANT_HEADER)
+        .filter(|_| claims.roles.iter().any(|r| r == SERVICE_ROLE))
+        .and_then(|h| h.to_str().ok())
+        .and_then(|v| Uuid::parse_str(v).ok())
+        .ok_or_else(|| AppError::Forbidden("miss.tenant".to_string()))
 }
@@ -13,9 +13,8 @@ pub async fn roles(
 ) -> Result<Response, AppError> {
     let user = req.extensions().get::<UserInfo>();
     tracing::debug!("user: {:?}, required_roles: {:?}", user, required_roles);
-    //TODO: implement roles check here
     match user {
-        None => Err(AppError::Forbidden("miss.permission".to_string())),
-        Some(_) => Ok(next.run(req).await),
+        Some(user) if user.has_any_role(&required_roles) => Ok(next.run(req).await),
+        _ => Err(AppError::Forbidden("miss.permission".to_string())),
     }
 }
@@ -1,36 +1,45 @@
 use std::pin::Pin;
 
 use axum::{
-    Extension, Json,
+    Extension,
     extract::State,
     response::{IntoResponse, Response},
 };
-use serde::Serialize;
+
+use tower_http::request_id::RequestId;
 
 use crate::{
     application::{PublicCase, SecureCase, error::AppError},
-    presentation::{guards::UserInfo, http::AppState},
+    infrastructure::db::AuditContext,
+    presentation::{guards::UserInfo, http::AppState, response::IntoCaseBody},
 };
 
 type HandlerFuture = Pin<Box<dyn Future<Output = Result<Response, AppError>> + Send>>;
 
 pub fn secure_case_handler<U, I, O, F>(
     make_uc: F,
-) -> impl Fn(State<AppState>, Extension<UserInfo>, I) -> HandlerFuture + Clone + Send + Sync + 'static
+) -> impl Fn(State<AppState>, Extension<UserInfo>, Option<Extension<RequestId>>, I) -> HandlerFuture
++ Clone
++ Send
++ Sync
++ 'static
 where
     U: SecureCase<Input = I, Output = O> + Send + Sync + 'static,
     I: Send + 'static,
-    O: Serialize + Send + 'static,
+    O: IntoCaseBody + Send + 'static,
     F: Fn(&AppState) -> U + Send + Sync + Clone + 'static,
 {
-    move |State(state), Extension(user), input| {
+    move |State(state), Extension(user), request_id, input| {
         let uc = make_uc(&state);
 
-        Box::pin(async move {
+        let request_id = request_id
+            .and_then(|Extension(id)| id.header_value().to_str().ok().map(str::to_string));
+        let ctx = AuditContext::new(Some(user.id.clone()), request_id);
+
+        Box::pin(ctx.scope(async move {
             let result = uc.execute(input, user).await?;
-            let response: Response = (result.status, Json(result.data)).into_response();
-            Ok(response)
-        })
+            Ok(result.into_response())
+        }))
     }
 }
 
@@ -40,7 +49,7 @@ pub fn public_case_handler<U, I, O, F>(
 where
     U: PublicCase<Input = I, Output = O> + Send + Sync + 'static,
     I: Send + 'static,
-    O: Serialize + Send + 'static,
+    O: IntoCaseBody + Send + 'static,
     F: Fn(&AppState) -> U + Send + Sync + Clone + 'static,
 {
     move |State(state), input| {
@@ -48,8 +57,7 @@ where
 
         Box::pin(async move {
             let result = uc.execute(input).await?;
-            let response: Response = (result.status, Json(result.data)).into_response();
-            Ok(response)
+            Ok(result.into_response())
         })
     }
 }
@@ -1,4 +1,5 @@
-mod routes;
+pub mod openapi;
+pub mod routes;
 mod server;
 mod state;
 
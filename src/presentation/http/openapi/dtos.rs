@@ -0,0 +1,67 @@
+//! The v1 DTOs that are documented, their schemas being derived along with them.
+
+use crate::application::dtos::{
+    audit::{ReqQueryAuditLogDto, ResAuditLogDto},
+    company::{
+        ReqAddCompanyDto, ReqBatchCompanyDto, ReqCompanyIdDto, ReqQueryCompanyDto,
+        ReqUpdateCompanyDto, ResBatchCompanyDto, ResDeletedCompanyDto, ResGetCompanyDto,
+        ResQueryCompanyDto,
+    },
+    department::{
+        ReqAddDepartmentDto, ReqDepartmentIdDto, ReqUpdateDepartmentDto, ResDeletedDepartmentDto,
+        ResGetDepartmentDto,
+    },
+    event::ReqEventFeedDto,
+    job::{ReqJobIdDto, ReqQueryJobDto, ResJobDto},
+    organization::{
+        ReqExportOrganizationDto, ReqImportOrganizationDto, ReqSearchOrganizationDto,
+        ResImportOrganizationDto, ResSearchHitDto,
+    },
+    webhook::{
+        ReqAddWebhookDto, ReqQueryWebhookDeliveryDto, ReqUpdateWebhookDto, ReqWebhookIdDto,
+        ResAddWebhookDto, ResWebhookDeliveryDto, ResWebhookDto,
+    },
+};
+
+use super::schema::ApiSchema;
+
+macro_rules! api_schemas {
+    ($($ty:ty),+ $(,)?) => {
+        $(impl ApiSchema for $ty {})+
+    };
+}
+
+api_schemas!(
+    ReqCompanyIdDto,
+    ReqAddCompanyDto,
+    ReqUpdateCompanyDto,
+    ReqQueryCompanyDto,
+    ResQueryCompanyDto,
+    ResGetCompanyDto,
+    ResDeletedCompanyDto,
+    ReqBatchCompanyDto,
+    ResBatchCompanyDto,
+    ReqDepartmentIdDto,
+    ReqAddDepartmentDto,
+    ReqUpdateDepartmentDto,
+    ResGetDepartmentDto,
+    ResDeletedDepartmentDto,
+    ReqImportOrganizationDto,
+    ResImportOrganizationDto,
+    ReqExportOrganizationDto,
+    ReqSearchOrganizationDto,
+    ResSearchHitDto,
+    ReqQueryAuditLogDto,
+    ResAuditLogDto,
+    ReqEventFeedDto,
+    ReqWebhookIdDto,
+    ReqAddWebhookDto,
+    ReqUpdateWebhookDto,
+    ResAddWebhookDto,
+    ResWebhookDto,
+    ReqQueryWebhookDeliveryDto,
+    ResWebhookDeliveryDto,
+    ReqJobIdDto,
+    ReqQueryJobDto,
+    ResJobDto,
+);
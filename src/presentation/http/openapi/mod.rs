@@ -0,0 +1,118 @@
+mod dtos;
+mod operation;
+mod schema;
+
+use std::collections::BTreeMap;
+
+use serde_json::{Map, Value, json};
+
+use crate::application::error::ErrorResponse;
+
+pub use operation::*;
+pub use schema::*;
+
+/// Swagger UI reading the document from `/api/openapi.json`, its assets being served
+/// along with it rather than pulled from a CDN.
+#[cfg(feature = "openapi-ui")]
+pub const API_DOCS_HTML: &str = r##"<!doctype html>
+<html>
+  <head>
+    <title>API reference</title>
+    <meta charset="utf-8" />
+    <meta name="viewport" content="width=device-width, initial-scale=1" />
+    <link rel="stylesheet" href="/api/docs/swagger-ui.css" />
+  </head>
+  <body>
+    <div id="api-reference"></div>
+    <script src="/api/docs/swagger-ui-bundle.js"></script>
+    <script>
+      SwaggerUIBundle({ url: "/api/openapi.json", dom_id: "#api-reference" });
+    </script>
+  </body>
+</html>
+"##;
+
+/// Swagger UI 5.17.14, see `assets/swagger-ui` for its license.
+#[cfg(feature = "openapi-ui")]
+pub const SWAGGER_UI_JS: &str = include_str!("../../../../assets/swagger-ui/swagger-ui-bundle.js");
+#[cfg(feature = "openapi-ui")]
+pub const SWAGGER_UI_CSS: &str = include_str!("../../../../assets/swagger-ui/swagger-ui.css");
+
+/// An OpenAPI 3.1 document, the operations of which are described by the input and
+/// output types of their use case.
+#[derive(Debug, Clone)]
+pub struct ApiDoc {
+    title: String,
+    version: String,
+    servers: Vec<String>,
+    components: Components,
+    paths: BTreeMap<String, Map<String, Value>>,
+}
+
+impl ApiDoc {
+    pub fn new(title: &str, version: &str) -> Self {
+        Self {
+            title: title.to_string(),
+            version: version.to_string(),
+            servers: Vec::new(),
+            components: Components::default(),
+            paths: BTreeMap::new(),
+        }
+    }
+
+    pub fn server(mut self, url: &str) -> Self {
+        self.servers.push(url.to_string());
+        self
+    }
+
+    /// Documents the operation of a use case taking `I` and responding with `O`, `secure`
+    /// ones being behind `guards::auth`.
+    pub fn operation<I: ApiInput, O: ApiOutput>(
+        &mut self,
+        method: &str,
+        path: &str,
+        secure: bool,
+        describe: impl FnOnce(Operation<'_>) -> Operation<'_>,
+    ) {
+        let error_schema = self.components.output::<ErrorResponse>();
+
+        let mut op = Operation::new(&mut self.components);
+        I::document(&mut op);
+        O::document(&mut op);
+
+        let mut op = describe(op);
+        if secure {
+            op.add_error(401, "The bearer token is missing or invalid.");
+            op.extension("security", json!([{ "bearerAuth": [] }]));
+        } else {
+            op.extension("security", json!([]));
+        }
+        op.add_error(500, "Something went wrong on our side.");
+
+        self.paths
+            .entry(path.to_string())
+            .or_default()
+            .insert(method.to_lowercase(), op.build(error_schema));
+    }
+
+    pub fn build(self) -> Value {
+        let servers: Vec<Value> = self
+            .servers
+            .iter()
+            .map(|url| json!({ "url": url }))
+            .collect();
+
+        json!({
+            "openapi": "3.1.0",
+            "info": { "title": self.title, "version": self.version },
+            "servers": servers,
+            "paths": self.paths,
+            "components": {
+                "schemas": self.components.into_schemas(),
+                "securitySchemes": {
+                    "bearerAuth": { "type": "http", "scheme": "bearer", "bearerFormat": "JWT" },
+                },
+            },
+        })
+    }
+}
@@ -0,0 +1,298 @@
+use axum::http::HeaderMap;
+use serde_json::{Map, Value, json};
+
+use crate::presentation::{
+    middlewares::{
+        conditional::IfMatchParams,
+        rate_limit::RateLimit,
+        validator::{
+            JsonParams, MultipartParams, PathAndJsonParams, PathAndQueryParams, PathParams,
+            QueryParams,
+        },
+    },
+    response::RawBody,
+};
+
+use super::schema::{ApiSchema, Components, array, binary, string};
+
+/// The part of an operation described by the input of its use case.
+pub trait ApiInput {
+    fn document(op: &mut Operation<'_>);
+}
+
+/// The successful response described by the output of a use case.
+pub trait ApiOutput {
+    fn document(op: &mut Operation<'_>);
+}
+
+pub struct Operation<'c> {
+    components: &'c mut Components,
+    fields: Map<String, Value>,
+    parameters: Vec<Value>,
+    status: u16,
+    content: Option<(Vec<String>, Value)>,
+    response_headers: Map<String, Value>,
+    errors: Vec<(u16, String)>,
+}
+
+impl<'c> Operation<'c> {
+    pub(super) fn new(components: &'c mut Components) -> Self {
+        Self {
+            components,
+            fields: Map::new(),
+            parameters: Vec::new(),
+            status: 200,
+            content: None,
+            response_headers: Map::new(),
+            errors: Vec::new(),
+        }
+    }
+
+    pub fn summary(mut self, summary: &str) -> Self {
+        self.fields.insert("summary".into(), json!(summary));
+        self
+    }
+
+    pub fn description(mut self, description: &str) -> Self {
+        self.fields.insert("description".into(), json!(description));
+        self
+    }
+
+    pub fn tag(mut self, tag: &str) -> Self {
+        self.fields.insert("tags".into(), json!([tag]));
+        self
+    }
+
+    /// Status of the successful response, when it is not 200 or 204.
+    pub fn status(mut self, status: u16) -> Self {
+        self.status = status;
+        self
+    }
+
+    /// Content types of a raw body response.
+    pub fn produces(mut self, content_types: &[&str]) -> Self {
+        if let Some((types, _)) = &mut self.content {
+            *types = content_types.iter().map(|t| t.to_string()).collect();
+        }
+        self
+    }
+
+    pub fn header(mut self, name: &str, description: &str, required: bool) -> Self {
+        self.parameters.push(json!({
+            "name": name,
+            "in": "header",
+            "required": required,
+            "description": description,
+            "schema": string(),
+        }));
+        self
+    }
+
+    /// A header of the successful response.
+    pub fn response_header(mut self, name: &str, description: &str) -> Self {
+        self.response_headers.insert(
+            name.to_string(),
+            json!({ "description": description, "schema": string() }),
+        );
+        self
+    }
+
+    pub fn error(mut self, status: u16, description: &str) -> Self {
+        self.add_error(status, description);
+        self
+    }
+
+    /// Mirrors a `guards::roles` layer on the route.
+    pub fn roles(mut self, roles: &[&str]) -> Self {
+        self.extension("x-required-roles", json!(roles));
+        self.add_error(403, "The user lacks the required role.");
+        self
+    }
+
+    pub fn rate_limit(mut self, limit: RateLimit) -> Self {
+        self.extension("x-rate-limit", json!(limit));
+        self.add_error(429, "Too many requests, see the Retry-After header.");
+        self
+    }
+
+    pub(super) fn extension(&mut self, name: &str, value: Value) {
+        self.fields.insert(name.to_string(), value);
+    }
+
+    pub(super) fn add_error(&mut self, status: u16, description: &str) {
+        if !self.errors.iter().any(|(s, _)| *s == status) {
+            self.errors.push((status, description.to_string()));
+        }
+    }
+
+    fn params<T: ApiSchema>(&mut self, location: &str) {
+        let schema = self.components.inline::<T>();
+        let required: Vec<&str> = schema["required"]
+            .as_array()
+            .map(|r| r.iter().filter_map(Value::as_str).collect())
+            .unwrap_or_default();
+
+        for (name, property) in schema["properties"].as_object().into_iter().flatten() {
+            let mut parameter = json!({
+                "name": name,
+                "in": location,
+                "required": location == "path" || required.contains(&name.as_str()),
+                "schema": property,
+            });
+            if property["type"] == "object" {
+                parameter["style"] = json!("deepObject");
+                parameter["explode"] = json!(true);
+            }
+            self.parameters.push(parameter);
+        }
+
+        self.add_error(400, "The parameters are invalid.");
+    }
+
+    fn body(&mut self, content_type: &str, schema: Value) {
+        self.fields.insert(
+            "requestBody".into(),
+            json!({ "required": true, "content": { content_type: { "schema": schema } } }),
+        );
+        self.add_error(400, "The body is invalid.");
+    }
+
+    fn respond(&mut self, status: u16, content_type: &str, schema: Value) {
+        self.status = status;
+        self.content = Some((vec![content_type.to_string()], schema));
+    }
+
+    pub(super) fn build(self, error_schema: Value) -> Value {
+        let mut operation = self.fields;
+
+        if !self.parameters.is_empty() {
+            operation.insert("parameters".into(), json!(self.parameters));
+        }
+
+        let mut responses = Map::new();
+        let mut success = json!({ "description": "Success." });
+        if let Some((types, schema)) = self.content {
+            let content: Map<String, Value> = types
+                .into_iter()
+                .map(|t| (t, json!({ "schema": schema })))
+                .collect();
+            success["content"] = Value::Object(content);
+        }
+        if !self.response_headers.is_empty() {
+            success["headers"] = Value::Object(self.response_headers);
+        }
+        responses.insert(self.status.to_string(), success);
+
+        for (status, description) in self.errors {
+            responses.insert(
+                status.to_string(),
+                json!({
+                    "description": description,
+                    "content": { "application/json": { "schema": error_schema } },
+                }),
+            );
+        }
+        operation.insert("responses".into(), Value::Object(responses));
+
+        Value::Object(operation)
+    }
+}
+
+impl ApiInput for () {
+    fn document(_: &mut Operation<'_>) {}
+}
+
+impl<I: ApiInput> ApiInput for (HeaderMap, I) {
+    fn document(op: &mut Operation<'_>) {
+        I::document(op);
+    }
+}
+
+impl<T: ApiSchema> ApiInput for PathParams<T> {
+    fn document(op: &mut Operation<'_>) {
+        op.params::<T>("path");
+    }
+}
+
+impl<T: ApiSchema> ApiInput for QueryParams<T> {
+    fn document(op: &mut Operation<'_>) {
+        op.params::<T>("query");
+    }
+}
+
+impl<T: ApiSchema> ApiInput for JsonParams<T> {
+    fn document(op: &mut Operation<'_>) {
+        let schema = op.components.reference::<T>();
+        op.body("application/json", schema);
+    }
+}
+
+impl<T: ApiSchema> ApiInput for MultipartParams<T> {
+    fn document(op: &mut Operation<'_>) {
+        let schema = op.components.reference::<T>();
+        op.body("multipart/form-data", schema);
+    }
+}
+
+impl<P: ApiSchema, B: ApiSchema> ApiInput for PathAndJsonParams<P, B> {
+    fn document(op: &mut Operation<'_>) {
+        PathParams::<P>::document(op);
+        JsonParams::<B>::document(op);
+    }
+}
+
+impl<P: ApiSchema, Q: ApiSchema> ApiInput for PathAndQueryParams<P, Q> {
+    fn document(op: &mut Operation<'_>) {
+        PathParams::<P>::document(op);
+        QueryParams::<Q>::document(op);
+    }
+}
+
+impl<I: ApiInput> ApiInput for IfMatchParams<I> {
+    fn document(op: &mut Operation<'_>) {
+        I::document(op);
+
+        op.parameters.push(json!({
+            "name": "If-Match",
+            "in": "header",
+            "required": true,
+            "description": "The ETag the resource was read with, or `*` for whatever version it is at.",
+            "schema": string(),
+        }));
+        op.add_error(412, "The resource has been modified since it was read.");
+        op.add_error(428, "The If-Match header is missing.");
+    }
+}
+
+impl ApiOutput for () {
+    fn document(op: &mut Operation<'_>) {
+        op.status = 204;
+        op.content = None;
+    }
+}
+
+impl ApiOutput for String {
+    fn document(op: &mut Operation<'_>) {
+        op.respond(200, "application/json", string());
+    }
+}
+
+impl ApiOutput for RawBody {
+    fn document(op: &mut Operation<'_>) {
+        op.respond(200, "application/octet-stream", binary());
+    }
+}
+
+impl<T: ApiSchema> ApiOutput for T {
+    fn document(op: &mut Operation<'_>) {
+        let schema = op.components.output::<T>();
+        op.respond(200, "application/json", schema);
+    }
+}
+
+impl<T: ApiSchema> ApiOutput for Vec<T> {
+    fn document(op: &mut Operation<'_>) {
+        let schema = op.components.output::<T>();
+        op.respond(200, "application/json", array(schema));
+    }
+}
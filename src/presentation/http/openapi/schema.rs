@@ -0,0 +1,67 @@
+use schemars::{JsonSchema, SchemaGenerator, generate::SchemaSettings};
+use serde_json::{Map, Value, json};
+
+/// A type that can be described in the `components/schemas` of the API document, its
+/// schema being derived by `JsonSchema` from its fields and `#[validate]` attributes.
+pub trait ApiSchema: JsonSchema {}
+
+/// Requests and responses are described by separate generators, an optional field
+/// being required in the latter since it is serialized as null.
+#[derive(Debug, Clone)]
+pub struct Components {
+    input: SchemaGenerator,
+    output: SchemaGenerator,
+}
+
+impl Default for Components {
+    fn default() -> Self {
+        // OpenAPI 3.1 schemas are JSON Schema 2020-12
+        let settings = SchemaSettings::draft2020_12().with(|s| {
+            s.definitions_path = "/components/schemas".into();
+            s.meta_schema = None;
+        });
+
+        Self {
+            input: settings.clone().for_deserialize().into_generator(),
+            output: settings.for_serialize().into_generator(),
+        }
+    }
+}
+
+impl Components {
+    /// Registers `T` as read from requests along with the schemas it refers to, and
+    /// returns a reference to it.
+    pub fn reference<T: JsonSchema>(&mut self) -> Value {
+        self.input.subschema_for::<T>().to_value()
+    }
+
+    /// The schema of `T` itself as read from requests, registering the ones it refers to.
+    pub fn inline<T: JsonSchema>(&mut self) -> Value {
+        T::json_schema(&mut self.input).to_value()
+    }
+
+    /// Same as `reference`, for `T` as written to responses.
+    pub fn output<T: JsonSchema>(&mut self) -> Value {
+        self.output.subschema_for::<T>().to_value()
+    }
+
+    /// Types found in both requests and responses, such as enums, are described the same
+    /// way by either generator.
+    pub fn into_schemas(mut self) -> Map<String, Value> {
+        let mut schemas = self.output.take_definitions(true);
+        schemas.extend(self.input.take_definitions(true));
+        schemas
+    }
+}
+
+pub fn string() -> Value {
+    json!({ "type": "string" })
+}
+
+pub fn array(items: Value) -> Value {
+    json!({ "type": "array", "items": items })
+}
+
+pub fn binary() -> Value {
+    json!({ "type": "string", "format": "binary" })
+}
@@ -1 +1,4 @@
+mod registry;
 pub mod v1;
+
+pub use registry::*;
@@ -0,0 +1,285 @@
+use axum::{
+    Router,
+    extract::{DefaultBodyLimit, FromRequest},
+    http::Method,
+    middleware::from_fn_with_state,
+    routing::{MethodFilter, MethodRouter, on},
+};
+use serde::Serialize;
+use serde_json::Value;
+use std::{
+    any::TypeId,
+    collections::{BTreeMap, HashMap},
+};
+
+use crate::{
+    application::{PublicCase, SecureCase},
+    presentation::{
+        guards,
+        handlers::{public_case_handler, secure_case_handler},
+        http::{
+            AppState,
+            openapi::{ApiDoc, ApiInput, ApiOutput, Operation},
+        },
+        middlewares::{
+            consistency,
+            rate_limit::{self, RateLimit, RateLimiter},
+        },
+        response::IntoCaseBody,
+    },
+};
+
+/// Who may call an operation, as declared when it was registered.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Permission {
+    pub method: String,
+    pub path: String,
+    pub authenticated: bool,
+    /// Any of them will do, none meaning any authenticated user.
+    pub roles: Vec<String>,
+    pub rate_limit: Option<RateLimit>,
+}
+
+/// The roles each registered use case requires, for the transports other than REST to
+/// let the same callers through as its route.
+#[derive(Debug, Clone, Default)]
+pub struct CaseRoles(HashMap<TypeId, Vec<String>>);
+
+impl CaseRoles {
+    /// Any of them will do, none meaning any authenticated user. `None` when `U` has no
+    /// route, so nothing tells who may run it.
+    pub fn of<U: 'static>(&self) -> Option<&[String]> {
+        self.0.get(&TypeId::of::<U>()).map(Vec::as_slice)
+    }
+}
+
+/// What a use case declares about its route, along with its documentation.
+pub struct Route<'c> {
+    op: Operation<'c>,
+    roles: Vec<String>,
+    rate_limit: Option<RateLimit>,
+    body_limit: Option<usize>,
+}
+
+impl<'c> Route<'c> {
+    pub fn roles(mut self, roles: &[&str]) -> Self {
+        self.roles = roles.iter().map(|r| r.to_string()).collect();
+        self.op = self.op.roles(roles);
+        self
+    }
+
+    pub fn rate_limit(mut self, limit: RateLimit) -> Self {
+        self.rate_limit = Some(limit);
+        self.op = self.op.rate_limit(limit);
+        self
+    }
+
+    /// Overrides the 2MB axum accepts by default.
+    pub fn body_limit(mut self, bytes: usize) -> Self {
+        self.body_limit = Some(bytes);
+        self.op = self.op.error(413, "The body is too large.");
+        self
+    }
+
+    pub fn tag(mut self, tag: &str) -> Self {
+        self.op = self.op.tag(tag);
+        self
+    }
+
+    pub fn summary(mut self, summary: &str) -> Self {
+        self.op = self.op.summary(summary);
+        self
+    }
+
+    pub fn description(mut self, description: &str) -> Self {
+        self.op = self.op.description(description);
+        self
+    }
+
+    pub fn status(mut self, status: u16) -> Self {
+        self.op = self.op.status(status);
+        self
+    }
+
+    pub fn produces(mut self, content_types: &[&str]) -> Self {
+        self.op = self.op.produces(content_types);
+        self
+    }
+
+    pub fn header(mut self, name: &str, description: &str) -> Self {
+        self.op = self.op.header(name, description, false);
+        self
+    }
+
+    pub fn response_header(mut self, name: &str, description: &str) -> Self {
+        self.op = self.op.response_header(name, description);
+        self
+    }
+
+    pub fn error(mut self, status: u16, description: &str) -> Self {
+        self.op = self.op.error(status, description);
+        self
+    }
+}
+
+struct Endpoint {
+    case: TypeId,
+    permission: Permission,
+    handler: MethodRouter<AppState>,
+}
+
+/// Use cases along with their method, path, auth requirement, roles and rate limit, from
+/// which the router, the OpenAPI document and the permission matrix are all generated.
+pub struct RouteRegistry {
+    doc: ApiDoc,
+    endpoints: Vec<Endpoint>,
+}
+
+impl RouteRegistry {
+    pub fn new(doc: ApiDoc) -> Self {
+        Self {
+            doc,
+            endpoints: Vec::new(),
+        }
+    }
+
+    /// A use case behind `guards::auth`, `make_uc` being a `make_case!`.
+    pub fn secure<U, I, O, F, M>(
+        mut self,
+        method: Method,
+        path: &str,
+        make_uc: F,
+        declare: impl FnOnce(Route<'_>) -> Route<'_>,
+    ) -> Self
+    where
+        U: SecureCase<Input = I, Output = O> + Send + Sync + 'static,
+        I: ApiInput + FromRequest<AppState, M> + Send + 'static,
+        O: ApiOutput + IntoCaseBody + Send + 'static,
+        F: Fn(&AppState) -> U + Send + Sync + Clone + 'static,
+        M: 'static,
+    {
+        let handler = on(filter(&method), secure_case_handler(make_uc));
+        self.add::<U, I, O>(method, path, true, handler, declare);
+        self
+    }
+
+    pub fn public<U, I, O, F, M>(
+        mut self,
+        method: Method,
+        path: &str,
+        make_uc: F,
+        declare: impl FnOnce(Route<'_>) -> Route<'_>,
+    ) -> Self
+    where
+        U: PublicCase<Input = I, Output = O> + Send + Sync + 'static,
+        I: ApiInput + FromRequest<AppState, M> + Send + 'static,
+        O: ApiOutput + IntoCaseBody + Send + 'static,
+        F: Fn(&AppState) -> U + Send + Sync + Clone + 'static,
+        M: 'static,
+    {
+        let handler = on(filter(&method), public_case_handler(make_uc));
+        self.add::<U, I, O>(method, path, false, handler, declare);
+        self
+    }
+
+    fn add<U: 'static, I: ApiInput, O: ApiOutput>(
+        &mut self,
+        method: Method,
+        path: &str,
+        authenticated: bool,
+        mut handler: MethodRouter<AppState>,
+        declare: impl FnOnce(Route<'_>) -> Route<'_>,
+    ) {
+        let mut declared = None;
+        self.doc
+            .operation::<I, O>(method.as_str(), path, authenticated, |op| {
+                let route = declare(Route {
+                    op,
+                    roles: Vec::new(),
+                    rate_limit: None,
+                    body_limit: None,
+                });
+                declared = Some((route.roles, route.rate_limit, route.body_limit));
+                route.op
+            });
+        let (roles, rate_limit, body_limit) = declared.expect("the route is always declared");
+
+        // layers added last run first: auth, then the rate limit, then the roles
+        if !roles.is_empty() {
+            handler = handler.route_layer(from_fn_with_state(roles.clone(), guards::roles));
+        }
+        if let Some(limit) = rate_limit {
+            handler = handler.route_layer(from_fn_with_state(
+                RateLimiter::new(limit),
+                rate_limit::rate_limit,
+            ));
+        }
+        if let Some(bytes) = body_limit {
+            handler = handler.layer(DefaultBodyLimit::max(bytes));
+        }
+
+        self.endpoints.push(Endpoint {
+            case: TypeId::of::<U>(),
+            permission: Permission {
+                method: method.to_string(),
+                path: path.to_string(),
+                authenticated,
+                roles,
+                rate_limit,
+            },
+            handler,
+        });
+    }
+
+    pub fn router(&self, state: AppState) -> Router<AppState> {
+        let mut paths: BTreeMap<&str, MethodRouter<AppState>> = BTreeMap::new();
+
+        for endpoint in &self.endpoints {
+            let mut handler = endpoint.handler.clone();
+            if endpoint.permission.authenticated {
+                if !matches!(endpoint.permission.method.as_str(), "GET" | "HEAD") {
+                    handler = handler
+                        .route_layer(from_fn_with_state(state.clone(), consistency::track_writes));
+                }
+                handler = handler.route_layer(from_fn_with_state(state.clone(), guards::auth));
+            }
+
+            let path = endpoint.permission.path.as_str();
+            let merged = match paths.remove(path) {
+                Some(existing) => existing.merge(handler),
+                None => handler,
+            };
+            paths.insert(path, merged);
+        }
+
+        paths
+            .into_iter()
+            .fold(Router::new(), |router, (path, handler)| {
+                router.route(path, handler)
+            })
+    }
+
+    pub fn openapi(&self) -> Value {
+        self.doc.clone().build()
+    }
+
+    pub fn case_roles(&self) -> CaseRoles {
+        CaseRoles(
+            self.endpoints
+                .iter()
+                .map(|e| (e.case, e.permission.roles.clone()))
+                .collect(),
+        )
+    }
+
+    pub fn permissions(&self) -> Vec<Permission> {
+        self.endpoints
+            .iter()
+            .map(|e| e.permission.clone())
+            .collect()
+    }
+}
+
+fn filter(method: &Method) -> MethodFilter {
+    MethodFilter::try_from(method.clone()).expect("routes use standard methods")
+}
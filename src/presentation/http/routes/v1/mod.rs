@@ -1,38 +1,379 @@
 use crate::{
     application::cases::{
+        audit::QueryAuditLogUseCase,
         auth::SignInUseCase,
-        company::{AddCompanyUseCase, QueryCompanyUseCase},
-        department::AddDepartmentUseCase,
+        company::{
+            AddCompanyUseCase, BatchCompanyUseCase, DeleteCompanyUseCase, GetCompanyUseCase,
+            QueryCompanyUseCase, QueryDeletedCompanyUseCase, RestoreCompanyUseCase,
+            UpdateCompanyUseCase,
+        },
+        department::{
+            AddDepartmentUseCase, DeleteDepartmentUseCase, GetDepartmentUseCase,
+            QueryDeletedDepartmentUseCase, RestoreDepartmentUseCase, UpdateDepartmentUseCase,
+        },
+        event::StreamEventsUseCase,
+        job::{CancelJobUseCase, GetJobUseCase, QueryJobUseCase, RetryJobUseCase},
+        organization::{
+            ExportCompaniesUseCase, ExportDepartmentsUseCase, ImportOrganizationUseCase,
+            SearchOrganizationUseCase,
+        },
+        webhook::{
+            AddWebhookUseCase, DeleteWebhookUseCase, GetWebhookUseCase,
+            QueryWebhookDeliveryUseCase, QueryWebhookUseCase, UpdateWebhookUseCase,
+        },
     },
     make_case,
     presentation::{
-        guards,
-        handlers::{public_case_handler, secure_case_handler},
-        http::AppState,
+        http::{AppState, openapi::ApiDoc, routes::RouteRegistry},
+        middlewares::{conditional, rate_limit::RateLimit},
     },
 };
 
-use axum::{
-    Router,
-    middleware::from_fn_with_state,
-    routing::{get, post},
-};
+use axum::{Router, http::Method, middleware::from_fn};
+
+const ADMIN: &[&str] = &["admin"];
+
+// spreadsheets of a few thousand rows exceed the default 2MB
+const IMPORT_BODY_LIMIT: usize = 20 * 1024 * 1024;
+
+const EXPORT_TYPES: &[&str] = &[
+    "text/csv",
+    "application/x-ndjson",
+    "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet",
+];
 
 pub fn v1_routes(state: AppState) -> Router<AppState> {
-    Router::new()
-        .route(
+    v1_registry()
+        .router(state)
+        .layer(from_fn(conditional::not_modified))
+}
+
+pub fn v1_registry() -> RouteRegistry {
+    let doc = ApiDoc::new(env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION")).server("/api/v1");
+
+    RouteRegistry::new(doc)
+        .secure(
+            Method::GET,
+            "/companies",
+            make_case!(QueryCompanyUseCase),
+            |r| r.roles(ADMIN).tag("companies").summary("List companies"),
+        )
+        .secure(
+            Method::POST,
             "/companies",
-            get(secure_case_handler(make_case!(QueryCompanyUseCase)))
-                .route_layer(from_fn_with_state(vec!["admin".to_string()], guards::roles))
-                .post(secure_case_handler(make_case!(AddCompanyUseCase))),
+            make_case!(AddCompanyUseCase),
+            |r| {
+                r.roles(ADMIN)
+                    .tag("companies")
+                    .summary("Create a company")
+                    .status(201)
+                    .response_header("Location", "Where the company can be read.")
+            },
         )
-        .route(
+        .secure(
+            Method::GET,
+            "/companies/{id}",
+            make_case!(GetCompanyUseCase),
+            |r| {
+                r.tag("companies")
+                    .summary("Read a company")
+                    .response_header("ETag", "The version to update or delete it with.")
+                    .error(404, "The company does not exist.")
+            },
+        )
+        .secure(
+            Method::PUT,
+            "/companies/{id}",
+            make_case!(UpdateCompanyUseCase),
+            |r| {
+                r.roles(ADMIN)
+                    .tag("companies")
+                    .summary("Update a company")
+                    .response_header("ETag", "The new version.")
+                    .error(404, "The company does not exist.")
+            },
+        )
+        .secure(
+            Method::DELETE,
+            "/companies/{id}",
+            make_case!(DeleteCompanyUseCase),
+            |r| {
+                r.roles(ADMIN)
+                    .tag("companies")
+                    .summary("Delete a company and its departments")
+                    .error(404, "The company does not exist.")
+            },
+        )
+        .secure(
+            Method::POST,
+            "/companies/batch",
+            make_case!(BatchCompanyUseCase),
+            |r| {
+                r.roles(ADMIN)
+                    .rate_limit(RateLimit::per_minute(30))
+                    .tag("companies")
+                    .summary("Create, update and delete companies at once")
+                    .error(422, "An atomic batch was rolled back, see the results.")
+            },
+        )
+        .secure(
+            Method::GET,
+            "/companies/export",
+            make_case!(ExportCompaniesUseCase),
+            |r| {
+                r.roles(ADMIN)
+                    .rate_limit(RateLimit::per_minute(10))
+                    .tag("organization")
+                    .summary("Export companies")
+                    .produces(EXPORT_TYPES)
+                    .error(406, "None of the accepted formats can be produced.")
+            },
+        )
+        .secure(
+            Method::GET,
+            "/companies/deleted",
+            make_case!(QueryDeletedCompanyUseCase),
+            |r| {
+                r.roles(ADMIN)
+                    .tag("companies")
+                    .summary("List deleted companies")
+            },
+        )
+        .secure(
+            Method::POST,
+            "/companies/{id}/restore",
+            make_case!(RestoreCompanyUseCase),
+            |r| {
+                r.roles(ADMIN)
+                    .tag("companies")
+                    .summary("Restore a deleted company")
+                    .error(404, "The company does not exist.")
+            },
+        )
+        .secure(
+            Method::POST,
             "/departments",
-            post(secure_case_handler(make_case!(AddDepartmentUseCase))),
+            make_case!(AddDepartmentUseCase),
+            |r| {
+                r.tag("departments")
+                    .summary("Create a department")
+                    .status(201)
+                    .response_header("Location", "Where the department can be read.")
+            },
+        )
+        .secure(
+            Method::GET,
+            "/departments/{id}",
+            make_case!(GetDepartmentUseCase),
+            |r| {
+                r.tag("departments")
+                    .summary("Read a department")
+                    .response_header("ETag", "The version to update or delete it with.")
+                    .error(404, "The department does not exist.")
+            },
+        )
+        .secure(
+            Method::PUT,
+            "/departments/{id}",
+            make_case!(UpdateDepartmentUseCase),
+            |r| {
+                r.tag("departments")
+                    .summary("Update a department")
+                    .response_header("ETag", "The new version.")
+                    .error(404, "The department does not exist.")
+            },
+        )
+        .secure(
+            Method::DELETE,
+            "/departments/{id}",
+            make_case!(DeleteDepartmentUseCase),
+            |r| {
+                r.tag("departments")
+                    .summary("Delete a department")
+                    .error(404, "The department does not exist.")
+            },
+        )
+        .secure(
+            Method::GET,
+            "/departments/export",
+            make_case!(ExportDepartmentsUseCase),
+            |r| {
+                r.roles(ADMIN)
+                    .rate_limit(RateLimit::per_minute(10))
+                    .tag("organization")
+                    .summary("Export departments")
+                    .produces(EXPORT_TYPES)
+                    .error(406, "None of the accepted formats can be produced.")
+            },
+        )
+        .secure(
+            Method::GET,
+            "/departments/deleted",
+            make_case!(QueryDeletedDepartmentUseCase),
+            |r| {
+                r.roles(ADMIN)
+                    .tag("departments")
+                    .summary("List deleted departments")
+            },
+        )
+        .secure(
+            Method::POST,
+            "/departments/{id}/restore",
+            make_case!(RestoreDepartmentUseCase),
+            |r| {
+                r.roles(ADMIN)
+                    .tag("departments")
+                    .summary("Restore a deleted department")
+                    .error(404, "The department does not exist.")
+                    .error(409, "The company of the department is deleted.")
+            },
+        )
+        .secure(
+            Method::POST,
+            "/organization/import",
+            make_case!(ImportOrganizationUseCase),
+            |r| {
+                r.roles(ADMIN)
+                    .body_limit(IMPORT_BODY_LIMIT)
+                    .rate_limit(RateLimit::per_minute(5))
+                    .tag("organization")
+                    .summary("Import companies and departments")
+                    .error(
+                        422,
+                        "An all-or-nothing import was rejected, see the errors.",
+                    )
+            },
+        )
+        .secure(
+            Method::GET,
+            "/search",
+            make_case!(SearchOrganizationUseCase),
+            |r| {
+                r.rate_limit(RateLimit::per_minute(120))
+                    .tag("organization")
+                    .summary("Search companies and departments")
+            },
+        )
+        .secure(
+            Method::GET,
+            "/events",
+            make_case!(StreamEventsUseCase),
+            |r| {
+                r.tag("events")
+                    .summary("Stream company and department changes")
+                    .description(
+                        "Server-sent events of the tenant, the `id` of which can be sent back \
+                         as `Last-Event-ID` to resume after it. Without it, only what happens \
+                         from now on is streamed.",
+                    )
+                    .produces(&["text/event-stream"])
+                    .header("Last-Event-ID", "The id of the last event received.")
+            },
+        )
+        .secure(
+            Method::GET,
+            "/audit",
+            make_case!(QueryAuditLogUseCase),
+            |r| r.roles(ADMIN).tag("audit").summary("List audit logs"),
+        )
+        .secure(
+            Method::GET,
+            "/webhooks",
+            make_case!(QueryWebhookUseCase),
+            |r| r.roles(ADMIN).tag("webhooks").summary("List webhooks"),
+        )
+        .secure(
+            Method::POST,
+            "/webhooks",
+            make_case!(AddWebhookUseCase),
+            |r| {
+                r.roles(ADMIN)
+                    .tag("webhooks")
+                    .summary("Subscribe a webhook")
+                    .status(201)
+                    .response_header("Location", "Where the webhook can be read.")
+            },
+        )
+        .secure(
+            Method::GET,
+            "/webhooks/{id}",
+            make_case!(GetWebhookUseCase),
+            |r| {
+                r.roles(ADMIN)
+                    .tag("webhooks")
+                    .summary("Read a webhook")
+                    .error(404, "The webhook does not exist.")
+            },
+        )
+        .secure(
+            Method::PUT,
+            "/webhooks/{id}",
+            make_case!(UpdateWebhookUseCase),
+            |r| {
+                r.roles(ADMIN)
+                    .tag("webhooks")
+                    .summary("Update a webhook")
+                    .error(404, "The webhook does not exist.")
+            },
+        )
+        .secure(
+            Method::DELETE,
+            "/webhooks/{id}",
+            make_case!(DeleteWebhookUseCase),
+            |r| {
+                r.roles(ADMIN)
+                    .tag("webhooks")
+                    .summary("Delete a webhook")
+                    .error(404, "The webhook does not exist.")
+            },
+        )
+        .secure(
+            Method::GET,
+            "/webhooks/{id}/deliveries",
+            make_case!(QueryWebhookDeliveryUseCase),
+            |r| {
+                r.roles(ADMIN)
+                    .tag("webhooks")
+                    .summary("List the deliveries of a webhook")
+            },
+        )
+        .secure(Method::GET, "/jobs", make_case!(QueryJobUseCase), |r| {
+            r.roles(ADMIN).tag("jobs").summary("List jobs")
+        })
+        .secure(Method::GET, "/jobs/{id}", make_case!(GetJobUseCase), |r| {
+            r.roles(ADMIN)
+                .tag("jobs")
+                .summary("Read a job")
+                .error(404, "The job does not exist.")
+        })
+        .secure(
+            Method::POST,
+            "/jobs/{id}/retry",
+            make_case!(RetryJobUseCase),
+            |r| {
+                r.roles(ADMIN)
+                    .tag("jobs")
+                    .summary("Retry a failed job")
+                    .error(404, "The job does not exist.")
+                    .error(409, "The job can't be retried in its current status.")
+            },
         )
-        .layer(from_fn_with_state(state, guards::auth))
-        .route(
-            "/signin",
-            get(public_case_handler(make_case!(SignInUseCase))),
+        .secure(
+            Method::POST,
+            "/jobs/{id}/cancel",
+            make_case!(CancelJobUseCase),
+            |r| {
+                r.roles(ADMIN)
+                    .tag("jobs")
+                    .summary("Cancel a pending job")
+                    .error(404, "The job does not exist.")
+                    .error(409, "The job can't be cancelled in its current status.")
+            },
         )
+        .public(Method::GET, "/signin", make_case!(SignInUseCase), |r| {
+            r.rate_limit(RateLimit::per_minute(10))
+                .tag("auth")
+                .summary("Get an access token")
+                .description("The token is to be sent as a bearer `Authorization` header.")
+        })
 }
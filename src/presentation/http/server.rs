@@ -1,13 +1,27 @@
-use std::sync::Arc;
+use std::{net::SocketAddr, sync::Arc};
 
 use crate::{
     config::AppConfig,
-    infrastructure::{db::DbContext, helpers::token::JwtHelper},
-    presentation::http::AppState,
+    infrastructure::{db::DbContext, events::EventBus, helpers::token::JwtHelper},
+    presentation::{
+        graphql, grpc, guards,
+        http::AppState,
+        middlewares::{
+            client_ip::{self, TrustedProxies},
+            consistency,
+            rate_limit::RateLimit,
+        },
+    },
 };
 use anyhow::Context;
-use axum::{Router, extract::Request, routing::get, serve};
-use sea_orm::DatabaseConnection;
+use axum::{
+    Extension, Json, Router,
+    extract::Request,
+    middleware::{from_fn, from_fn_with_state},
+    routing::{get, post},
+    serve,
+};
+use tower_http::request_id::{MakeRequestUuid, PropagateRequestIdLayer, SetRequestIdLayer};
 
 use super::routes;
 
@@ -17,42 +31,115 @@ pub struct HttpServer {
 }
 
 impl HttpServer {
-    pub async fn new(db: Arc<DatabaseConnection>, config: Arc<AppConfig>) -> anyhow::Result<Self> {
+    pub async fn new(
+        db_context: Arc<DbContext>,
+        config: Arc<AppConfig>,
+        event_bus: Arc<EventBus>,
+    ) -> anyhow::Result<Self> {
+        let state = AppState {
+            db_context,
+            jwt_helper: Arc::new(JwtHelper::new(config.token_secret_key.clone())),
+            event_bus,
+        };
+        let router = Self::router(state, &config);
+
+        let listener = tokio::net::TcpListener::bind(format!("0.0.0.0:{}", config.server_port))
+            .await
+            .with_context(|| format!("failed to start listening on {}", config.server_port))?;
+
+        Ok(Self { router, listener })
+    }
+
+    /// The full application, guards and layers included, without binding a port.
+    pub fn router(state: AppState, config: &AppConfig) -> Router {
         let trace_layer =
             tower_http::trace::TraceLayer::new_for_http().make_span_with(|req: &Request<_>| {
                 let uri = req.uri().to_string();
                 tracing::info_span!("[HTTP]", method = ?req.method(), uri)
             });
 
-        let state = AppState {
-            db_context: Arc::new(DbContext::new(db)),
-            jwt_helper: Arc::new(JwtHelper::new(config.token_secret_key.clone())),
-        };
-
-        let router = Router::new()
+        Router::new()
             .nest(
                 "/api/",
-                api_routes().nest("/v1", routes::v1::v1_routes(state.clone())),
+                api_routes(config.openapi_ui)
+                    .merge(graphql_routes(state.clone(), config))
+                    .nest("/v1", routes::v1::v1_routes(state.clone())),
             )
+            .with_state(state.clone())
+            // gRPC calls come over HTTP/2 on the same port, told apart by their path
+            .merge(grpc::grpc_routes(
+                state,
+                RateLimit::per_minute(config.grpc_rate_limit),
+            ))
+            .layer(from_fn(consistency::read_your_writes))
+            .layer(from_fn_with_state(
+                TrustedProxies::new(config.trusted_proxies.clone()),
+                client_ip::client_ip,
+            ))
             .layer(trace_layer)
-            .with_state(state);
-
-        let listener = tokio::net::TcpListener::bind(format!("0.0.0.0:{}", config.server_port))
-            .await
-            .with_context(|| format!("failed to start listening on {}", config.server_port))?;
-
-        Ok(Self { router, listener })
+            .layer(PropagateRequestIdLayer::x_request_id())
+            .layer(SetRequestIdLayer::x_request_id(MakeRequestUuid))
     }
 
     pub async fn start(self) -> anyhow::Result<()> {
         tracing::debug!("listening on {}", self.listener.local_addr().unwrap());
-        serve(self.listener, self.router)
-            .await
-            .context("received error from running server")?;
+        serve(
+            self.listener,
+            self.router
+                .into_make_service_with_connect_info::<SocketAddr>(),
+        )
+        .await
+        .context("received error from running server")?;
         Ok(())
     }
 }
 
-fn api_routes() -> Router<AppState> {
-    Router::new().route("/healthy", get(|| async { "I'm alive!" }))
+fn api_routes(openapi_ui: bool) -> Router<AppState> {
+    let v1 = routes::v1::v1_registry();
+    let spec = Json(v1.openapi());
+    let permissions = Json(v1.permissions());
+
+    let router = Router::new()
+        .route("/healthy", get(|| async { "I'm alive!" }))
+        .route("/openapi.json", get(|| async move { spec }))
+        .route("/permissions.json", get(|| async move { permissions }));
+
+    if openapi_ui {
+        docs_routes(router)
+    } else {
+        router
+    }
+}
+
+#[cfg(feature = "openapi-ui")]
+fn docs_routes(router: Router<AppState>) -> Router<AppState> {
+    use axum::{http::header, response::Html};
+
+    use super::openapi::{API_DOCS_HTML, SWAGGER_UI_CSS, SWAGGER_UI_JS};
+
+    router
+        .route("/docs", get(|| async { Html(API_DOCS_HTML) }))
+        .route(
+            "/docs/swagger-ui-bundle.js",
+            get(|| async { ([(header::CONTENT_TYPE, "text/javascript")], SWAGGER_UI_JS) }),
+        )
+        .route(
+            "/docs/swagger-ui.css",
+            get(|| async { ([(header::CONTENT_TYPE, "text/css")], SWAGGER_UI_CSS) }),
+        )
+}
+
+#[cfg(not(feature = "openapi-ui"))]
+fn docs_routes(router: Router<AppState>) -> Router<AppState> {
+    tracing::warn!("OPENAPI_UI is true, but the openapi-ui feature was not compiled in");
+    router
+}
+
+fn graphql_routes(state: AppState, config: &AppConfig) -> Router<AppState> {
+    let schema = graphql::build_schema(config.graphql_max_depth, config.graphql_max_complexity);
+
+    Router::new()
+        .route("/graphql", post(graphql::graphql_handler))
+        .route_layer(from_fn_with_state(state, guards::auth))
+        .layer(Extension(schema))
 }
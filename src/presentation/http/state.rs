@@ -1,9 +1,11 @@
 use std::sync::Arc;
 
-use crate::infrastructure::{db::DbContext, helpers::token::JwtHelper};
+use crate::infrastructure::{db::DbContext, events::EventBus, helpers::token::JwtHelper};
 
 #[derive(Clone, Debug)]
 pub struct AppState {
     pub db_context: Arc<DbContext>,
     pub jwt_helper: Arc<JwtHelper>,
+    /// Tells the change feed that events were published by this instance.
+    pub event_bus: Arc<EventBus>,
 }
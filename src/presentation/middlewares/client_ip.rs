@@ -0,0 +1,85 @@
+use std::{
+    net::{IpAddr, SocketAddr},
+    sync::Arc,
+};
+
+use axum::{
+    extract::{ConnectInfo, Request, State},
+    http::HeaderMap,
+    middleware::Next,
+    response::Response,
+};
+use ipnet::IpNet;
+
+/// Address of the client a request comes from, as told by `client_ip`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ClientIp(pub IpAddr);
+
+impl ClientIp {
+    /// The one `client_ip` told, or the peer itself when the layer is not in place.
+    pub fn of(req: &Request) -> Option<Self> {
+        req.extensions().get::<Self>().copied().or_else(|| {
+            req.extensions()
+                .get::<ConnectInfo<SocketAddr>>()
+                .map(|ConnectInfo(peer)| Self(peer.ip()))
+        })
+    }
+}
+
+/// The proxies whose `X-Forwarded-For` header is believed, any other peer being the
+/// client itself whatever the header says.
+#[derive(Debug, Clone, Default)]
+pub struct TrustedProxies(Arc<Vec<IpNet>>);
+
+impl TrustedProxies {
+    pub fn new(proxies: Vec<IpNet>) -> Self {
+        Self(Arc::new(proxies))
+    }
+
+    fn trusts(&self, ip: IpAddr) -> bool {
+        self.0.iter().any(|net| net.contains(&ip))
+    }
+
+    /// Walks `X-Forwarded-For` from the right, each trusted hop vouching for the address
+    /// on its left, up to the first one that is not trusted.
+    pub fn resolve(&self, peer: IpAddr, headers: &HeaderMap) -> IpAddr {
+        let mut client = peer;
+        if !self.trusts(client) {
+            return client;
+        }
+
+        let hops: Vec<&str> = headers
+            .get_all("x-forwarded-for")
+            .iter()
+            .filter_map(|h| h.to_str().ok())
+            .flat_map(|h| h.split(','))
+            .collect();
+
+        for hop in hops.into_iter().rev() {
+            let Ok(ip) = hop.trim().parse::<IpAddr>() else {
+                break;
+            };
+            client = ip;
+            if !self.trusts(ip) {
+                break;
+            }
+        }
+
+        client
+    }
+}
+
+/// Tells the address of the client to the layers after it, the peer of the connection
+/// being known through `ConnectInfo`.
+pub async fn client_ip(
+    State(proxies): State<TrustedProxies>,
+    mut req: Request,
+    next: Next,
+) -> Response {
+    if let Some(&ConnectInfo(peer)) = req.extensions().get::<ConnectInfo<SocketAddr>>() {
+        let ip = proxies.resolve(peer.ip(), req.headers());
+        req.extensions_mut().insert(ClientIp(ip));
+    }
+
+    next.run(req).await
+}
@@ -0,0 +1,175 @@
+use axum::{
+    extract::{FromRequest, FromRequestParts, Request},
+    http::{HeaderMap, Method, StatusCode, header, request::Parts},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+
+use crate::{
+    application::error::AppError,
+    domain::error::{DomainError, ErrorKind},
+};
+
+#[derive(Debug, Clone)]
+pub struct IfMatchParams<T> {
+    pub if_match: IfMatch,
+    pub params: T,
+}
+
+/// An `If-Match` precondition. Entity tags are compared strongly, as RFC 9110 requires:
+/// a weak tag never matches.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IfMatch {
+    /// `*`, any current version of an existing resource.
+    Any,
+    /// The versions of the strong tags listed.
+    Versions(Vec<i32>),
+}
+
+impl IfMatch {
+    pub fn version(version: i32) -> Self {
+        Self::Versions(vec![version])
+    }
+
+    /// `None` when the value is not `*` nor a list of entity tags.
+    pub fn parse(value: &str) -> Option<Self> {
+        let value = value.trim();
+        if value == "*" {
+            return Some(Self::Any);
+        }
+
+        let mut versions = Vec::new();
+        for tag in value.split(',').map(str::trim) {
+            if let Some(weak) = tag.strip_prefix("W/") {
+                opaque_tag(weak)?;
+                continue;
+            }
+            let version: Option<i32> = match opaque_tag(tag) {
+                // a tag of ours or someone else's, which can only fail to match
+                Some(opaque) => opaque.parse().ok(),
+                // bare numbers are still taken from the clients sending the version alone
+                None => Some(tag.parse().ok()?),
+            };
+            versions.extend(version);
+        }
+        Some(Self::Versions(versions))
+    }
+
+    pub fn matches(&self, current: i32) -> bool {
+        match self {
+            Self::Any => true,
+            Self::Versions(versions) => versions.contains(&current),
+        }
+    }
+
+    /// The version to write with. A single tag is handed as is to the repositories, which
+    /// compare it while writing; otherwise `current`, the version of the resource if it
+    /// exists, is awaited and checked here.
+    pub async fn resolve<F>(&self, current: F) -> Result<i32, DomainError>
+    where
+        F: Future<Output = Result<Option<i32>, DomainError>>,
+    {
+        if let Self::Versions(versions) = self
+            && let [version] = versions.as_slice()
+        {
+            return Ok(*version);
+        }
+
+        match current.await? {
+            Some(version) if self.matches(version) => Ok(version),
+            _ => Err(DomainError::CaseError(
+                ErrorKind::PreconditionFailed,
+                "VERSION_MISMATCH".to_string(),
+                "the current version does not match If-Match".to_string(),
+            )),
+        }
+    }
+}
+
+impl<T, S> FromRequest<S> for IfMatchParams<T>
+where
+    T: FromRequest<S, Rejection = AppError>,
+    S: Send + Sync,
+{
+    type Rejection = AppError;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let if_match = if_match(req.headers())?;
+        let params = T::from_request(req, state).await?;
+
+        Ok(Self { if_match, params })
+    }
+}
+
+impl<T, S> FromRequestParts<S> for IfMatchParams<T>
+where
+    T: FromRequestParts<S, Rejection = AppError> + Send,
+    S: Send + Sync,
+{
+    type Rejection = AppError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let if_match = if_match(&parts.headers)?;
+        let params = T::from_request_parts(parts, state).await?;
+
+        Ok(Self { if_match, params })
+    }
+}
+
+pub async fn not_modified(req: Request, next: Next) -> Response {
+    let if_none_match = (req.method() == Method::GET)
+        .then(|| req.headers().get(header::IF_NONE_MATCH).cloned())
+        .flatten();
+
+    let res = next.run(req).await;
+
+    match (if_none_match, res.headers().get(header::ETAG)) {
+        (Some(expected), Some(etag))
+            if res.status() == StatusCode::OK && matches(&expected, etag) =>
+        {
+            let mut headers = HeaderMap::new();
+            headers.insert(header::ETAG, etag.clone());
+            (StatusCode::NOT_MODIFIED, headers).into_response()
+        }
+        _ => res,
+    }
+}
+
+fn if_match(headers: &HeaderMap) -> Result<IfMatch, AppError> {
+    headers
+        .get(header::IF_MATCH)
+        .and_then(|h| h.to_str().ok())
+        .and_then(IfMatch::parse)
+        .ok_or_else(|| {
+            AppError::PreconditionRequired(
+                "If-Match header with the resource version is required.".to_string(),
+            )
+        })
+}
+
+fn opaque_tag(tag: &str) -> Option<&str> {
+    tag.strip_prefix('"')?.strip_suffix('"')
+}
+
+fn parse_etag(value: &str) -> Option<i32> {
+    value
+        .trim()
+        .trim_start_matches("W/")
+        .trim_matches('"')
+        .parse()
+        .ok()
+}
+
+fn matches(expected: &header::HeaderValue, etag: &header::HeaderValue) -> bool {
+    let Some(current) = etag.to_str().ok().and_then(parse_etag) else {
+        return false;
+    };
+
+    expected
+        .to_str()
+        .map(|v| {
+            v.split(',')
+                .any(|tag| tag.trim() == "*" || parse_etag(tag) == Some(current))
+        })
+        .unwrap_or(false)
+}
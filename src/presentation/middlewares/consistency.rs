@@ -0,0 +1,73 @@
+use std::time::Duration;
+
+use axum::{
+    extract::{Request, State},
+    http::{HeaderMap, HeaderValue, header},
+    middleware::Next,
+    response::Response,
+};
+
+use crate::{infrastructure::db::LastWrite, presentation::http::AppState};
+
+/// When the client last wrote, as milliseconds since the epoch. Also sent as gRPC
+/// metadata, for clients to send back along with their next calls.
+pub const LAST_WRITE_HEADER: &str = "x-last-write";
+/// The same for browsers, which send it back on their own.
+const LAST_WRITE_COOKIE: &str = "last_write";
+
+/// Runs the request within the `LastWrite` its client told, in the `X-Last-Write` header
+/// or the `last_write` cookie, for its reads to be served by the primary if need be.
+pub async fn read_your_writes(req: Request, next: Next) -> Response {
+    match last_write_of(req.headers()) {
+        Some(last) => last.scope(next.run(req)).await,
+        None => next.run(req).await,
+    }
+}
+
+/// Behind `guards::auth` on the routes that write, so that the client is told when it
+/// wrote and keeps reading from the primary for a while after they succeeded.
+pub async fn track_writes(State(state): State<AppState>, req: Request, next: Next) -> Response {
+    let mut res = next.run(req).await;
+
+    if let Some(window) = state.db_context.read_your_writes()
+        && res.status().is_success()
+    {
+        mark_written(res.headers_mut(), LastWrite::now(), window);
+    }
+
+    res
+}
+
+/// The cookie expires along with the window, past which the marker makes no difference.
+fn mark_written(headers: &mut HeaderMap, last: LastWrite, window: Duration) {
+    let millis = last.to_millis();
+    headers.insert(LAST_WRITE_HEADER, HeaderValue::from(millis));
+
+    let cookie = format!(
+        "{}={}; Max-Age={}; Path=/; HttpOnly; SameSite=Lax",
+        LAST_WRITE_COOKIE,
+        millis,
+        window.as_millis().div_ceil(1000)
+    );
+    if let Ok(cookie) = HeaderValue::from_str(&cookie) {
+        headers.append(header::SET_COOKIE, cookie);
+    }
+}
+
+fn last_write_of(headers: &HeaderMap) -> Option<LastWrite> {
+    let cookie = || {
+        headers
+            .get_all(header::COOKIE)
+            .iter()
+            .filter_map(|h| h.to_str().ok())
+            .flat_map(|h| h.split(';'))
+            .find_map(|c| c.trim().strip_prefix(LAST_WRITE_COOKIE)?.strip_prefix('='))
+    };
+
+    headers
+        .get(LAST_WRITE_HEADER)
+        .and_then(|h| h.to_str().ok())
+        .or_else(cookie)
+        .and_then(|millis| millis.trim().parse().ok())
+        .and_then(LastWrite::from_millis)
+}
@@ -1 +1,5 @@
+pub mod client_ip;
+pub mod conditional;
+pub mod consistency;
+pub mod rate_limit;
 pub mod validator;
@@ -0,0 +1,110 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use axum::{
+    extract::{Request, State},
+    middleware::Next,
+    response::Response,
+};
+use serde::Serialize;
+
+use crate::{
+    application::error::AppError,
+    presentation::{guards::UserInfo, middlewares::client_ip::ClientIp},
+};
+
+// past this many callers, the windows that are over get dropped
+const PRUNE_THRESHOLD: usize = 10_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct RateLimit {
+    pub requests: u32,
+    pub per_secs: u64,
+}
+
+impl RateLimit {
+    pub const fn per_minute(requests: u32) -> Self {
+        Self {
+            requests,
+            per_secs: 60,
+        }
+    }
+}
+
+/// Fixed windows counted per caller, that is per user behind `guards::auth` and per
+/// `ClientIp` otherwise.
+#[derive(Debug, Clone)]
+pub struct RateLimiter {
+    limit: RateLimit,
+    windows: Arc<Mutex<HashMap<String, (Instant, u32)>>>,
+}
+
+impl RateLimiter {
+    pub fn new(limit: RateLimit) -> Self {
+        Self {
+            limit,
+            windows: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    /// Counts a request of `caller`, or tells in how many seconds it may try again.
+    pub fn acquire(&self, caller: &str, now: Instant) -> Result<(), u64> {
+        let period = Duration::from_secs(self.limit.per_secs);
+        let mut windows = self.windows.lock().expect("rate limiter lock poisoned");
+
+        if windows.len() > PRUNE_THRESHOLD {
+            windows.retain(|_, (start, _)| now.duration_since(*start) < period);
+        }
+
+        let (start, count) = windows.entry(caller.to_string()).or_insert((now, 0));
+        if now.duration_since(*start) >= period {
+            *start = now;
+            *count = 0;
+        }
+
+        if *count >= self.limit.requests {
+            let elapsed = now.duration_since(*start);
+            return Err((period - elapsed).as_secs().max(1));
+        }
+
+        *count += 1;
+        Ok(())
+    }
+
+    /// Counts `req` as a request of `user`, or of its `ClientIp` when it is anonymous.
+    pub fn check(
+        &self,
+        req: &Request,
+        user: Option<&UserInfo>,
+        now: Instant,
+    ) -> Result<(), AppError> {
+        let caller = match user {
+            Some(user) => user.caller(),
+            // rather than one bucket that anybody could fill up for everyone else
+            None => match ClientIp::of(req) {
+                Some(ClientIp(ip)) => ip.to_string(),
+                None => {
+                    return Err(AppError::InternalError(anyhow::anyhow!(
+                        "the client address is unknown, the server must be served with connect info"
+                    )));
+                }
+            },
+        };
+
+        self.acquire(&caller, now)
+            .map_err(AppError::TooManyRequests)
+    }
+}
+
+pub async fn rate_limit(
+    State(limiter): State<RateLimiter>,
+    req: Request,
+    next: Next,
+) -> Result<Response, AppError> {
+    limiter.check(&req, req.extensions().get::<UserInfo>(), Instant::now())?;
+
+    Ok(next.run(req).await)
+}
@@ -1,8 +1,14 @@
+use std::{borrow::Cow, collections::HashMap};
+
 use axum::{
     Form, Json,
-    extract::{FromRequest, FromRequestParts, Path, Query, Request, rejection::FormRejection},
+    body::Bytes,
+    extract::{
+        FromRequest, FromRequestParts, Multipart, Path, Query, Request, rejection::FormRejection,
+    },
     http::request::Parts,
 };
+use schemars::{JsonSchema, Schema, SchemaGenerator, json_schema};
 use serde::de::DeserializeOwned;
 use validator::Validate;
 
@@ -169,3 +175,99 @@ where
         Ok(Self { q, b })
     }
 }
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct PathAndQueryParams<P, Q> {
+    pub p: P,
+    pub q: Q,
+}
+
+impl<P, Q, S> FromRequestParts<S> for PathAndQueryParams<P, Q>
+where
+    P: DeserializeOwned + Validate + Send,
+    Q: DeserializeOwned + Validate + Send,
+    S: Send + Sync,
+{
+    type Rejection = AppError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let Path(p) = Path::<P>::from_request_parts(parts, state).await?;
+        p.validate()?;
+
+        let Query(q) = Query::<Q>::from_request_parts(parts, state).await?;
+        q.validate()?;
+
+        Ok(Self { p, q })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct UploadedFile {
+    pub file_name: String,
+    pub content_type: Option<String>,
+    pub data: Bytes,
+}
+
+impl JsonSchema for UploadedFile {
+    fn inline_schema() -> bool {
+        true
+    }
+
+    fn schema_name() -> Cow<'static, str> {
+        "UploadedFile".into()
+    }
+
+    fn json_schema(_: &mut SchemaGenerator) -> Schema {
+        json_schema!({ "type": "string", "format": "binary" })
+    }
+}
+
+/// A `multipart/form-data` body read into memory, keyed by field name.
+#[derive(Debug, Clone, Default)]
+pub struct MultipartForm {
+    pub fields: HashMap<String, String>,
+    pub files: HashMap<String, UploadedFile>,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MultipartParams<T>(pub T);
+
+impl<T, S> FromRequest<S> for MultipartParams<T>
+where
+    T: TryFrom<MultipartForm, Error = AppError> + Validate,
+    S: Send + Sync,
+{
+    type Rejection = AppError;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let mut multipart = Multipart::from_request(req, state).await?;
+        let mut form = MultipartForm::default();
+
+        while let Some(field) = multipart.next_field().await? {
+            let name = field.name().unwrap_or_default().to_string();
+
+            match field.file_name().map(str::to_string) {
+                Some(file_name) => {
+                    let content_type = field.content_type().map(str::to_string);
+                    let data = field.bytes().await?;
+
+                    form.files.insert(
+                        name,
+                        UploadedFile {
+                            file_name,
+                            content_type,
+                            data,
+                        },
+                    );
+                }
+                None => {
+                    form.fields.insert(name, field.text().await?);
+                }
+            }
+        }
+
+        let value = T::try_from(form)?;
+        value.validate()?;
+        Ok(Self(value))
+    }
+}
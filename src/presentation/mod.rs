@@ -1,3 +1,5 @@
+pub mod graphql;
+pub mod grpc;
 pub mod guards;
 pub mod handlers;
 pub mod http;
@@ -1,30 +1,138 @@
-use axum::http::StatusCode;
+use axum::{
+    Json,
+    body::{Body, Bytes},
+    http::{
+        HeaderMap, HeaderValue, StatusCode,
+        header::{self, IntoHeaderName},
+    },
+    response::{IntoResponse, Response},
+};
+use cookie::Cookie;
+use futures_core::Stream;
 use serde::Serialize;
 
-pub struct CaseResponse<T: Serialize> {
+pub struct CaseResponse<T: IntoCaseBody> {
     pub status: StatusCode,
+    pub headers: HeaderMap,
     pub data: T,
 }
 
-impl<T: Serialize> CaseResponse<T> {
-    pub fn ok(data: T) -> Self {
+impl<T: IntoCaseBody> CaseResponse<T> {
+    pub fn new(status: StatusCode, data: T) -> Self {
         Self {
-            status: StatusCode::OK,
+            status,
+            headers: HeaderMap::new(),
             data,
         }
     }
 
+    pub fn ok(data: T) -> Self {
+        Self::new(StatusCode::OK, data)
+    }
+
     pub fn created(data: T) -> Self {
-        Self {
-            status: StatusCode::CREATED,
-            data,
-        }
+        Self::new(StatusCode::CREATED, data)
     }
 
     pub fn no_content() -> CaseResponse<()> {
-        CaseResponse {
-            status: StatusCode::NO_CONTENT,
-            data: (),
+        CaseResponse::new(StatusCode::NO_CONTENT, ())
+    }
+
+    pub fn with_header<K: IntoHeaderName>(mut self, key: K, value: HeaderValue) -> Self {
+        self.headers.insert(key, value);
+        self
+    }
+
+    /// Tags the response with the version of the resource it represents, so clients can
+    /// send it back in `If-Match` / `If-None-Match`.
+    pub fn with_etag(self, version: i32) -> Self {
+        self.with_header(header::ETAG, etag(version))
+    }
+
+    pub fn with_location(self, uri: &str) -> Self {
+        self.with_header(header::LOCATION, header_value(uri))
+    }
+
+    pub fn with_cache_control(self, directives: &str) -> Self {
+        self.with_header(header::CACHE_CONTROL, header_value(directives))
+    }
+
+    /// Asks the client to save the body as a file instead of rendering it.
+    pub fn with_attachment(self, filename: &str) -> Self {
+        let filename = filename.replace(['"', '\\'], "_");
+        self.with_header(
+            header::CONTENT_DISPOSITION,
+            header_value(&format!("attachment; filename=\"{}\"", filename)),
+        )
+    }
+
+    pub fn with_cookie(mut self, cookie: Cookie<'_>) -> Self {
+        self.headers
+            .append(header::SET_COOKIE, header_value(&cookie.to_string()));
+        self
+    }
+}
+
+impl<T: IntoCaseBody> IntoResponse for CaseResponse<T> {
+    fn into_response(self) -> Response {
+        (self.status, self.headers, self.data.into_case_body()).into_response()
+    }
+}
+
+/// Body of a use case result. Every `Serialize` output is rendered as JSON, while
+/// [`RawBody`] lets a use case hand back CSV, files or streams as they are.
+pub trait IntoCaseBody {
+    fn into_case_body(self) -> Response;
+}
+
+impl<T: Serialize> IntoCaseBody for T {
+    fn into_case_body(self) -> Response {
+        Json(self).into_response()
+    }
+}
+
+pub struct RawBody {
+    content_type: HeaderValue,
+    body: Body,
+}
+
+impl RawBody {
+    pub fn new(content_type: &str, body: impl Into<Body>) -> Self {
+        Self {
+            content_type: header_value(content_type),
+            body: body.into(),
         }
     }
+
+    pub fn stream<S>(content_type: &str, stream: S) -> Self
+    where
+        S: Stream<Item = Result<Bytes, axum::Error>> + Send + 'static,
+    {
+        Self::new(content_type, Body::from_stream(stream))
+    }
+
+    pub fn content_type(&self) -> &HeaderValue {
+        &self.content_type
+    }
+
+    pub fn into_body(self) -> Body {
+        self.body
+    }
+}
+
+impl IntoCaseBody for RawBody {
+    fn into_case_body(self) -> Response {
+        ([(header::CONTENT_TYPE, self.content_type)], self.body).into_response()
+    }
+}
+
+pub fn etag(version: i32) -> HeaderValue {
+    HeaderValue::from_str(&format!("\"{}\"", version)).expect("etag is always a valid header")
+}
+
+fn header_value(value: &str) -> HeaderValue {
+    HeaderValue::from_str(value).unwrap_or_else(|_| {
+        HeaderValue::from_str(&value.escape_default().to_string())
+            .expect("escaped value is a valid header")
+    })
 }
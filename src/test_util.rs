@@ -0,0 +1,286 @@
+//! Drives the whole application in-process, the way a client would see it.
+//!
+//! ```ignore
+//! let app = TestApp::new(MockDatabase::new(DatabaseBackend::Postgres).into_connection());
+//! let token = app.token(&["admin"]);
+//! app.get("/api/v1/companies").bearer(&token).send().await.assert_status(StatusCode::OK);
+//! ```
+
+use std::{
+    net::{IpAddr, Ipv4Addr, SocketAddr},
+    sync::Arc,
+};
+
+use axum::{
+    Router,
+    body::{Body, Bytes, to_bytes},
+    extract::ConnectInfo,
+    http::{HeaderMap, HeaderName, HeaderValue, Method, Request, StatusCode, header},
+};
+use migration::{Migrator, MigratorTrait};
+use sea_orm::{Database, DatabaseConnection};
+use serde::{Serialize, de::DeserializeOwned};
+use serde_json::Value;
+use tower::ServiceExt;
+use uuid::Uuid;
+
+use crate::{
+    config::AppConfig,
+    infrastructure::{
+        db::{DEFAULT_TENANT_ID, DbContext},
+        events::EventBus,
+        helpers::token::JwtHelper,
+    },
+    presentation::http::{AppState, HttpServer},
+};
+
+pub const TEST_SECRET: &str = "test-secret";
+
+/// The peer requests come from unless told otherwise.
+pub const TEST_PEER: SocketAddr = SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), 40000);
+
+/// An in-memory SQLite database with every migration applied, a fresh one on each call.
+pub async fn migrated_sqlite() -> DatabaseConnection {
+    let db = Database::connect("sqlite::memory:")
+        .await
+        .expect("failed to open an in-memory SQLite database");
+    Migrator::up(&db, None)
+        .await
+        .expect("failed to migrate the SQLite database");
+    db
+}
+
+pub struct TestApp {
+    pub state: AppState,
+    router: Router,
+}
+
+impl TestApp {
+    /// Builds the app against `migrated_sqlite`.
+    pub async fn sqlite() -> Self {
+        Self::new(migrated_sqlite().await)
+    }
+
+    /// Builds the app against `conn`, a `MockDatabase` connection or a migrated SQLite one.
+    pub fn new(conn: DatabaseConnection) -> Self {
+        Self::with_db_context(DbContext::new(Arc::new(conn)))
+    }
+
+    /// For a context carrying repository overrides, replicas and the like.
+    pub fn with_db_context(db_context: DbContext) -> Self {
+        Self::with_config(db_context, &Self::config())
+    }
+
+    pub fn with_config(db_context: DbContext, config: &AppConfig) -> Self {
+        let state = AppState {
+            db_context: Arc::new(db_context),
+            jwt_helper: Arc::new(JwtHelper::new(config.token_secret_key.clone())),
+            event_bus: Arc::new(EventBus::new(64)),
+        };
+        let router = HttpServer::router(state.clone(), config);
+
+        Self { state, router }
+    }
+
+    /// The defaults of `AppConfig::from_env`, with the docs UI on and nothing read from the environment.
+    pub fn config() -> AppConfig {
+        AppConfig {
+            server_port: "0".to_string(),
+            trusted_proxies: Vec::new(),
+            db_connect_str: String::new(),
+            token_secret_key: TEST_SECRET.to_string(),
+            soft_delete_retention_days: 30,
+            purge_interval_secs: 3600,
+            event_log_retention_days: 7,
+            row_level_security: false,
+            db_replica_connect_strs: Vec::new(),
+            read_your_writes_ms: 5000,
+            outbox_poll_interval_ms: 1000,
+            outbox_batch_size: 100,
+            outbox_max_attempts: 10,
+            outbox_webhook_url: None,
+            webhook_poll_interval_ms: 1000,
+            webhook_batch_size: 20,
+            webhook_max_attempts: 8,
+            webhook_private_destinations: false,
+            job_poll_interval_ms: 1000,
+            job_concurrency: 4,
+            job_lease_secs: 300,
+            openapi_ui: true,
+            graphql_max_depth: 8,
+            graphql_max_complexity: 250,
+            grpc_rate_limit: 600,
+        }
+    }
+
+    /// A token of the default tenant carrying `roles`.
+    pub fn token(&self, roles: &[&str]) -> String {
+        self.token_for("test-user", Some(DEFAULT_TENANT_ID), roles)
+    }
+
+    pub fn token_for(&self, sub: &str, tenant_id: Option<Uuid>, roles: &[&str]) -> String {
+        self.state
+            .jwt_helper
+            .generate_for_tenant(
+                sub.to_string(),
+                tenant_id,
+                roles.iter().map(|r| r.to_string()).collect(),
+            )
+            .expect("failed to sign the test token")
+    }
+
+    pub fn get(&self, uri: &str) -> TestRequest<'_> {
+        self.request(Method::GET, uri)
+    }
+
+    pub fn post(&self, uri: &str) -> TestRequest<'_> {
+        self.request(Method::POST, uri)
+    }
+
+    pub fn put(&self, uri: &str) -> TestRequest<'_> {
+        self.request(Method::PUT, uri)
+    }
+
+    pub fn patch(&self, uri: &str) -> TestRequest<'_> {
+        self.request(Method::PATCH, uri)
+    }
+
+    pub fn delete(&self, uri: &str) -> TestRequest<'_> {
+        self.request(Method::DELETE, uri)
+    }
+
+    pub fn request(&self, method: Method, uri: &str) -> TestRequest<'_> {
+        TestRequest {
+            app: self,
+            builder: Request::builder().method(method).uri(uri),
+            body: Body::empty(),
+        }
+    }
+
+    /// Sends a prepared request through a fresh clone of the router, from `TEST_PEER`
+    /// when it doesn't say where it comes from.
+    pub async fn oneshot(&self, mut req: Request<Body>) -> TestResponse {
+        if req.extensions().get::<ConnectInfo<SocketAddr>>().is_none() {
+            req.extensions_mut().insert(ConnectInfo(TEST_PEER));
+        }
+
+        let res = self
+            .router
+            .clone()
+            .oneshot(req)
+            .await
+            .expect("the router is infallible");
+        let status = res.status();
+        let headers = res.headers().clone();
+        let body = to_bytes(res.into_body(), usize::MAX)
+            .await
+            .expect("failed to read the response body");
+
+        TestResponse {
+            status,
+            headers,
+            body,
+        }
+    }
+}
+
+pub struct TestRequest<'a> {
+    app: &'a TestApp,
+    builder: axum::http::request::Builder,
+    body: Body,
+}
+
+impl TestRequest<'_> {
+    pub fn bearer(self, token: &str) -> Self {
+        self.header(header::AUTHORIZATION, &format!("Bearer {}", token))
+    }
+
+    pub fn header<K>(mut self, key: K, value: &str) -> Self
+    where
+        K: TryInto<HeaderName>,
+        K::Error: Into<axum::http::Error>,
+    {
+        self.builder = self.builder.header(
+            key,
+            HeaderValue::from_str(value).expect("invalid header value"),
+        );
+        self
+    }
+
+    pub fn json<T: Serialize>(mut self, body: &T) -> Self {
+        self.builder = self
+            .builder
+            .header(header::CONTENT_TYPE, "application/json");
+        self.body = Body::from(serde_json::to_vec(body).expect("failed to serialize the body"));
+        self
+    }
+
+    /// The address of the connection the request comes over.
+    pub fn peer(mut self, addr: SocketAddr) -> Self {
+        self.builder = self.builder.extension(ConnectInfo(addr));
+        self
+    }
+
+    /// A body sent as-is, e.g. malformed JSON; pair it with a content type header.
+    pub fn body(mut self, body: impl Into<Body>) -> Self {
+        self.body = body.into();
+        self
+    }
+
+    pub async fn send(self) -> TestResponse {
+        let req = self
+            .builder
+            .body(self.body)
+            .expect("failed to build the request");
+        self.app.oneshot(req).await
+    }
+}
+
+#[derive(Debug)]
+pub struct TestResponse {
+    pub status: StatusCode,
+    pub headers: HeaderMap,
+    pub body: Bytes,
+}
+
+impl TestResponse {
+    pub fn json<T: DeserializeOwned>(&self) -> T {
+        serde_json::from_slice(&self.body).unwrap_or_else(|e| {
+            panic!(
+                "response body is not the expected JSON ({}): {}",
+                e,
+                String::from_utf8_lossy(&self.body)
+            )
+        })
+    }
+
+    pub fn text(&self) -> String {
+        String::from_utf8_lossy(&self.body).into_owned()
+    }
+
+    #[track_caller]
+    pub fn assert_status(&self, status: StatusCode) -> &Self {
+        assert_eq!(
+            self.status,
+            status,
+            "unexpected status, body: {}",
+            self.text()
+        );
+        self
+    }
+
+    /// Checks the `AppError` envelope, `{"status_code": .., "data": {"code": .., "message": ..}}`,
+    /// and returns its message.
+    #[track_caller]
+    pub fn assert_error(&self, status: StatusCode, code: &str) -> String {
+        self.assert_status(status);
+        let body: Value = self.json();
+        assert_eq!(body["status_code"], status.as_u16(), "body: {}", body);
+        assert_eq!(body["data"]["code"], code, "body: {}", body);
+
+        body["data"]["message"]
+            .as_str()
+            .unwrap_or_else(|| panic!("error without a message: {}", body))
+            .to_string()
+    }
+}
@@ -0,0 +1,258 @@
+#[cfg(test)]
+mod add_company_test_suite {
+    use std::{collections::HashMap, sync::Arc, sync::Mutex};
+
+    use async_trait::async_trait;
+    use axum::http::StatusCode;
+    use chrono::{DateTime, Utc};
+    use lib::{
+        application::{
+            SecureCase, cases::company::AddCompanyUseCase, dtos::company::ReqAddCompanyDto,
+            error::AppError,
+        },
+        domain::{
+            error::DomainError,
+            events::{DomainEvent, OutboxMessage, repositories::OutboxRepository},
+            organization::{
+                Company, CompanyCriteria, CompanyId, CompanyName, repositories::CompanyRepository,
+            },
+        },
+        infrastructure::{
+            db::{DbContext, RepositoryOverrides},
+            events::EventBus,
+            helpers::token::JwtHelper,
+        },
+        presentation::{guards::UserInfo, http::AppState, middlewares::validator::JsonParams},
+    };
+    use sea_orm::{DatabaseBackend, DatabaseConnection, MockDatabase};
+    use uuid::Uuid;
+
+    /// What the fakes answer to a call adding a company never makes.
+    fn unexpected(call: &str) -> DomainError {
+        DomainError::Invalid(format!("{call} is not expected when adding a company"))
+    }
+
+    /// Companies kept in memory, names being unique as in the database.
+    #[derive(Default)]
+    struct FakeCompanies(Mutex<Vec<Company>>);
+
+    #[async_trait]
+    impl CompanyRepository for FakeCompanies {
+        async fn query(&self, _: &CompanyCriteria) -> Result<Vec<Company>, DomainError> {
+            Ok(self.0.lock().unwrap().clone())
+        }
+
+        async fn find(&self, id: CompanyId) -> Result<Option<Company>, DomainError> {
+            Ok(self.0.lock().unwrap().iter().find(|c| c.id == id).cloned())
+        }
+
+        async fn find_many(&self, ids: &[CompanyId]) -> Result<Vec<Company>, DomainError> {
+            let companies = self.0.lock().unwrap();
+            Ok(companies
+                .iter()
+                .filter(|c| ids.contains(&c.id))
+                .cloned()
+                .collect())
+        }
+
+        async fn exists(&self, id: CompanyId) -> Result<bool, DomainError> {
+            Ok(self.find(id).await?.is_some())
+        }
+
+        async fn find_ids_by_name(
+            &self,
+            names: &[String],
+        ) -> Result<HashMap<String, CompanyId>, DomainError> {
+            let companies = self.0.lock().unwrap();
+            Ok(companies
+                .iter()
+                .filter(|c| names.iter().any(|n| n == c.name.as_str()))
+                .map(|c| (c.name.to_string(), c.id))
+                .collect())
+        }
+
+        async fn add(&self, com: Company) -> Result<CompanyId, DomainError> {
+            let mut companies = self.0.lock().unwrap();
+            if companies.iter().any(|c| c.name == com.name) {
+                return Err(DomainError::Duplicated(format!("{} exists", com.name)));
+            }
+            let id = com.id;
+            companies.push(com);
+            Ok(id)
+        }
+
+        async fn add_many(&self, coms: Vec<Company>) -> Result<Vec<CompanyId>, DomainError> {
+            let mut ids = Vec::new();
+            for com in coms {
+                ids.push(self.add(com).await?);
+            }
+            Ok(ids)
+        }
+
+        async fn update(&self, _: CompanyId, _: i32, _: CompanyName) -> Result<i32, DomainError> {
+            Err(unexpected("update"))
+        }
+
+        async fn delete(&self, _: CompanyId, _: i32, _: DateTime<Utc>) -> Result<(), DomainError> {
+            Err(unexpected("delete"))
+        }
+
+        async fn query_deleted(&self) -> Result<Vec<Company>, DomainError> {
+            Err(unexpected("query_deleted"))
+        }
+
+        async fn restore(&self, _: CompanyId) -> Result<DateTime<Utc>, DomainError> {
+            Err(unexpected("restore"))
+        }
+
+        async fn purge(&self, _: DateTime<Utc>) -> Result<u64, DomainError> {
+            Err(unexpected("purge"))
+        }
+    }
+
+    #[derive(Default)]
+    struct FakeOutbox(Mutex<Vec<DomainEvent>>);
+
+    #[async_trait]
+    impl OutboxRepository for FakeOutbox {
+        async fn add(&self, event: DomainEvent) -> Result<(), DomainError> {
+            self.0.lock().unwrap().push(event);
+            Ok(())
+        }
+
+        async fn add_many(&self, events: Vec<DomainEvent>) -> Result<(), DomainError> {
+            self.0.lock().unwrap().extend(events);
+            Ok(())
+        }
+
+        async fn claim_due(
+            &self,
+            _: u64,
+            _: DateTime<Utc>,
+        ) -> Result<Vec<OutboxMessage>, DomainError> {
+            Err(unexpected("claim_due"))
+        }
+
+        async fn mark_published(&self, _: Uuid) -> Result<(), DomainError> {
+            Err(unexpected("mark_published"))
+        }
+
+        async fn mark_failed(
+            &self,
+            _: Uuid,
+            _: &str,
+            _: Option<DateTime<Utc>>,
+        ) -> Result<(), DomainError> {
+            Err(unexpected("mark_failed"))
+        }
+    }
+
+    struct Fixture {
+        db: Arc<DatabaseConnection>,
+        companies: Arc<FakeCompanies>,
+        outbox: Arc<FakeOutbox>,
+        use_case: AddCompanyUseCase,
+    }
+
+    fn fixture() -> Fixture {
+        let db = Arc::new(MockDatabase::new(DatabaseBackend::Postgres).into_connection());
+        let companies = Arc::new(FakeCompanies::default());
+        let outbox = Arc::new(FakeOutbox::default());
+
+        let overrides = RepositoryOverrides::default()
+            .with_company_repo(companies.clone())
+            .with_outbox_repo(outbox.clone());
+        let state = AppState {
+            db_context: Arc::new(DbContext::new(db.clone()).with_repository_overrides(overrides)),
+            jwt_helper: Arc::new(JwtHelper::new("secret".to_owned())),
+            event_bus: Arc::new(EventBus::new(8)),
+        };
+
+        Fixture {
+            db,
+            companies,
+            outbox,
+            use_case: AddCompanyUseCase::new(Arc::new(state)),
+        }
+    }
+
+    fn user() -> UserInfo {
+        UserInfo {
+            id: "logon-user".to_owned(),
+            roles: vec![],
+            tenant_id: Uuid::nil(),
+        }
+    }
+
+    fn dto(name: &str) -> JsonParams<ReqAddCompanyDto> {
+        JsonParams(ReqAddCompanyDto {
+            name: name.to_owned(),
+        })
+    }
+
+    #[tokio::test]
+    async fn add_company_through_injected_repositories() {
+        // Given
+        let Fixture {
+            db,
+            companies,
+            outbox,
+            use_case,
+        } = fixture();
+
+        // When
+        let result = use_case.execute(dto("Acme"), user()).await.unwrap();
+
+        // Then
+        assert_eq!(result.status, StatusCode::CREATED);
+
+        let added = companies.0.lock().unwrap().clone();
+        assert_eq!(added.len(), 1);
+        assert_eq!(added[0].id.to_string(), result.data);
+        assert_eq!(
+            *outbox.0.lock().unwrap(),
+            [DomainEvent::CompanyCreated {
+                id: added[0].id.into(),
+                name: "Acme".to_owned(),
+            }]
+        );
+
+        // only the transaction itself went to the database
+        let log: Vec<String> = db_log(db);
+        assert_eq!(log, ["BEGIN", "COMMIT"]);
+    }
+
+    #[tokio::test]
+    async fn roll_back_when_an_injected_repository_fails() {
+        // Given
+        let Fixture {
+            db,
+            companies,
+            outbox,
+            use_case,
+        } = fixture();
+        companies
+            .add(Company::new(CompanyName::new("Acme").unwrap()))
+            .await
+            .unwrap();
+
+        // When
+        let result = use_case.execute(dto("Acme"), user()).await;
+
+        // Then
+        assert!(matches!(
+            result,
+            Err(AppError::Domain(DomainError::Duplicated(_)))
+        ));
+        assert!(outbox.0.lock().unwrap().is_empty());
+        assert_eq!(db_log(db), ["BEGIN", "ROLLBACK"]);
+    }
+
+    fn db_log(db: Arc<DatabaseConnection>) -> Vec<String> {
+        let db = Arc::try_unwrap(db).expect("the use case still holds the connection");
+        db.into_transaction_log()
+            .iter()
+            .flat_map(|t| t.statements().iter().map(|s| s.sql.clone()))
+            .collect()
+    }
+}
@@ -0,0 +1,130 @@
+#[cfg(test)]
+mod batch_company_test_suite {
+    use std::sync::Arc;
+
+    use axum::http::StatusCode;
+    use chrono::Utc;
+    use lib::{
+        application::{
+            SecureCase, cases::company::BatchCompanyUseCase, dtos::company::ReqBatchCompanyDto,
+        },
+        infrastructure::{
+            db::{DbContext, entities::companies},
+            events::EventBus,
+            helpers::token::JwtHelper,
+        },
+        presentation::{guards::UserInfo, http::AppState, middlewares::validator::JsonParams},
+    };
+    use sea_orm::{DatabaseBackend, DatabaseConnection, MockDatabase, MockExecResult};
+    use serde_json::json;
+    use uuid::Uuid;
+
+    fn use_case(db: Arc<DatabaseConnection>) -> BatchCompanyUseCase {
+        let state = AppState {
+            db_context: Arc::new(DbContext::new(db)),
+            jwt_helper: Arc::new(JwtHelper::new("secret".to_owned())),
+            event_bus: Arc::new(EventBus::new(8)),
+        };
+
+        BatchCompanyUseCase::new(Arc::new(state))
+    }
+
+    fn user() -> UserInfo {
+        UserInfo {
+            id: "logon-user".to_owned(),
+            roles: vec![],
+            tenant_id: Uuid::nil(),
+        }
+    }
+
+    fn company(name: &str) -> companies::Model {
+        companies::Model {
+            id: Uuid::new_v4(),
+            name: name.to_owned(),
+            version: 1,
+            deleted_at: None,
+            created_at: Utc::now().fixed_offset(),
+            updated_at: Utc::now().fixed_offset(),
+            created_by: None,
+            updated_by: None,
+            tenant_id: Uuid::nil(),
+        }
+    }
+
+    #[tokio::test]
+    async fn reject_atomic_batch_with_an_invalid_operation() {
+        // Given
+        let db = MockDatabase::new(DatabaseBackend::Postgres).into_connection();
+        let dto: ReqBatchCompanyDto = serde_json::from_value(json!({
+            "operations": [
+                { "op": "create", "name": "Acme" },
+                { "op": "update", "id": Uuid::new_v4(), "version": 1, "name": "" },
+            ]
+        }))
+        .unwrap();
+
+        // When
+        let res = use_case(Arc::new(db))
+            .execute(JsonParams(dto), user())
+            .await
+            .unwrap();
+
+        // Then
+        assert_eq!(res.status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert!(!res.data.committed);
+        assert_eq!(res.data.results[0].status, 424);
+        assert_eq!(res.data.results[1].status, 400);
+    }
+
+    #[tokio::test]
+    async fn apply_per_item_batch_with_a_single_insert() {
+        // Given
+        let db = Arc::new(
+            MockDatabase::new(DatabaseBackend::Postgres)
+                .append_query_results([vec![company("Acme"), company("Globex")]])
+                .append_query_results([Vec::<companies::Model>::new()])
+                .append_exec_results([
+                    MockExecResult {
+                        last_insert_id: 0,
+                        rows_affected: 2,
+                    },
+                    MockExecResult {
+                        last_insert_id: 0,
+                        rows_affected: 2,
+                    },
+                ])
+                .into_connection(),
+        );
+        let dto: ReqBatchCompanyDto = serde_json::from_value(json!({
+            "mode": "per_item",
+            "operations": [
+                { "op": "create", "name": "Acme" },
+                { "op": "create", "name": "Globex" },
+                { "op": "delete", "id": Uuid::new_v4(), "version": 1 },
+            ]
+        }))
+        .unwrap();
+
+        // When
+        let res = use_case(db.clone())
+            .execute(JsonParams(dto), user())
+            .await
+            .unwrap();
+
+        // Then
+        assert_eq!(res.status, StatusCode::OK);
+        assert!(res.data.committed);
+        let statuses: Vec<u16> = res.data.results.iter().map(|r| r.status).collect();
+        assert_eq!(statuses, [201, 201, 404]);
+
+        let log = Arc::try_unwrap(db).unwrap().into_transaction_log();
+        let inserts: Vec<String> = log
+            .iter()
+            .flat_map(|t| t.statements())
+            .map(|s| s.to_string())
+            .filter(|s| s.starts_with(r#"INSERT INTO "companies""#))
+            .collect();
+        assert_eq!(inserts.len(), 1);
+        assert!(inserts[0].contains("'Acme'") && inserts[0].contains("'Globex'"));
+    }
+}
@@ -1 +1,3 @@
+mod add_company;
+mod batch_company;
 mod query_company;
\ No newline at end of file
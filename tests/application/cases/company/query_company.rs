@@ -3,12 +3,14 @@ mod query_company_test_suite {
     use std::sync::Arc;
 
     use axum::http::StatusCode;
+    use chrono::Utc;
     use lib::{
         application::{
             SecureCase, cases::company::QueryCompanyUseCase, dtos::company::ReqQueryCompanyDto,
         },
         infrastructure::{
             db::{DbContext, entities::companies},
+            events::EventBus,
             helpers::token::JwtHelper,
         },
         presentation::{guards::UserInfo, http::AppState, middlewares::validator::QueryParams},
@@ -24,10 +26,24 @@ mod query_company_test_suite {
                 companies::Model {
                     id: Uuid::new_v4(),
                     name: "test-1".to_owned(),
+                    version: 1,
+                    deleted_at: None,
+                    created_at: Utc::now().fixed_offset(),
+                    updated_at: Utc::now().fixed_offset(),
+                    created_by: None,
+                    updated_by: None,
+                    tenant_id: Uuid::nil(),
                 },
                 companies::Model {
                     id: Uuid::new_v4(),
                     name: "test-2".to_owned(),
+                    version: 1,
+                    deleted_at: None,
+                    created_at: Utc::now().fixed_offset(),
+                    updated_at: Utc::now().fixed_offset(),
+                    created_by: None,
+                    updated_by: None,
+                    tenant_id: Uuid::nil(),
                 },
             ]])
             .into_connection();
@@ -35,15 +51,18 @@ mod query_company_test_suite {
         let state = AppState {
             db_context: Arc::new(DbContext::new(Arc::new(db))),
             jwt_helper: Arc::new(JwtHelper::new("secret".to_owned())),
+            event_bus: Arc::new(EventBus::new(8)),
         };
 
         let uc = QueryCompanyUseCase::new(Arc::new(state));
 
         let user = UserInfo {
             id: "logon-user".to_owned(),
+            roles: vec!["admin".to_owned()],
+            tenant_id: Uuid::nil(),
         };
 
-        let dto = ReqQueryCompanyDto { name: None };
+        let dto = ReqQueryCompanyDto::default();
 
         // When
         let result = uc.execute(QueryParams(dto), user).await;
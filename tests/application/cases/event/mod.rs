@@ -0,0 +1 @@
+mod stream_events;
\ No newline at end of file
@@ -0,0 +1,221 @@
+#[cfg(test)]
+mod stream_events_test_suite {
+    use std::{sync::Arc, time::Duration};
+
+    use axum::{
+        body::Bytes,
+        http::{HeaderMap, HeaderValue},
+    };
+    use chrono::Utc;
+    use futures_util::{Stream, StreamExt};
+    use lib::{
+        application::{
+            SecureCase,
+            cases::event::{LAST_EVENT_ID_HEADER, StreamEventsUseCase},
+            dtos::event::ReqEventFeedDto,
+            error::AppError,
+        },
+        domain::events::OutboxMessage,
+        infrastructure::{
+            db::{DbContext, entities::event_log},
+            events::{EventBus, EventSink},
+            helpers::token::JwtHelper,
+        },
+        presentation::{guards::UserInfo, http::AppState, middlewares::validator::QueryParams},
+    };
+    use sea_orm::{DatabaseBackend, DatabaseConnection, MockDatabase};
+    use serde_json::json;
+    use uuid::Uuid;
+
+    fn state(db: DatabaseConnection) -> (Arc<AppState>, Arc<DatabaseConnection>) {
+        let db = Arc::new(db);
+        let state = AppState {
+            db_context: Arc::new(DbContext::new(db.clone())),
+            jwt_helper: Arc::new(JwtHelper::new("secret".to_owned())),
+            event_bus: Arc::new(EventBus::new(8)),
+        };
+
+        (Arc::new(state), db)
+    }
+
+    fn user(roles: &[&str]) -> UserInfo {
+        UserInfo {
+            id: "logon-user".to_owned(),
+            roles: roles.iter().map(|r| r.to_string()).collect(),
+            tenant_id: Uuid::nil(),
+        }
+    }
+
+    fn message(event_type: &str) -> OutboxMessage {
+        OutboxMessage {
+            id: Uuid::new_v4(),
+            tenant_id: Some(Uuid::nil()),
+            event_type: event_type.to_owned(),
+            aggregate_id: Uuid::new_v4(),
+            payload: json!({}),
+            attempts: 0,
+            created_at: Utc::now().fixed_offset(),
+        }
+    }
+
+    fn entry(seq: i64, event_type: &str) -> event_log::Model {
+        event_log::Model {
+            seq,
+            message_id: Uuid::new_v4(),
+            tenant_id: Some(Uuid::nil()),
+            event_type: event_type.to_owned(),
+            aggregate_id: Uuid::new_v4(),
+            payload: json!({ "type": event_type, "data": {} }),
+            created_at: Utc::now().fixed_offset(),
+        }
+    }
+
+    async fn next_frame(
+        stream: &mut (impl Stream<Item = Result<Bytes, axum::Error>> + Unpin),
+    ) -> String {
+        let frame = stream.next().await.unwrap().unwrap();
+        String::from_utf8(frame.to_vec()).unwrap()
+    }
+
+    #[tokio::test]
+    async fn resume_after_the_last_event_id() {
+        // Given
+        let (state, db) = state(
+            MockDatabase::new(DatabaseBackend::Postgres)
+                .append_query_results([vec![
+                    entry(5, "company.created"),
+                    entry(6, "department.deleted"),
+                ]])
+                .into_connection(),
+        );
+        let mut headers = HeaderMap::new();
+        headers.insert(LAST_EVENT_ID_HEADER, HeaderValue::from_static("4"));
+
+        // When
+        let res = StreamEventsUseCase::new(state)
+            .execute(
+                (headers, QueryParams(ReqEventFeedDto::default())),
+                user(&["admin"]),
+            )
+            .await
+            .unwrap();
+
+        // Then
+        assert_eq!(res.data.content_type(), "text/event-stream");
+        let mut stream = res.data.into_body().into_data_stream();
+
+        let first = next_frame(&mut stream).await;
+        assert!(
+            first.starts_with("id: 5\nevent: company.created\ndata: {"),
+            "{}",
+            first
+        );
+        assert!(first.ends_with("}\n\n"));
+        let second = next_frame(&mut stream).await;
+        assert!(
+            second.starts_with("id: 6\nevent: department.deleted\n"),
+            "{}",
+            second
+        );
+
+        drop(stream);
+        let log = Arc::try_unwrap(db).unwrap().into_transaction_log();
+        let query = log[0].statements()[0].to_string();
+        assert!(query.contains(r#""event_log"."seq" > 4"#), "{}", query);
+        assert!(
+            query.contains(r#""event_log"."tenant_id" = '00000000-0000-0000-0000-000000000000'"#)
+        );
+    }
+
+    #[tokio::test]
+    async fn push_what_gets_published_from_now_on() {
+        // Given
+        let (state, _db) = state(
+            MockDatabase::new(DatabaseBackend::Postgres)
+                .append_query_results([vec![entry(7, "company.created")]])
+                .append_query_results([Vec::<event_log::Model>::new()])
+                .append_query_results([vec![entry(8, "company.updated")]])
+                .into_connection(),
+        );
+        let bus = state.event_bus.clone();
+
+        let res = StreamEventsUseCase::new(state)
+            .execute(
+                (HeaderMap::new(), QueryParams(ReqEventFeedDto::default())),
+                user(&["admin"]),
+            )
+            .await
+            .unwrap();
+        let mut stream = res.data.into_body().into_data_stream();
+        assert_eq!(next_frame(&mut stream).await, ": keep-alive\n\n");
+
+        // When
+        bus.publish(&message("company.updated")).await.unwrap();
+
+        // Then
+        assert!(
+            next_frame(&mut stream)
+                .await
+                .starts_with("id: 8\nevent: company.updated\n")
+        );
+    }
+
+    #[tokio::test]
+    async fn skip_company_events_for_other_users() {
+        // Given
+        let (state, db) = state(
+            MockDatabase::new(DatabaseBackend::Postgres)
+                .append_query_results([vec![
+                    entry(5, "company.created"),
+                    entry(6, "department.created"),
+                    entry(7, "company.updated"),
+                ]])
+                .into_connection(),
+        );
+        let bus = state.event_bus.clone();
+        let mut headers = HeaderMap::new();
+        headers.insert(LAST_EVENT_ID_HEADER, HeaderValue::from_static("4"));
+
+        // When
+        let res = StreamEventsUseCase::new(state)
+            .execute(
+                (headers, QueryParams(ReqEventFeedDto::default())),
+                user(&[]),
+            )
+            .await
+            .unwrap();
+        let mut stream = res.data.into_body().into_data_stream();
+
+        // Then
+        assert!(
+            next_frame(&mut stream)
+                .await
+                .starts_with("id: 6\nevent: department.created\n")
+        );
+
+        // the feed isn't woken up to read the log again
+        bus.publish(&message("company.deleted")).await.unwrap();
+        let next = tokio::time::timeout(Duration::from_millis(100), stream.next()).await;
+        assert!(next.is_err());
+
+        drop(stream);
+        let log = Arc::try_unwrap(db).unwrap().into_transaction_log();
+        assert_eq!(log.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn reject_a_malformed_last_event_id() {
+        let (state, _db) = state(MockDatabase::new(DatabaseBackend::Postgres).into_connection());
+        let mut headers = HeaderMap::new();
+        headers.insert(LAST_EVENT_ID_HEADER, HeaderValue::from_static("abc"));
+
+        let result = StreamEventsUseCase::new(state)
+            .execute(
+                (headers, QueryParams(ReqEventFeedDto::default())),
+                user(&["admin"]),
+            )
+            .await;
+
+        assert!(matches!(result, Err(AppError::Domain(_))));
+    }
+}
@@ -1 +1,3 @@
-mod company;
\ No newline at end of file
+mod company;
+mod event;
+mod organization;
\ No newline at end of file
@@ -0,0 +1,110 @@
+#[cfg(test)]
+mod export_companies_test_suite {
+    use std::{collections::BTreeMap, sync::Arc, time::Duration};
+
+    use axum::{
+        body::to_bytes,
+        http::{HeaderMap, HeaderValue, StatusCode, header},
+        response::IntoResponse,
+    };
+    use chrono::DateTime;
+    use lib::{
+        application::{
+            SecureCase,
+            cases::organization::ExportCompaniesUseCase,
+            dtos::{company::ReqQueryCompanyDto, organization::ReqExportOrganizationDto},
+        },
+        infrastructure::{db::DbContext, events::EventBus, helpers::token::JwtHelper},
+        presentation::{guards::UserInfo, http::AppState, middlewares::validator::QueryParams},
+    };
+    use sea_orm::{DatabaseBackend, MockDatabase, Value};
+    use uuid::Uuid;
+
+    fn user() -> UserInfo {
+        UserInfo {
+            id: "logon-user".to_owned(),
+            roles: vec![],
+            tenant_id: Uuid::nil(),
+        }
+    }
+
+    fn row(name: &str) -> BTreeMap<&'static str, Value> {
+        let at = DateTime::parse_from_rfc3339("2025-12-01T09:30:00+00:00").unwrap();
+        BTreeMap::from([
+            ("id", Value::from(Uuid::nil())),
+            ("name", Value::from(name)),
+            ("department_count", Value::from(3i64)),
+            ("created_at", Value::from(at)),
+            ("updated_at", Value::from(at)),
+        ])
+    }
+
+    fn use_case(rows: Vec<BTreeMap<&'static str, Value>>) -> ExportCompaniesUseCase {
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results([rows])
+            .into_connection();
+        let state = AppState {
+            db_context: Arc::new(DbContext::new(Arc::new(db))),
+            jwt_helper: Arc::new(JwtHelper::new("secret".to_owned())),
+            event_bus: Arc::new(EventBus::new(8)),
+        };
+
+        ExportCompaniesUseCase::new(Arc::new(state))
+    }
+
+    fn ndjson() -> (HeaderMap, QueryParams<ReqExportOrganizationDto>) {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            header::ACCEPT,
+            HeaderValue::from_static("application/x-ndjson"),
+        );
+        let dto = ReqExportOrganizationDto {
+            filter: ReqQueryCompanyDto::default(),
+            format: None,
+        };
+
+        (headers, QueryParams(dto))
+    }
+
+    #[tokio::test]
+    async fn stream_companies_as_ndjson_attachment() {
+        // When
+        let res = use_case(vec![row("Acme")])
+            .execute(ndjson(), user())
+            .await
+            .unwrap()
+            .into_response();
+
+        // Then
+        assert_eq!(res.status(), StatusCode::OK);
+        assert_eq!(res.headers()[header::CONTENT_TYPE], "application/x-ndjson");
+        let disposition = res.headers()[header::CONTENT_DISPOSITION].to_str().unwrap();
+        assert!(disposition.starts_with("attachment; filename=\"companies-"));
+        assert!(disposition.ends_with(".ndjson\""));
+
+        let body = to_bytes(res.into_body(), usize::MAX).await.unwrap();
+        let line: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(line["name"], "Acme");
+        assert_eq!(line["department_count"], 3);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn give_up_on_a_client_that_stopped_reading() {
+        // Given, rows filling a lot more chunks than are buffered
+        let name = "a".repeat(10_000);
+        let rows = (0..100).map(|_| row(&name)).collect();
+
+        // When
+        let res = use_case(rows)
+            .execute(ndjson(), user())
+            .await
+            .unwrap()
+            .into_response();
+        tokio::time::sleep(Duration::from_secs(120)).await;
+
+        // Then, only what was buffered before the export ended
+        let body = to_bytes(res.into_body(), usize::MAX).await.unwrap();
+        let lines = body.iter().filter(|b| **b == b'\n').count();
+        assert!(lines > 0 && lines < 100, "{}", lines);
+    }
+}
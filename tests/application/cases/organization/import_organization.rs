@@ -0,0 +1,150 @@
+#[cfg(test)]
+mod import_organization_test_suite {
+    use std::sync::Arc;
+
+    use axum::{body::Bytes, http::StatusCode};
+    use chrono::Utc;
+    use lib::{
+        application::{
+            SecureCase,
+            cases::organization::ImportOrganizationUseCase,
+            dtos::organization::{ImportMode, ReqImportOrganizationDto},
+        },
+        infrastructure::{
+            db::{
+                DbContext,
+                entities::{companies, departments},
+            },
+            events::EventBus,
+            helpers::token::JwtHelper,
+        },
+        presentation::{
+            guards::UserInfo,
+            http::AppState,
+            middlewares::validator::{MultipartParams, UploadedFile},
+        },
+    };
+    use sea_orm::{DatabaseBackend, DatabaseConnection, MockDatabase, MockExecResult};
+    use uuid::Uuid;
+
+    const COMPANIES: &str = "name\nAcme\n\nAcme\n";
+    const DEPARTMENTS: &str = "Name,Company\nSales,Acme\nOps,Unknown\n";
+
+    fn file(file_name: &str, content: &'static str) -> Option<UploadedFile> {
+        Some(UploadedFile {
+            file_name: file_name.to_owned(),
+            content_type: Some("text/csv".to_owned()),
+            data: Bytes::from_static(content.as_bytes()),
+        })
+    }
+
+    fn use_case(db: DatabaseConnection) -> ImportOrganizationUseCase {
+        let state = AppState {
+            db_context: Arc::new(DbContext::new(Arc::new(db))),
+            jwt_helper: Arc::new(JwtHelper::new("secret".to_owned())),
+            event_bus: Arc::new(EventBus::new(8)),
+        };
+
+        ImportOrganizationUseCase::new(Arc::new(state))
+    }
+
+    fn user() -> UserInfo {
+        UserInfo {
+            id: "logon-user".to_owned(),
+            roles: vec![],
+            tenant_id: Uuid::nil(),
+        }
+    }
+
+    fn dto(mode: ImportMode) -> ReqImportOrganizationDto {
+        ReqImportOrganizationDto {
+            mode,
+            companies: file("companies.csv", COMPANIES),
+            departments: file("departments.csv", DEPARTMENTS),
+        }
+    }
+
+    #[tokio::test]
+    async fn reject_everything_when_a_row_is_invalid() {
+        // Given
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results([Vec::<companies::Model>::new()])
+            .append_query_results([Vec::<departments::Model>::new()])
+            .into_connection();
+
+        // When
+        let res = use_case(db)
+            .execute(MultipartParams(dto(ImportMode::AllOrNothing)), user())
+            .await
+            .unwrap();
+
+        // Then
+        assert_eq!(res.status, StatusCode::UNPROCESSABLE_ENTITY);
+        assert!(!res.data.committed);
+        assert_eq!(res.data.companies.created, 0);
+        assert_eq!(res.data.companies.failed, 1);
+        assert_eq!(res.data.departments.failed, 1);
+
+        let rows: Vec<(&str, usize)> = res
+            .data
+            .errors
+            .iter()
+            .map(|e| (e.file.as_str(), e.row))
+            .collect();
+        assert_eq!(rows, vec![("companies", 4), ("departments", 3)]);
+    }
+
+    #[tokio::test]
+    async fn import_valid_rows_in_best_effort() {
+        // Given
+        let now = Utc::now().fixed_offset();
+        let company_id = Uuid::new_v4();
+        let company = companies::Model {
+            id: company_id,
+            name: "Acme".to_owned(),
+            version: 1,
+            deleted_at: None,
+            created_at: now,
+            updated_at: now,
+            created_by: None,
+            updated_by: None,
+            tenant_id: Uuid::nil(),
+        };
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results([Vec::<companies::Model>::new()])
+            .append_query_results([Vec::<departments::Model>::new()])
+            .append_query_results([vec![company.clone()]])
+            // the company of the department is checked as it is for a single one
+            .append_query_results([vec![company]])
+            .append_query_results([vec![departments::Model {
+                id: Uuid::new_v4(),
+                name: "Sales".to_owned(),
+                company_id,
+                version: 1,
+                deleted_at: None,
+                created_at: now,
+                updated_at: now,
+                created_by: None,
+                updated_by: None,
+                tenant_id: Uuid::nil(),
+            }]])
+            .append_exec_results((0..4).map(|_| MockExecResult {
+                last_insert_id: 0,
+                rows_affected: 1,
+            }))
+            .into_connection();
+
+        // When
+        let res = use_case(db)
+            .execute(MultipartParams(dto(ImportMode::BestEffort)), user())
+            .await
+            .unwrap();
+
+        // Then
+        assert_eq!(res.status, StatusCode::OK);
+        assert!(res.data.committed);
+        assert_eq!(res.data.companies.created, 1);
+        assert_eq!(res.data.departments.created, 1);
+        assert_eq!(res.data.errors.len(), 2);
+    }
+}
@@ -0,0 +1,3 @@
+mod export_companies;
+mod import_organization;
+mod search_organization;
\ No newline at end of file
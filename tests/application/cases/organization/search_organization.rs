@@ -0,0 +1,153 @@
+#[cfg(test)]
+mod search_organization_test_suite {
+    use std::{collections::BTreeMap, sync::Arc};
+
+    use axum::http::StatusCode;
+    use lib::{
+        application::{
+            SecureCase,
+            cases::organization::SearchOrganizationUseCase,
+            dtos::organization::{ReqSearchOrganizationDto, SearchKindDto},
+        },
+        infrastructure::{db::DbContext, events::EventBus, helpers::token::JwtHelper},
+        presentation::{guards::UserInfo, http::AppState, middlewares::validator::QueryParams},
+    };
+    use sea_orm::{DatabaseBackend, DatabaseConnection, MockDatabase, Value};
+    use uuid::Uuid;
+
+    fn use_case(db: Arc<DatabaseConnection>) -> SearchOrganizationUseCase {
+        let state = AppState {
+            db_context: Arc::new(DbContext::new(db)),
+            jwt_helper: Arc::new(JwtHelper::new("secret".to_owned())),
+            event_bus: Arc::new(EventBus::new(8)),
+        };
+
+        SearchOrganizationUseCase::new(Arc::new(state))
+    }
+
+    fn user(roles: &[&str]) -> UserInfo {
+        UserInfo {
+            id: "logon-user".to_owned(),
+            roles: roles.iter().map(|r| r.to_string()).collect(),
+            tenant_id: Uuid::nil(),
+        }
+    }
+
+    fn row(name: &str, company: Option<&str>, score: f64) -> BTreeMap<&'static str, Value> {
+        marked_row(name, &format!("\u{E000}{}\u{E001}", name), company, score)
+    }
+
+    /// A row as found on Postgres, `highlight` coming from `ts_headline`.
+    fn marked_row(
+        name: &str,
+        highlight: &str,
+        company: Option<&str>,
+        score: f64,
+    ) -> BTreeMap<&'static str, Value> {
+        BTreeMap::from([
+            ("id", Value::from(Uuid::new_v4())),
+            ("name", Value::from(name)),
+            ("company_id", Value::from(company.map(|_| Uuid::new_v4()))),
+            ("company_name", Value::from(company.map(str::to_owned))),
+            ("score", Value::from(score)),
+            ("highlight", Value::from(highlight)),
+        ])
+    }
+
+    fn dto(q: &str) -> ReqSearchOrganizationDto {
+        ReqSearchOrganizationDto {
+            q: q.to_owned(),
+            limit: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn rank_companies_and_departments_together_for_admins() {
+        // Given
+        let db = Arc::new(
+            MockDatabase::new(DatabaseBackend::Postgres)
+                .append_query_results([vec![row("Acme", None, 0.4)]])
+                .append_query_results([vec![row("Acme Sales", Some("Acme"), 0.9)]])
+                .into_connection(),
+        );
+
+        // When
+        let res = use_case(db.clone())
+            .execute(QueryParams(dto(" acme ")), user(&["admin"]))
+            .await
+            .unwrap();
+
+        // Then
+        assert_eq!(res.status, StatusCode::OK);
+        let kinds: Vec<SearchKindDto> = res.data.iter().map(|h| h.kind).collect();
+        assert_eq!(kinds, [SearchKindDto::Department, SearchKindDto::Company]);
+        assert_eq!(res.data[0].company_name.as_deref(), Some("Acme"));
+
+        let log = Arc::try_unwrap(db).unwrap().into_transaction_log();
+        let sql = log[0].statements()[0].to_string();
+        assert!(sql.contains(
+            r#""companies"."search_vector" @@ websearch_to_tsquery('simple', 'acme') OR "companies"."name" % 'acme'"#
+        ));
+        assert!(
+            sql.contains(r#""companies"."tenant_id" = '00000000-0000-0000-0000-000000000000'"#)
+        );
+    }
+
+    #[tokio::test]
+    async fn search_only_departments_for_other_users() {
+        // Given
+        let db = Arc::new(
+            MockDatabase::new(DatabaseBackend::Postgres)
+                .append_query_results([vec![row("Sales", Some("Acme"), 0.5)]])
+                .into_connection(),
+        );
+
+        // When
+        let res = use_case(db.clone())
+            .execute(QueryParams(dto("sales")), user(&[]))
+            .await
+            .unwrap();
+
+        // Then
+        assert_eq!(res.data.len(), 1);
+        assert_eq!(res.data[0].kind, SearchKindDto::Department);
+
+        let log = Arc::try_unwrap(db).unwrap().into_transaction_log();
+        assert_eq!(log.len(), 1);
+        assert!(
+            log[0].statements()[0]
+                .to_string()
+                .contains(r#"FROM "departments""#)
+        );
+    }
+
+    #[tokio::test]
+    async fn escape_the_names_around_the_marks() {
+        // Given
+        let name = "<script>alert(1)</script> Acme";
+        let db = Arc::new(
+            MockDatabase::new(DatabaseBackend::Postgres)
+                .append_query_results([vec![marked_row(
+                    name,
+                    "<script>alert(1)</script> \u{E000}Acme\u{E001}",
+                    None,
+                    0.5,
+                )]])
+                .append_query_results([Vec::<BTreeMap<&str, Value>>::new()])
+                .into_connection(),
+        );
+
+        // When
+        let res = use_case(db)
+            .execute(QueryParams(dto("acme")), user(&["admin"]))
+            .await
+            .unwrap();
+
+        // Then
+        assert_eq!(res.data[0].name, name);
+        assert_eq!(
+            res.data[0].highlight,
+            "&lt;script&gt;alert(1)&lt;/script&gt; <mark>Acme</mark>"
+        );
+    }
+}
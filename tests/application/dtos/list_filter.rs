@@ -0,0 +1,50 @@
+#[cfg(test)]
+mod list_filter_test_suite {
+    use axum::{extract::Query, http::Uri};
+    use lib::application::dtos::{
+        company::ReqQueryCompanyDto,
+        filter::{FilterOp, FilterValue},
+    };
+    use uuid::Uuid;
+    use validator::Validate;
+
+    fn parse(query: &str) -> ReqQueryCompanyDto {
+        let uri: Uri = format!("/companies?{}", query).parse().unwrap();
+        Query::<ReqQueryCompanyDto>::try_from_uri(&uri).unwrap().0
+    }
+
+    #[test]
+    fn parse_filters_and_leave_other_params() {
+        let (a, b) = (Uuid::from_u128(1), Uuid::from_u128(2));
+
+        let dto = parse(&format!(
+            "name=acme&filter[id][in]={},{}&filter[name]=Acme",
+            a, b
+        ));
+
+        assert!(dto.validate().is_ok());
+        assert_eq!(dto.name.as_deref(), Some("acme"));
+        assert_eq!(dto.filter.clauses.len(), 2);
+        assert_eq!(dto.filter.clauses[0].field, "id");
+        assert_eq!(dto.filter.clauses[0].op, FilterOp::In);
+        assert_eq!(
+            dto.filter.clauses[0].values,
+            [FilterValue::Uuid(a), FilterValue::Uuid(b)]
+        );
+        assert_eq!(dto.filter.clauses[1].op, FilterOp::Eq);
+    }
+
+    #[test]
+    fn report_unknown_fields_operators_and_values() {
+        let dto = parse(
+            "filter[tenant_id]=x&filter[name][gte]=a&filter[version][between]=1&filter[created_at][lt]=yesterday",
+        );
+
+        let errors = dto.validate().unwrap_err().to_string();
+
+        assert!(errors.contains("filter[tenant_id]: unknown field"));
+        assert!(errors.contains("filter[name][gte]: name can't be filtered with gte."));
+        assert!(errors.contains("filter[version][between]"));
+        assert!(errors.contains("filter[created_at][lt]: yesterday is not a DateTime value."));
+    }
+}
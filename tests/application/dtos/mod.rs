@@ -0,0 +1,2 @@
+mod list_filter;
+mod webhook;
\ No newline at end of file
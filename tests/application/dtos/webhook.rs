@@ -0,0 +1,30 @@
+#[cfg(test)]
+mod webhook_dto_test_suite {
+    use lib::application::dtos::webhook::ReqAddWebhookDto;
+    use validator::Validate;
+
+    fn hook(url: &str) -> ReqAddWebhookDto {
+        ReqAddWebhookDto {
+            url: url.to_string(),
+            events: vec!["*".to_string()],
+            secret: None,
+        }
+    }
+
+    #[test]
+    fn only_http_urls_are_accepted() {
+        assert!(hook("https://example.com/hooks").validate().is_ok());
+        assert!(hook("http://example.com:8080/hooks").validate().is_ok());
+
+        for url in [
+            "file:///etc/passwd",
+            "gopher://example.com/",
+            "ftp://example.com/",
+            "data:text/plain,hello",
+            "example.com/hooks",
+        ] {
+            let errors = hook(url).validate().unwrap_err();
+            assert!(errors.field_errors().contains_key("url"), "{}", url);
+        }
+    }
+}
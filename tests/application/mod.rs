@@ -1 +1,2 @@
-mod cases;
\ No newline at end of file
+mod cases;
+mod dtos;
\ No newline at end of file
@@ -0,0 +1 @@
+mod organization;
\ No newline at end of file
@@ -0,0 +1,43 @@
+#[cfg(test)]
+mod company_test_suite {
+    use lib::domain::{
+        error::DomainError,
+        organization::{Company, CompanyId, CompanyName},
+    };
+    use uuid::Uuid;
+
+    #[test]
+    fn name_takes_1_to_200_characters() {
+        assert!(CompanyName::new("a").is_ok());
+        assert!(CompanyName::new("é".repeat(200)).is_ok());
+
+        assert!(matches!(CompanyName::new(""), Err(DomainError::Invalid(_))));
+        assert!(matches!(
+            CompanyName::new("  "),
+            Err(DomainError::Invalid(_))
+        ));
+        assert!(matches!(
+            CompanyName::new("a".repeat(201)),
+            Err(DomainError::Invalid(_))
+        ));
+    }
+
+    #[test]
+    fn new_company_starts_active_at_version_1() {
+        let company = Company::new(CompanyName::new("acme").unwrap());
+
+        assert_eq!(company.name.as_str(), "acme");
+        assert_eq!(company.version, 1);
+        assert!(!company.is_deleted());
+        assert_ne!(company.id, Company::new(company.name.clone()).id);
+    }
+
+    #[test]
+    fn id_converts_to_and_from_uuid() {
+        let uuid = Uuid::from_u128(7);
+        let id = CompanyId::from(uuid);
+
+        assert_eq!(Uuid::from(id), uuid);
+        assert_eq!(id.to_string(), uuid.to_string());
+    }
+}
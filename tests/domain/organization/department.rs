@@ -0,0 +1,31 @@
+#[cfg(test)]
+mod department_test_suite {
+    use lib::domain::{
+        error::DomainError,
+        organization::{CompanyId, Department, DepartmentName},
+    };
+
+    #[test]
+    fn name_is_required() {
+        assert!(DepartmentName::new("sales").is_ok());
+
+        assert!(matches!(
+            DepartmentName::new(""),
+            Err(DomainError::Invalid(_))
+        ));
+        assert!(matches!(
+            DepartmentName::new("\t"),
+            Err(DomainError::Invalid(_))
+        ));
+    }
+
+    #[test]
+    fn new_department_belongs_to_its_company() {
+        let company_id = CompanyId::new();
+        let department = Department::new(DepartmentName::new("sales").unwrap(), company_id);
+
+        assert_eq!(department.company_id, company_id);
+        assert_eq!(department.version, 1);
+        assert!(department.deleted_at.is_none());
+    }
+}
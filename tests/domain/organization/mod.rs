@@ -0,0 +1,2 @@
+mod company;
+mod department;
\ No newline at end of file
@@ -0,0 +1,101 @@
+#[cfg(test)]
+mod db_context_test_suite {
+    use std::{sync::Arc, time::Duration};
+
+    use chrono::{TimeDelta, Utc};
+    use lib::infrastructure::db::{DbContext, LastWrite, TenantScope, entities::companies};
+    use sea_orm::{DatabaseBackend, DatabaseConnection, MockDatabase};
+    use uuid::Uuid;
+
+    const TENANT: Uuid = Uuid::from_u128(1);
+
+    fn pool(name: &str, reads: usize) -> Arc<DatabaseConnection> {
+        let company = companies::Model {
+            id: Uuid::nil(),
+            name: name.to_owned(),
+            version: 1,
+            deleted_at: None,
+            created_at: Utc::now().fixed_offset(),
+            updated_at: Utc::now().fixed_offset(),
+            created_by: None,
+            updated_by: None,
+            tenant_id: TENANT,
+        };
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results(vec![vec![company]; reads])
+            .into_connection();
+        Arc::new(db)
+    }
+
+    async fn read_from(ctx: &DbContext) -> String {
+        ctx.read_provider(TenantScope::Tenant(TENANT))
+            .company_repo()
+            .find(Uuid::nil().into())
+            .await
+            .unwrap()
+            .unwrap()
+            .name
+            .into_inner()
+    }
+
+    #[tokio::test]
+    async fn reads_go_to_the_replicas_in_turn() {
+        let ctx = DbContext::new(pool("primary", 1))
+            .with_replicas(vec![pool("replica-1", 1), pool("replica-2", 1)]);
+
+        assert_eq!(read_from(&ctx).await, "replica-1");
+        assert_eq!(read_from(&ctx).await, "replica-2");
+    }
+
+    #[tokio::test]
+    async fn reads_stay_on_the_primary_without_replicas() {
+        let ctx = DbContext::new(pool("primary", 1));
+
+        assert_eq!(read_from(&ctx).await, "primary");
+        assert_eq!(ctx.read_your_writes(), None);
+    }
+
+    #[tokio::test]
+    async fn writer_reads_from_the_primary_within_the_window() {
+        let ctx = DbContext::new(pool("primary", 1))
+            .with_replicas(vec![pool("replica", 1)])
+            .with_read_your_writes(Duration::from_secs(60));
+
+        let read = LastWrite::now().scope(read_from(&ctx)).await;
+        assert_eq!(read, "primary");
+        assert_eq!(read_from(&ctx).await, "replica");
+    }
+
+    #[tokio::test]
+    async fn writer_is_back_on_the_replicas_after_the_window() {
+        let ctx = DbContext::new(pool("primary", 1))
+            .with_replicas(vec![pool("replica", 1)])
+            .with_read_your_writes(Duration::from_millis(20));
+        let last_write = LastWrite::now();
+        tokio::time::sleep(Duration::from_millis(40)).await;
+
+        assert_eq!(last_write.scope(read_from(&ctx)).await, "replica");
+    }
+
+    #[test]
+    fn marker_far_in_the_future_is_not_recent() {
+        let window = Duration::from_secs(60);
+        let now = Utc::now();
+
+        assert!(LastWrite(now - TimeDelta::seconds(30)).within(window, now));
+        assert!(LastWrite(now + TimeDelta::seconds(1)).within(window, now));
+        assert!(!LastWrite(now - TimeDelta::seconds(90)).within(window, now));
+        assert!(!LastWrite(now + TimeDelta::seconds(30)).within(window, now));
+        assert!(!LastWrite(now + TimeDelta::days(365 * 100)).within(window, now));
+    }
+
+    #[tokio::test]
+    async fn writer_from_the_future_reads_from_the_replicas() {
+        let ctx = DbContext::new(pool("primary", 1))
+            .with_replicas(vec![pool("replica", 1)])
+            .with_read_your_writes(Duration::from_secs(60));
+        let last_write = LastWrite(Utc::now() + TimeDelta::days(365));
+
+        assert_eq!(last_write.scope(read_from(&ctx)).await, "replica");
+    }
+}
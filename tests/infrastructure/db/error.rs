@@ -0,0 +1,22 @@
+#[cfg(test)]
+mod error_test_suite {
+    use lib::domain::error::DomainError;
+    use sea_orm::DbErr;
+
+    #[test]
+    fn db_errors_map_to_what_the_domain_makes_of_them() {
+        assert!(matches!(
+            DomainError::from(DbErr::RecordNotFound("company".to_string())),
+            DomainError::NotFound(m) if m == "company"
+        ));
+        assert!(matches!(
+            DomainError::from(DbErr::RecordNotInserted),
+            DomainError::Duplicated(_)
+        ));
+
+        let DomainError::Storage(e) = DomainError::from(DbErr::Custom("boom".to_string())) else {
+            panic!("other errors are left to the storage");
+        };
+        assert!(matches!(e.downcast_ref::<DbErr>(), Some(DbErr::Custom(_))));
+    }
+}
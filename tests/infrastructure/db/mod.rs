@@ -0,0 +1,5 @@
+mod context;
+mod error;
+mod sqlite;
+mod tenant;
+mod transaction;
\ No newline at end of file
@@ -0,0 +1,178 @@
+#[cfg(test)]
+mod sqlite_test_suite {
+    use axum::{extract::Query, http::Uri};
+    use chrono::Utc;
+    use lib::{
+        application::dtos::company::ReqQueryCompanyDto,
+        domain::{
+            events::OutboxMessage,
+            organization::{Company, CompanyCriteria, CompanyName, SearchKind},
+        },
+        infrastructure::db::{RepositoryProvider, TenantScope},
+        test_util::migrated_sqlite,
+    };
+    use migration::{Migrator, MigratorTrait};
+    use sea_orm::DatabaseConnection;
+    use uuid::Uuid;
+
+    const TENANT: Uuid = Uuid::from_u128(1);
+
+    fn company(name: &str) -> Company {
+        Company::new(CompanyName::new(name).unwrap())
+    }
+
+    fn filtered(query: &str) -> CompanyCriteria {
+        let uri: Uri = format!("/companies?{}", query).parse().unwrap();
+        CompanyCriteria::from(&Query::<ReqQueryCompanyDto>::try_from_uri(&uri).unwrap().0)
+    }
+
+    async fn names(db: &DatabaseConnection, criteria: &CompanyCriteria) -> Vec<String> {
+        let provider = RepositoryProvider::new(db, TenantScope::Tenant(TENANT));
+        let mut names: Vec<String> = provider
+            .company_repo()
+            .query(criteria)
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|c| c.name.into_inner())
+            .collect();
+        names.sort();
+        names
+    }
+
+    #[tokio::test]
+    async fn migrations_run_both_ways() {
+        let db = migrated_sqlite().await;
+
+        Migrator::down(&db, None).await.unwrap();
+        Migrator::up(&db, None).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn names_are_unique_among_active_rows() {
+        let db = migrated_sqlite().await;
+        let provider = RepositoryProvider::new(&db, TenantScope::Tenant(TENANT));
+        let repo = provider.company_repo();
+
+        let id = repo.add(company("acme")).await.unwrap();
+        assert!(repo.add(company("acme")).await.is_err());
+
+        repo.delete(id, 1, Utc::now()).await.unwrap();
+        assert!(repo.add(company("acme")).await.is_ok());
+
+        let other = RepositoryProvider::new(&db, TenantScope::Tenant(Uuid::from_u128(2)));
+        assert!(other.company_repo().add(company("acme")).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn like_filters_match_as_on_postgres() {
+        let db = migrated_sqlite().await;
+        let provider = RepositoryProvider::new(&db, TenantScope::Tenant(TENANT));
+        provider
+            .company_repo()
+            .add_many(vec![
+                company("Acme"),
+                company("acme labs"),
+                company("50%_off"),
+            ])
+            .await
+            .unwrap();
+
+        assert_eq!(
+            names(&db, &filtered("filter[name][like]=Acme")).await,
+            ["Acme"]
+        );
+        assert_eq!(
+            names(&db, &filtered("filter[name][ilike]=ACME")).await,
+            ["Acme", "acme labs"]
+        );
+        assert_eq!(
+            names(&db, &filtered("filter[name][ilike]=50%25_")).await,
+            ["50%_off"]
+        );
+        assert!(
+            names(&db, &filtered("filter[name][ilike]=5_%25"))
+                .await
+                .is_empty()
+        );
+    }
+
+    #[tokio::test]
+    async fn search_falls_back_to_the_names() {
+        let db = migrated_sqlite().await;
+        let provider = RepositoryProvider::new(&db, TenantScope::Tenant(TENANT));
+        provider
+            .company_repo()
+            .add_many(vec![
+                company("Acme"),
+                company("Big Acme Corp"),
+                company("Initech"),
+            ])
+            .await
+            .unwrap();
+
+        let hits = provider
+            .search_repo()
+            .search("acme", &[SearchKind::Company], 10)
+            .await
+            .unwrap();
+
+        let found: Vec<(&str, &str)> = hits
+            .iter()
+            .map(|hit| (hit.name.as_str(), hit.highlight.as_str()))
+            .collect();
+        assert_eq!(
+            found,
+            [
+                ("Acme", "<mark>Acme</mark>"),
+                ("Big Acme Corp", "Big <mark>Acme</mark> Corp")
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn search_escapes_the_names_it_marks() {
+        let db = migrated_sqlite().await;
+        let provider = RepositoryProvider::new(&db, TenantScope::Tenant(TENANT));
+        provider
+            .company_repo()
+            .add(company("<script>alert('acme')</script> & Acme"))
+            .await
+            .unwrap();
+
+        let hits = provider
+            .search_repo()
+            .search("acme", &[SearchKind::Company], 10)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            hits[0].highlight,
+            "&lt;script&gt;alert(&#39;<mark>acme</mark>&#39;)&lt;/script&gt; &amp; <mark>Acme</mark>"
+        );
+    }
+
+    #[tokio::test]
+    async fn events_are_logged_once() {
+        let db = migrated_sqlite().await;
+        let provider = RepositoryProvider::new(&db, TenantScope::Tenant(TENANT));
+        let message = OutboxMessage {
+            id: Uuid::new_v4(),
+            tenant_id: Some(TENANT),
+            event_type: "CompanyCreated".to_owned(),
+            aggregate_id: Uuid::new_v4(),
+            payload: serde_json::json!({ "name": "acme" }),
+            attempts: 0,
+            created_at: Utc::now().fixed_offset(),
+        };
+
+        let repo = provider.event_log_repo();
+        repo.append(&message).await.unwrap();
+        repo.append(&message).await.unwrap();
+
+        let logged = repo.since(0, 10).await.unwrap();
+        assert_eq!(logged.len(), 1);
+        assert_eq!(logged[0].seq, 1);
+        assert_eq!(repo.last_seq().await.unwrap(), 1);
+    }
+}
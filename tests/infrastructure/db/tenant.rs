@@ -0,0 +1,130 @@
+#[cfg(test)]
+mod tenant_test_suite {
+    use std::sync::Arc;
+
+    use lib::infrastructure::db::{
+        DbContext, Repository, TenantScope,
+        entities::{companies, jobs},
+    };
+    use sea_orm::{
+        DatabaseBackend, DatabaseConnection, MockDatabase, MockExecResult, QueryTrait, Value,
+    };
+    use uuid::Uuid;
+
+    const TENANT: Uuid = Uuid::from_u128(1);
+
+    fn conn() -> DatabaseConnection {
+        MockDatabase::new(DatabaseBackend::Postgres).into_connection()
+    }
+
+    #[test]
+    fn setting_denies_without_a_tenant() {
+        assert_eq!(TenantScope::setting(None), "");
+        assert_eq!(TenantScope::setting(Some(TenantScope::All)), "*");
+        assert_eq!(
+            TenantScope::setting(Some(TenantScope::Tenant(TENANT))),
+            TENANT.to_string()
+        );
+    }
+
+    #[tokio::test]
+    async fn checkout_tenant_is_the_running_one() {
+        assert_eq!(TenantScope::checkout(), None);
+
+        let tenant = TenantScope::Tenant(TENANT)
+            .run(async { TenantScope::checkout() })
+            .await;
+
+        assert_eq!(tenant, Some(TenantScope::Tenant(TENANT)));
+    }
+
+    #[test]
+    fn repository_queries_are_restricted_to_the_tenant() {
+        let db = conn();
+        let repo = Repository::new(&db, TenantScope::Tenant(TENANT));
+
+        for sql in [
+            repo.find::<companies::Entity>()
+                .build(DatabaseBackend::Postgres)
+                .to_string(),
+            repo.find_by_id::<companies::Entity>(Uuid::nil())
+                .build(DatabaseBackend::Postgres)
+                .to_string(),
+            repo.update_many::<jobs::Entity>()
+                .build(DatabaseBackend::Postgres)
+                .to_string(),
+            repo.delete_many::<companies::Entity>()
+                .build(DatabaseBackend::Postgres)
+                .to_string(),
+        ] {
+            assert!(
+                sql.contains(&format!(r#""tenant_id" = '{}'"#, TENANT)),
+                "{}",
+                sql
+            );
+        }
+    }
+
+    #[test]
+    fn maintenance_queries_see_every_tenant() {
+        let db = conn();
+        let repo = Repository::new(&db, TenantScope::All);
+
+        let sql = repo
+            .find::<companies::Entity>()
+            .build(DatabaseBackend::Postgres)
+            .to_string();
+
+        assert!(!sql.contains("tenant_id\" ="), "{}", sql);
+    }
+
+    #[tokio::test]
+    async fn transactions_of_every_tenant_ask_for_all_rows() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_exec_results([MockExecResult::default()])
+            .into_connection();
+        let db = Arc::new(db);
+        let db_context = DbContext::new(db.clone()).with_row_level_security(true);
+
+        db_context
+            .transaction(TenantScope::All, |_| Box::pin(async { Ok(()) }))
+            .await
+            .unwrap();
+
+        drop(db_context);
+        let db = Arc::into_inner(db).unwrap();
+        let log = format!("{:?}", db.into_transaction_log());
+        assert!(log.contains("set_config('app.tenant_id', $1, true)"), "{}", log);
+        assert!(
+            log.contains(&format!("{:?}", Value::from("*".to_string()))),
+            "{}",
+            log
+        );
+    }
+
+    /// Every statement of the repositories on a tenant's table goes through the helpers
+    /// of `Repository`, so that none of them forgets the tenant.
+    #[test]
+    fn implementations_do_not_reach_tenant_tables_directly() {
+        let dir = concat!(env!("CARGO_MANIFEST_DIR"), "/src/infrastructure/implements");
+        for entry in std::fs::read_dir(dir).unwrap() {
+            let path = entry.unwrap().path();
+            let source = std::fs::read_to_string(&path).unwrap();
+            for call in [
+                "::Entity::find(",
+                "::Entity::find_by_id(",
+                "::Entity::update(",
+                "::Entity::update_many(",
+                "::Entity::delete_many(",
+                "::Entity::delete_by_id(",
+            ] {
+                assert!(
+                    !source.contains(call),
+                    "{} calls {} instead of the tenant-scoped `Repository` helper",
+                    path.display(),
+                    call
+                );
+            }
+        }
+    }
+}
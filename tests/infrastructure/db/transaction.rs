@@ -0,0 +1,221 @@
+#[cfg(test)]
+mod transaction_test_suite {
+    use std::{
+        borrow::Cow,
+        error::Error,
+        fmt,
+        sync::{
+            Arc,
+            atomic::{AtomicU32, Ordering},
+        },
+    };
+
+    use chrono::Utc;
+    use lib::{
+        domain::error::{DomainError, ErrorKind as DomainErrorKind},
+        infrastructure::db::{
+            DbContext, TenantScope, TxOptions, entities::companies, is_retryable,
+        },
+        with_savepoint, with_transaction,
+    };
+    use sea_orm::{
+        DatabaseBackend, DbErr, MockDatabase, RuntimeErr,
+        sqlx::{self, error::ErrorKind},
+    };
+    use uuid::Uuid;
+
+    const TENANT: Uuid = Uuid::from_u128(1);
+
+    /// What the driver reports for a statement Postgres rejected with `code`.
+    #[derive(Debug)]
+    struct PgError(&'static str);
+
+    impl fmt::Display for PgError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "error {}", self.0)
+        }
+    }
+
+    impl Error for PgError {}
+
+    impl sqlx::error::DatabaseError for PgError {
+        fn message(&self) -> &str {
+            "rejected"
+        }
+
+        fn code(&self) -> Option<Cow<'_, str>> {
+            Some(Cow::Borrowed(self.0))
+        }
+
+        fn as_error(&self) -> &(dyn Error + Send + Sync + 'static) {
+            self
+        }
+
+        fn as_error_mut(&mut self) -> &mut (dyn Error + Send + Sync + 'static) {
+            self
+        }
+
+        fn into_error(self: Box<Self>) -> Box<dyn Error + Send + Sync + 'static> {
+            self
+        }
+
+        fn kind(&self) -> ErrorKind {
+            ErrorKind::Other
+        }
+    }
+
+    fn rejected(code: &'static str) -> DbErr {
+        DbErr::Query(RuntimeErr::SqlxError(sqlx::Error::Database(Box::new(
+            PgError(code),
+        ))))
+    }
+
+    fn company() -> companies::Model {
+        companies::Model {
+            id: Uuid::nil(),
+            name: "acme".to_owned(),
+            version: 1,
+            deleted_at: None,
+            created_at: Utc::now().fixed_offset(),
+            updated_at: Utc::now().fixed_offset(),
+            created_by: None,
+            updated_by: None,
+            tenant_id: TENANT,
+        }
+    }
+
+    #[test]
+    fn only_conflicts_are_retryable() {
+        assert!(is_retryable(&rejected("40001")));
+        assert!(is_retryable(&rejected("40P01")));
+        assert!(!is_retryable(&rejected("23505")));
+        assert!(!is_retryable(&DbErr::Query(RuntimeErr::Internal(
+            "40001".to_owned()
+        ))));
+    }
+
+    #[tokio::test]
+    async fn serialization_failures_run_the_closure_again() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_errors([rejected("40001")])
+            .append_query_results([vec![company()]])
+            .into_connection();
+        let ctx = DbContext::new(Arc::new(db));
+        let attempts = Arc::new(AtomicU32::new(0));
+
+        let exists = with_transaction!(ctx, TenantScope::Tenant(TENANT), TxOptions::serializable(), provider, clone(attempts) => {
+            attempts.fetch_add(1, Ordering::SeqCst);
+            provider.company_repo().exists(Uuid::nil().into()).await
+        })
+        .unwrap();
+
+        assert!(exists);
+        assert_eq!(attempts.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn retries_stop_after_the_last_attempt() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_errors([rejected("40001"), rejected("40P01")])
+            .into_connection();
+        let ctx = DbContext::new(Arc::new(db));
+        let attempts = Arc::new(AtomicU32::new(0));
+
+        let result = with_transaction!(ctx, TenantScope::Tenant(TENANT), TxOptions::serializable().with_max_attempts(2), provider, clone(attempts) => {
+            attempts.fetch_add(1, Ordering::SeqCst);
+            provider.company_repo().exists(Uuid::nil().into()).await
+        });
+
+        assert!(matches!(
+            result,
+            Err(DomainError::Storage(ref e)) if e.downcast_ref::<DbErr>().is_some_and(is_retryable)
+        ));
+        assert_eq!(attempts.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn other_errors_are_not_retried() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_errors([rejected("23505")])
+            .into_connection();
+        let ctx = DbContext::new(Arc::new(db));
+        let attempts = Arc::new(AtomicU32::new(0));
+
+        let result = with_transaction!(ctx, TenantScope::Tenant(TENANT), TxOptions::serializable(), provider, clone(attempts) => {
+            attempts.fetch_add(1, Ordering::SeqCst);
+            provider.company_repo().exists(Uuid::nil().into()).await
+        });
+
+        assert!(result.is_err());
+        assert_eq!(attempts.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn failed_savepoint_leaves_the_transaction_going() {
+        let db = Arc::new(
+            MockDatabase::new(DatabaseBackend::Postgres)
+                .append_query_results([vec![company()]])
+                .into_connection(),
+        );
+        let ctx = DbContext::new(db.clone());
+
+        let exists = with_transaction!(ctx, TenantScope::Tenant(TENANT), provider => {
+            let nested: Result<(), DomainError> = with_savepoint!(provider, inner => {
+                inner.company_repo().exists(Uuid::nil().into()).await?;
+                Err(DomainError::CaseError(
+                    DomainErrorKind::Conflict,
+                    "NESTED_FAIL".to_owned(),
+                    "nested use case failed".to_owned(),
+                ))
+            });
+            assert!(nested.is_err());
+
+            Ok(true)
+        })
+        .unwrap();
+        assert!(exists);
+        drop(ctx);
+
+        let log = format!("{:?}", Arc::try_unwrap(db).unwrap().into_transaction_log());
+        assert!(log.contains("SAVEPOINT savepoint_1"));
+        assert!(log.contains("ROLLBACK TO SAVEPOINT savepoint_1"));
+        assert!(log.contains("COMMIT"));
+    }
+
+    /// An error of the body of a savepoint that tells what it got done before failing.
+    #[derive(Debug)]
+    enum Partial {
+        Done(u32),
+        Storage(DomainError),
+    }
+
+    impl From<DomainError> for Partial {
+        fn from(e: DomainError) -> Self {
+            Self::Storage(e)
+        }
+    }
+
+    #[tokio::test]
+    async fn failed_savepoint_hands_its_own_error_back() {
+        let db = Arc::new(MockDatabase::new(DatabaseBackend::Postgres).into_connection());
+        let ctx = DbContext::new(db.clone());
+
+        let done = with_transaction!(ctx, TenantScope::Tenant(TENANT), provider => {
+            let nested: Result<(), Partial> = with_savepoint!(provider, _inner => {
+                Err(Partial::Done(2))
+            });
+
+            match nested {
+                Err(Partial::Done(done)) => Ok(done),
+                Err(Partial::Storage(e)) => Err(e),
+                Ok(()) => Ok(0),
+            }
+        })
+        .unwrap();
+        assert_eq!(done, 2);
+        drop(ctx);
+
+        let log = format!("{:?}", Arc::try_unwrap(db).unwrap().into_transaction_log());
+        assert!(log.contains("ROLLBACK TO SAVEPOINT savepoint_1"));
+    }
+}
@@ -0,0 +1,39 @@
+#[cfg(test)]
+mod egress_test_suite {
+    use std::net::IpAddr;
+
+    use lib::infrastructure::helpers::egress::is_public;
+
+    fn ip(s: &str) -> IpAddr {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn internal_addresses_are_refused() {
+        for addr in [
+            "127.0.0.1",
+            "10.1.2.3",
+            "172.16.0.1",
+            "192.168.1.1",
+            "169.254.169.254",
+            "100.64.0.1",
+            "0.0.0.0",
+            "255.255.255.255",
+            "::1",
+            "::",
+            "fd00::1",
+            "fe80::1",
+            "::ffff:127.0.0.1",
+            "::ffff:169.254.169.254",
+        ] {
+            assert!(!is_public(ip(addr)), "{} should be refused", addr);
+        }
+    }
+
+    #[test]
+    fn public_addresses_are_allowed() {
+        for addr in ["93.184.216.34", "8.8.8.8", "2606:4700:4700::1111"] {
+            assert!(is_public(ip(addr)), "{} should be allowed", addr);
+        }
+    }
+}
@@ -0,0 +1,120 @@
+#[cfg(test)]
+mod export_test_suite {
+    use chrono::DateTime;
+    use lib::{
+        application::dtos::organization::ResExportCompanyDto,
+        domain::error::{DomainError, ErrorKind},
+        infrastructure::helpers::export::{ExportEncoder, ExportFormat},
+    };
+    use uuid::Uuid;
+
+    fn row(name: &str) -> ResExportCompanyDto {
+        let at = DateTime::parse_from_rfc3339("2025-12-01T09:30:00+00:00").unwrap();
+
+        ResExportCompanyDto {
+            id: Uuid::nil(),
+            name: name.to_owned(),
+            department_count: 2,
+            created_at: at,
+            updated_at: at,
+        }
+    }
+
+    fn encode(format: ExportFormat) -> Vec<u8> {
+        let mut encoder = ExportEncoder::new::<ResExportCompanyDto>(format, "companies").unwrap();
+        let mut out = Vec::new();
+
+        for name in ["Acme", "Acme, Inc"] {
+            if let Some(chunk) = encoder.write(&row(name)).unwrap() {
+                out.extend_from_slice(&chunk);
+            }
+        }
+        out.extend_from_slice(&encoder.finish().unwrap());
+        out
+    }
+
+    #[test]
+    fn negotiate_format_from_query_then_accept() {
+        let negotiate = ExportFormat::negotiate;
+
+        assert_eq!(negotiate(None, None).unwrap(), ExportFormat::Csv);
+        assert_eq!(
+            negotiate(Some(ExportFormat::Xlsx), Some("text/csv")).unwrap(),
+            ExportFormat::Xlsx
+        );
+        assert_eq!(
+            negotiate(None, Some("application/x-ndjson;q=0.9, text/csv")).unwrap(),
+            ExportFormat::Ndjson
+        );
+        assert_eq!(negotiate(None, Some("*/*")).unwrap(), ExportFormat::Csv);
+
+        let Err(DomainError::CaseError(status, code, _)) = negotiate(None, Some("text/html"))
+        else {
+            panic!("text/html should not be acceptable");
+        };
+        assert_eq!(status, ErrorKind::NotAcceptable);
+        assert_eq!(code, "EXPORT_FORMAT_UNSUPPORTED");
+    }
+
+    #[test]
+    fn quote_csv_cells_spreadsheets_take_for_formulas() {
+        let mut encoder =
+            ExportEncoder::new::<ResExportCompanyDto>(ExportFormat::Csv, "companies").unwrap();
+        let mut out = Vec::new();
+        for name in ["=HYPERLINK(\"x\")", "@SUM(A1)", "+1", "-1", "\tTab", "A=B"] {
+            if let Some(chunk) = encoder.write(&row(name)).unwrap() {
+                out.extend_from_slice(&chunk);
+            }
+        }
+        out.extend_from_slice(&encoder.finish().unwrap());
+
+        let csv = String::from_utf8(out).unwrap();
+        let names: Vec<&str> = csv
+            .lines()
+            .skip(1)
+            .map(|line| line.split(',').nth(1).unwrap())
+            .collect();
+        assert_eq!(
+            names,
+            [
+                "\"'=HYPERLINK(\"\"x\"\")\"",
+                "'@SUM(A1)",
+                "'+1",
+                "'-1",
+                "'\tTab",
+                "A=B"
+            ]
+        );
+    }
+
+    #[test]
+    fn encode_csv_and_ndjson_rows() {
+        let csv = String::from_utf8(encode(ExportFormat::Csv)).unwrap();
+        let ndjson = String::from_utf8(encode(ExportFormat::Ndjson)).unwrap();
+
+        let id = Uuid::nil();
+        assert_eq!(
+            csv,
+            format!(
+                "id,name,department_count,created_at,updated_at\n\
+                 {id},Acme,2,2025-12-01T09:30:00+00:00,2025-12-01T09:30:00+00:00\n\
+                 {id},\"Acme, Inc\",2,2025-12-01T09:30:00+00:00,2025-12-01T09:30:00+00:00\n"
+            )
+        );
+
+        let lines: Vec<serde_json::Value> = ndjson
+            .lines()
+            .map(|l| serde_json::from_str(l).unwrap())
+            .collect();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[1]["name"], "Acme, Inc");
+        assert_eq!(lines[1]["department_count"], 2);
+    }
+
+    #[test]
+    fn encode_xlsx_workbook() {
+        let xlsx = encode(ExportFormat::Xlsx);
+
+        assert!(xlsx.starts_with(b"PK"));
+    }
+}
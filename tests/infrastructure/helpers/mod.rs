@@ -1 +1,5 @@
+mod egress;
+mod export;
+mod signature;
+mod spreadsheet;
 mod token;
\ No newline at end of file
@@ -0,0 +1,26 @@
+#[cfg(test)]
+mod signature_test_suite {
+    use lib::infrastructure::helpers::signature;
+
+    #[test]
+    fn verify_signature_success() {
+        let body = br#"{"type":"company.created"}"#;
+
+        let sig = signature::sign("secret", 1700000000, body);
+
+        assert!(sig.starts_with("v1="));
+        assert!(signature::verify("secret", 1700000000, body, &sig));
+    }
+
+    #[test]
+    fn verify_signature_faild() {
+        let body = br#"{"type":"company.created"}"#;
+
+        let sig = signature::sign("secret", 1700000000, body);
+
+        assert!(!signature::verify("other", 1700000000, body, &sig));
+        assert!(!signature::verify("secret", 1700000001, body, &sig));
+        assert!(!signature::verify("secret", 1700000000, b"{}", &sig));
+        assert!(!signature::verify("secret", 1700000000, body, "v1=zz"));
+    }
+}
@@ -0,0 +1,25 @@
+#[cfg(test)]
+mod spreadsheet_test_suite {
+    use lib::infrastructure::helpers::spreadsheet;
+
+    #[test]
+    fn read_csv_rows_by_header() {
+        let data = "\u{feff}Name , Company\n Sales ,Acme\n,\nOps,\"Acme, Inc\"\n";
+
+        let rows = spreadsheet::read_rows("departments.csv", data.as_bytes()).unwrap();
+
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].number, 2);
+        assert_eq!(rows[0].get("name"), "Sales");
+        assert_eq!(rows[1].number, 4);
+        assert_eq!(rows[1].get("company"), "Acme, Inc");
+        assert_eq!(rows[1].get("missing"), "");
+    }
+
+    #[test]
+    fn read_workbook_faild_when_corrupted() {
+        let result = spreadsheet::read_rows("companies.xlsx", b"name\nAcme\n");
+
+        assert!(result.is_err());
+    }
+}
@@ -1,6 +1,7 @@
 #[cfg(test)]
 mod token_test_suite {
     use lib::infrastructure::helpers::token::JwtHelper;
+    use uuid::Uuid;
 
     #[test]
     fn generate_token_success() {
@@ -36,4 +37,32 @@ mod token_test_suite {
 
         assert!(valid.is_err());
     }
-}
+
+    #[test]
+    fn decode_token_with_roles() {
+        let jwt_helper = JwtHelper::new("secret".to_string());
+
+        let token = jwt_helper
+            .generate_with_roles("user".to_string(), vec!["admin".to_string()])
+            .unwrap();
+
+        let claims = jwt_helper.decode(token.as_str()).unwrap();
+
+        assert_eq!(claims.sub, "user");
+        assert_eq!(claims.roles, ["admin"]);
+    }
+
+    #[test]
+    fn decode_token_with_tenant() {
+        let jwt_helper = JwtHelper::new("secret".to_string());
+        let tenant_id = Uuid::new_v4();
+
+        let token = jwt_helper
+            .generate_for_tenant("user".to_string(), Some(tenant_id), Vec::new())
+            .unwrap();
+
+        let claims = jwt_helper.decode(token.as_str()).unwrap();
+
+        assert_eq!(claims.tenant_id, Some(tenant_id));
+    }
+}
\ No newline at end of file
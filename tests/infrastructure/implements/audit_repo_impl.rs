@@ -0,0 +1,113 @@
+#[cfg(test)]
+mod audit_repo_test_suite {
+    use lib::{
+        domain::audit::{AuditAction, AuditLogCriteria, NewAuditLog},
+        infrastructure::db::{AuditContext, RepositoryProvider, TenantScope, entities::audit_log},
+    };
+    use sea_orm::{DatabaseBackend, DbErr, MockDatabase, MockExecResult, Transaction};
+    use serde_json::json;
+    use uuid::Uuid;
+
+    const TENANT: Uuid = Uuid::from_u128(1);
+
+    #[test]
+    fn diff_keep_only_changed_fields() {
+        let id = Uuid::new_v4();
+        let before = json!({ "id": id, "name": "test-1", "version": 1, "updated_by": null });
+        let after = json!({ "id": id, "name": "renamed", "version": 2, "updated_by": "user" });
+
+        let log = NewAuditLog::diff(
+            "company",
+            id,
+            AuditAction::Update,
+            Some(&before),
+            Some(&after),
+        );
+
+        assert_eq!(log.before, Some(json!({ "name": "test-1" })));
+        assert_eq!(log.after, Some(json!({ "name": "renamed" })));
+    }
+
+    #[test]
+    fn diff_keep_whole_state_on_create() {
+        let id = Uuid::new_v4();
+        let after = json!({ "id": id, "name": "test-1", "version": 1 });
+
+        let log = NewAuditLog::diff("company", id, AuditAction::Create, None, Some(&after));
+
+        assert_eq!(log.before, None);
+        assert_eq!(log.after, Some(after));
+    }
+
+    #[tokio::test]
+    async fn record_stamp_actor_and_request_id_from_context() -> Result<(), DbErr> {
+        let id = Uuid::new_v4();
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_exec_results([MockExecResult {
+                last_insert_id: 0,
+                rows_affected: 1,
+            }])
+            .into_connection();
+
+        let ctx = AuditContext::new(Some("logon-user".to_owned()), Some("req-1".to_owned()));
+        let result = ctx
+            .scope(async {
+                let provider = RepositoryProvider::new(&db, TenantScope::Tenant(TENANT));
+                provider
+                    .audit_repo()
+                    .record(NewAuditLog::diff(
+                        "company",
+                        id,
+                        AuditAction::Delete,
+                        Some(&json!({ "deleted_at": null })),
+                        Some(&json!({ "deleted_at": "2025-01-01T00:00:00Z" })),
+                    ))
+                    .await
+            })
+            .await;
+
+        assert!(result.is_ok());
+
+        let log = db.into_transaction_log();
+        let insert = log[0].statements()[0].to_string();
+        assert!(insert.starts_with(r#"INSERT INTO "audit_log""#));
+        assert!(insert.contains("'delete', 'logon-user'"));
+        assert!(insert.contains("'req-1'"));
+        assert!(insert.contains(&format!("'{}'", TENANT)));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn query_filter_by_entity_with_default_limit() -> Result<(), DbErr> {
+        let id = Uuid::new_v4();
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results([Vec::<audit_log::Model>::new()])
+            .into_connection();
+
+        let result = {
+            let provider = RepositoryProvider::new(&db, TenantScope::Tenant(TENANT));
+            provider
+                .audit_repo()
+                .query(&AuditLogCriteria {
+                    entity: Some("company".to_owned()),
+                    entity_id: Some(id),
+                    ..Default::default()
+                })
+                .await
+        };
+
+        assert!(result.unwrap().is_empty());
+
+        assert_eq!(
+            db.into_transaction_log(),
+            [Transaction::from_sql_and_values(
+                DatabaseBackend::Postgres,
+                r#"SELECT "audit_log"."id", "audit_log"."entity", "audit_log"."entity_id", "audit_log"."action", "audit_log"."actor", "audit_log"."before", "audit_log"."after", "audit_log"."request_id", "audit_log"."created_at", "audit_log"."tenant_id" FROM "audit_log" WHERE "audit_log"."tenant_id" = $1 AND "audit_log"."entity" = $2 AND "audit_log"."entity_id" = $3 ORDER BY "audit_log"."created_at" DESC LIMIT $4"#,
+                [TENANT.into(), "company".into(), id.into(), 100u64.into()]
+            ),]
+        );
+
+        Ok(())
+    }
+}
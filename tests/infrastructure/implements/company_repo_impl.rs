@@ -1,21 +1,28 @@
 #[cfg(test)]
 mod company_repo_test_suite {
+    use axum::{extract::Query, http::Uri};
+    use chrono::{DateTime, Utc};
     use lib::{
         application::dtos::company::ReqQueryCompanyDto,
-        domain::organization::repositories::CompanyRepository,
-        infrastructure::db::{RepositoryProvider, entities::companies},
+        domain::{
+            error::{DomainError, ErrorKind},
+            organization::{Company, CompanyCriteria, CompanyName},
+        },
+        infrastructure::db::{RepositoryProvider, TenantScope, entities::companies},
     };
-    use sea_orm::{DatabaseBackend, DbErr, MockDatabase, Transaction};
+    use sea_orm::{DatabaseBackend, DbErr, MockDatabase, MockExecResult, Transaction};
     use uuid::Uuid;
 
+    const TENANT: Uuid = Uuid::from_u128(1);
+
     #[tokio::test]
     async fn query_faild_when_no_data() -> Result<(), DbErr> {
         let db = MockDatabase::new(DatabaseBackend::Postgres).into_connection();
 
         let result = {
-            let provider = RepositoryProvider::new(&db);
+            let provider = RepositoryProvider::new(&db, TenantScope::Tenant(TENANT));
             let company_repo = provider.company_repo();
-            company_repo.query(&ReqQueryCompanyDto { name: None }).await
+            company_repo.query(&CompanyCriteria::default()).await
         };
 
         assert!(result.is_err());
@@ -24,8 +31,8 @@ mod company_repo_test_suite {
             db.into_transaction_log(),
             [Transaction::from_sql_and_values(
                 DatabaseBackend::Postgres,
-                r#"SELECT "companies"."id", "companies"."name" FROM "companies""#,
-                []
+                r#"SELECT "companies"."id", "companies"."name", "companies"."version", "companies"."deleted_at", "companies"."created_at", "companies"."updated_at", "companies"."created_by", "companies"."updated_by", "companies"."tenant_id" FROM "companies" WHERE "companies"."tenant_id" = $1 AND "companies"."deleted_at" IS NULL"#,
+                [TENANT.into()]
             ),]
         );
 
@@ -39,33 +46,47 @@ mod company_repo_test_suite {
                 companies::Model {
                     id: Uuid::new_v4(),
                     name: "test-1".to_owned(),
+                    version: 1,
+                    deleted_at: None,
+                    created_at: Utc::now().fixed_offset(),
+                    updated_at: Utc::now().fixed_offset(),
+                    created_by: None,
+                    updated_by: None,
+                    tenant_id: TENANT,
                 },
                 companies::Model {
                     id: Uuid::new_v4(),
                     name: "test-2".to_owned(),
+                    version: 1,
+                    deleted_at: None,
+                    created_at: Utc::now().fixed_offset(),
+                    updated_at: Utc::now().fixed_offset(),
+                    created_by: None,
+                    updated_by: None,
+                    tenant_id: TENANT,
                 },
             ]])
             .into_connection();
 
         let result = {
-            let provider = RepositoryProvider::new(&db);
+            let provider = RepositoryProvider::new(&db, TenantScope::Tenant(TENANT));
             let company_repo = provider.company_repo();
-            company_repo.query(&ReqQueryCompanyDto { name: None }).await
+            company_repo.query(&CompanyCriteria::default()).await
         };
 
         assert!(result.is_ok());
 
         let items = result.unwrap();
         assert!(items.len() == 2);
-        assert_eq!(items[0].name, "test-1");
-        assert_eq!(items[1].name, "test-2");
+        assert_eq!(items[0].name.as_str(), "test-1");
+        assert_eq!(items[1].name.as_str(), "test-2");
 
         assert_eq!(
             db.into_transaction_log(),
             [Transaction::from_sql_and_values(
                 DatabaseBackend::Postgres,
-                r#"SELECT "companies"."id", "companies"."name" FROM "companies""#,
-                []
+                r#"SELECT "companies"."id", "companies"."name", "companies"."version", "companies"."deleted_at", "companies"."created_at", "companies"."updated_at", "companies"."created_by", "companies"."updated_by", "companies"."tenant_id" FROM "companies" WHERE "companies"."tenant_id" = $1 AND "companies"."deleted_at" IS NULL"#,
+                [TENANT.into()]
             ),]
         );
 
@@ -78,16 +99,24 @@ mod company_repo_test_suite {
             .append_query_results([vec![companies::Model {
                 id: Uuid::new_v4(),
                 name: "test-1".to_owned(),
+                version: 1,
+                deleted_at: None,
+                created_at: Utc::now().fixed_offset(),
+                updated_at: Utc::now().fixed_offset(),
+                created_by: None,
+                updated_by: None,
+                tenant_id: TENANT,
             }]])
             .into_connection();
 
         let result = {
-            let provider = RepositoryProvider::new(&db);
+            let provider = RepositoryProvider::new(&db, TenantScope::Tenant(TENANT));
             let company_repo = provider.company_repo();
             company_repo
-                .query(&ReqQueryCompanyDto {
-                    name: Some("test-1".to_owned()),
-                })
+                .query(&CompanyCriteria::from(&ReqQueryCompanyDto {
+                    name: Some("Test-1".to_owned()),
+                    ..Default::default()
+                }))
                 .await
         };
 
@@ -95,19 +124,46 @@ mod company_repo_test_suite {
 
         let items = result.unwrap();
         assert_eq!(items.len(), 1);
-        assert_eq!(items[0].name, "test-1");
+        assert_eq!(items[0].name.as_str(), "test-1");
         assert_eq!(
             db.into_transaction_log(),
             [Transaction::from_sql_and_values(
                 DatabaseBackend::Postgres,
-                r#"SELECT "companies"."id", "companies"."name" FROM "companies" WHERE "companies"."name" LIKE $1"#,
-                ["%test-1%".into()]
+                r#"SELECT "companies"."id", "companies"."name", "companies"."version", "companies"."deleted_at", "companies"."created_at", "companies"."updated_at", "companies"."created_by", "companies"."updated_by", "companies"."tenant_id" FROM "companies" WHERE "companies"."tenant_id" = $1 AND "companies"."deleted_at" IS NULL AND LOWER("companies"."name") LIKE $2 ESCAPE E'\\'"#,
+                [TENANT.into(), "%test-1%".into()]
             ),]
         );
 
         Ok(())
     }
 
+    #[tokio::test]
+    async fn query_apply_list_filter_with_escaped_wildcards() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results([Vec::<companies::Model>::new()])
+            .into_connection();
+        let uri: Uri = "/companies?filter[name][ilike]=50%25_off&filter[created_at][gte]=2025-01-01T00:00:00Z&filter[updated_by][is_null]=false"
+            .parse()
+            .unwrap();
+        let Query(cond) = Query::<ReqQueryCompanyDto>::try_from_uri(&uri).unwrap();
+
+        let result = {
+            let provider = RepositoryProvider::new(&db, TenantScope::Tenant(TENANT));
+            provider
+                .company_repo()
+                .query(&CompanyCriteria::from(&cond))
+                .await
+        };
+
+        assert!(result.is_ok());
+        let sql = db.into_transaction_log()[0].statements()[0].to_string();
+        assert!(sql.contains(r#"LOWER("companies"."name") LIKE E'%50\\%\\_off%' ESCAPE E'\\'"#));
+        assert!(sql.contains(r#""companies"."created_at" >= '2025-01-01 00:00:00.000000 +00:00'"#));
+        assert!(sql.contains(r#""companies"."updated_by" IS NOT NULL"#));
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn exists_return_true_when_found() -> Result<(), DbErr> {
         let id = Uuid::new_v4();
@@ -115,13 +171,20 @@ mod company_repo_test_suite {
             .append_query_results([vec![companies::Model {
                 id,
                 name: "test-1".to_owned(),
+                version: 1,
+                deleted_at: None,
+                created_at: Utc::now().fixed_offset(),
+                updated_at: Utc::now().fixed_offset(),
+                created_by: None,
+                updated_by: None,
+                tenant_id: TENANT,
             }]])
             .into_connection();
 
         let result = {
-            let provider = RepositoryProvider::new(&db);
+            let provider = RepositoryProvider::new(&db, TenantScope::Tenant(TENANT));
             let company_repo = provider.company_repo();
-            company_repo.exists(id).await
+            company_repo.exists(id.into()).await
         };
 
         assert!(result.is_ok());
@@ -131,8 +194,8 @@ mod company_repo_test_suite {
             db.into_transaction_log(),
             [Transaction::from_sql_and_values(
                 DatabaseBackend::Postgres,
-                r#"SELECT "companies"."id", "companies"."name" FROM "companies" WHERE "companies"."id" = $1 LIMIT $2"#,
-                [id.into(), 1u64.into()]
+                r#"SELECT "companies"."id", "companies"."name", "companies"."version", "companies"."deleted_at", "companies"."created_at", "companies"."updated_at", "companies"."created_by", "companies"."updated_by", "companies"."tenant_id" FROM "companies" WHERE "companies"."id" = $1 AND "companies"."tenant_id" = $2 AND "companies"."deleted_at" IS NULL LIMIT $3"#,
+                [id.into(), TENANT.into(), 1u64.into()]
             ),]
         );
 
@@ -147,9 +210,9 @@ mod company_repo_test_suite {
             .into_connection();
 
         let result = {
-            let provider = RepositoryProvider::new(&db);
+            let provider = RepositoryProvider::new(&db, TenantScope::Tenant(TENANT));
             let company_repo = provider.company_repo();
-            company_repo.exists(id).await
+            company_repo.exists(id.into()).await
         };
 
         assert!(result.is_ok());
@@ -159,11 +222,178 @@ mod company_repo_test_suite {
             db.into_transaction_log(),
             [Transaction::from_sql_and_values(
                 DatabaseBackend::Postgres,
-                r#"SELECT "companies"."id", "companies"."name" FROM "companies" WHERE "companies"."id" = $1 LIMIT $2"#,
-                [id.into(), 1u64.into()]
+                r#"SELECT "companies"."id", "companies"."name", "companies"."version", "companies"."deleted_at", "companies"."created_at", "companies"."updated_at", "companies"."created_by", "companies"."updated_by", "companies"."tenant_id" FROM "companies" WHERE "companies"."id" = $1 AND "companies"."tenant_id" = $2 AND "companies"."deleted_at" IS NULL LIMIT $3"#,
+                [id.into(), TENANT.into(), 1u64.into()]
+            ),]
+        );
+
+        Ok(())
+    }
+
+    fn company(id: Uuid, name: &str, version: i32) -> companies::Model {
+        let at = DateTime::parse_from_rfc3339("2025-01-01T00:00:00Z").unwrap();
+
+        companies::Model {
+            id,
+            name: name.to_owned(),
+            version,
+            deleted_at: None,
+            created_at: at,
+            updated_at: at,
+            created_by: None,
+            updated_by: None,
+            tenant_id: TENANT,
+        }
+    }
+
+    #[tokio::test]
+    async fn update_bump_version_and_record_audit_when_version_matches() -> Result<(), DbErr> {
+        let id = Uuid::new_v4();
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results([
+                vec![company(id, "test-1", 3)],
+                vec![company(id, "renamed", 4)],
+            ])
+            .append_exec_results([MockExecResult {
+                last_insert_id: 0,
+                rows_affected: 1,
+            }])
+            .into_connection();
+
+        let result = {
+            let provider = RepositoryProvider::new(&db, TenantScope::Tenant(TENANT));
+            let company_repo = provider.company_repo();
+            company_repo
+                .update(id.into(), 3, CompanyName::new("renamed").unwrap())
+                .await
+        };
+
+        assert_eq!(result.unwrap(), 4);
+
+        let log = db.into_transaction_log();
+        assert_eq!(log.len(), 3);
+
+        let update = log[1].statements()[0].to_string();
+        assert!(update.starts_with(r#"UPDATE "companies" SET"#));
+        assert!(update.contains(r#"WHERE "companies"."id" = "#));
+        assert!(update.contains(&format!(r#"AND "companies"."tenant_id" = '{}'"#, TENANT)));
+        assert!(update.contains(r#"AND "companies"."version" = 3"#));
+
+        let audit = log[2].statements()[0].to_string();
+        assert!(audit.starts_with(r#"INSERT INTO "audit_log""#));
+        assert!(audit.contains(r#"'update'"#));
+        assert!(audit.contains(r#"{\"name\":\"test-1\"}"#));
+        assert!(audit.contains(r#"{\"name\":\"renamed\"}"#));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn update_fail_with_precondition_when_version_is_stale() -> Result<(), DbErr> {
+        let id = Uuid::new_v4();
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results([vec![company(id, "test-1", 4)]])
+            .into_connection();
+
+        let result = {
+            let provider = RepositoryProvider::new(&db, TenantScope::Tenant(TENANT));
+            provider
+                .company_repo()
+                .update(id.into(), 3, CompanyName::new("renamed").unwrap())
+                .await
+        };
+
+        assert!(matches!(
+            result,
+            Err(DomainError::CaseError(ErrorKind::PreconditionFailed, _, _))
+        ));
+
+        assert_eq!(db.into_transaction_log().len(), 1);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn update_fail_with_precondition_when_updated_concurrently() -> Result<(), DbErr> {
+        let id = Uuid::new_v4();
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results([vec![company(id, "test-1", 3)], vec![]])
+            .into_connection();
+
+        let provider = RepositoryProvider::new(&db, TenantScope::Tenant(TENANT));
+        let result = provider
+            .company_repo()
+            .update(id.into(), 3, CompanyName::new("renamed").unwrap())
+            .await;
+
+        assert!(matches!(
+            result,
+            Err(DomainError::CaseError(ErrorKind::PreconditionFailed, _, _))
+        ));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn delete_fail_with_not_found_when_company_is_missing() -> Result<(), DbErr> {
+        let id = Uuid::new_v4();
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results([Vec::<companies::Model>::new()])
+            .into_connection();
+
+        let provider = RepositoryProvider::new(&db, TenantScope::Tenant(TENANT));
+        let result = provider
+            .company_repo()
+            .delete(id.into(), 1, Utc::now())
+            .await;
+
+        assert!(matches!(result, Err(DomainError::NotFound(_))));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn restore_fail_when_company_is_not_deleted() -> Result<(), DbErr> {
+        let id = Uuid::new_v4();
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results([Vec::<companies::Model>::new()])
+            .into_connection();
+
+        let provider = RepositoryProvider::new(&db, TenantScope::Tenant(TENANT));
+        let result = provider.company_repo().restore(id.into()).await;
+
+        assert!(matches!(result, Err(DomainError::NotFound(_))));
+
+        assert_eq!(
+            db.into_transaction_log(),
+            [Transaction::from_sql_and_values(
+                DatabaseBackend::Postgres,
+                r#"SELECT "companies"."id", "companies"."name", "companies"."version", "companies"."deleted_at", "companies"."created_at", "companies"."updated_at", "companies"."created_by", "companies"."updated_by", "companies"."tenant_id" FROM "companies" WHERE "companies"."id" = $1 AND "companies"."tenant_id" = $2 AND "companies"."deleted_at" IS NOT NULL LIMIT $3"#,
+                [id.into(), TENANT.into(), 1u64.into()]
             ),]
         );
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn add_fail_when_not_bound_to_a_tenant() -> Result<(), DbErr> {
+        let db = MockDatabase::new(DatabaseBackend::Postgres).into_connection();
+
+        let result = {
+            let provider = RepositoryProvider::new(&db, TenantScope::All);
+            provider
+                .company_repo()
+                .add(Company::new(CompanyName::new("test-1").unwrap()))
+                .await
+        };
+
+        assert!(matches!(
+            result,
+            Err(DomainError::CaseError(ErrorKind::BadRequest, _, _))
+        ));
+        assert!(db.into_transaction_log().is_empty());
+
+        Ok(())
+    }
 }
@@ -0,0 +1,66 @@
+#[cfg(test)]
+mod event_log_repo_test_suite {
+    use chrono::Utc;
+    use lib::{
+        domain::events::OutboxMessage,
+        infrastructure::db::{RepositoryProvider, TenantScope},
+    };
+    use sea_orm::{DatabaseBackend, MockDatabase, MockExecResult};
+    use serde_json::json;
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn append_a_redelivered_message_once() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_exec_results([1, 1].map(|rows_affected| MockExecResult {
+                last_insert_id: 0,
+                rows_affected,
+            }))
+            .into_connection();
+        let message = OutboxMessage {
+            id: Uuid::new_v4(),
+            tenant_id: Some(Uuid::from_u128(1)),
+            event_type: "company.created".to_owned(),
+            aggregate_id: Uuid::new_v4(),
+            payload: json!({ "type": "company.created" }),
+            attempts: 2,
+            created_at: Utc::now().fixed_offset(),
+        };
+
+        let provider = RepositoryProvider::new(&db, TenantScope::All);
+        provider.event_log_repo().append(&message).await.unwrap();
+
+        let log = db.into_transaction_log();
+        // appends wait for one another before taking a number
+        let lock = log[0].statements()[0].to_string();
+        assert!(lock.contains("pg_advisory_xact_lock"), "{}", lock);
+        let insert = log[1].statements()[0].to_string();
+        assert!(!insert.contains(r#""seq""#), "{}", insert);
+        assert!(insert.contains(&message.id.to_string()));
+        assert!(insert.contains(r#"ON CONFLICT ("message_id") DO NOTHING"#));
+    }
+
+    #[tokio::test]
+    async fn purge_the_events_published_before() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_exec_results([MockExecResult {
+                last_insert_id: 0,
+                rows_affected: 3,
+            }])
+            .into_connection();
+        let before = Utc::now();
+
+        let provider = RepositoryProvider::new(&db, TenantScope::All);
+        let purged = provider.event_log_repo().purge(before).await.unwrap();
+
+        assert_eq!(purged, 3);
+        let log = db.into_transaction_log();
+        let delete = log[0].statements()[0].to_string();
+        assert!(
+            delete.starts_with(r#"DELETE FROM "event_log""#),
+            "{}",
+            delete
+        );
+        assert!(delete.contains(r#""created_at" <"#), "{}", delete);
+    }
+}
@@ -0,0 +1,116 @@
+#[cfg(test)]
+mod job_repo_test_suite {
+    use chrono::Utc;
+    use lib::{
+        domain::error::{DomainError, ErrorKind},
+        infrastructure::db::{RepositoryProvider, TenantScope, entities::jobs},
+    };
+    use sea_orm::{DatabaseBackend, MockDatabase, MockExecResult};
+    use uuid::Uuid;
+
+    const TENANT: Uuid = Uuid::from_u128(1);
+
+    fn job(id: Uuid, status: &str) -> jobs::Model {
+        let now = Utc::now().fixed_offset();
+
+        jobs::Model {
+            id,
+            tenant_id: Some(TENANT),
+            name: "greet".to_string(),
+            queue: "default".to_string(),
+            payload: serde_json::json!({}),
+            status: status.to_string(),
+            attempts: 1,
+            max_attempts: 5,
+            run_at: now,
+            locked_at: Some(now),
+            locked_by: None,
+            locked_until: None,
+            dedupe_key: None,
+            last_error: None,
+            created_at: now,
+            finished_at: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn cancel_fail_when_running() {
+        let id = Uuid::new_v4();
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_exec_results([MockExecResult {
+                last_insert_id: 0,
+                rows_affected: 0,
+            }])
+            .append_query_results([vec![job(id, "running")]])
+            .into_connection();
+
+        let provider = RepositoryProvider::new(&db, TenantScope::Tenant(TENANT));
+        let result = provider.job_repo().cancel(id).await;
+
+        assert!(matches!(
+            result,
+            Err(DomainError::CaseError(ErrorKind::Conflict, code, _)) if code == "JOB_NOT_CANCELLABLE"
+        ));
+    }
+
+    #[tokio::test]
+    async fn retry_fail_when_not_found() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_exec_results([MockExecResult {
+                last_insert_id: 0,
+                rows_affected: 0,
+            }])
+            .append_query_results([Vec::<jobs::Model>::new()])
+            .into_connection();
+
+        let provider = RepositoryProvider::new(&db, TenantScope::Tenant(TENANT));
+        let result = provider.job_repo().retry(Uuid::new_v4()).await;
+
+        assert!(matches!(result, Err(DomainError::NotFound(_))));
+    }
+
+    #[tokio::test]
+    async fn complete_fail_when_lease_lost() {
+        let (id, lease) = (Uuid::new_v4(), Uuid::new_v4());
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_exec_results([MockExecResult {
+                last_insert_id: 0,
+                rows_affected: 0,
+            }])
+            .into_connection();
+
+        let provider = RepositoryProvider::new(&db, TenantScope::All);
+        let result = provider.job_repo().complete(id, lease).await;
+
+        assert!(matches!(
+            result,
+            Err(DomainError::CaseError(ErrorKind::Conflict, code, _)) if code == "LEASE_LOST"
+        ));
+
+        let log = db.into_transaction_log();
+        let update = log[0].statements()[0].to_string();
+        assert!(
+            update.contains(&format!(r#""locked_by" = '{}'"#, lease)),
+            "{update}"
+        );
+        assert!(update.contains(r#""locked_until" > "#), "{update}");
+    }
+
+    #[tokio::test]
+    async fn fail_record_while_lease_held() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_exec_results([MockExecResult {
+                last_insert_id: 0,
+                rows_affected: 1,
+            }])
+            .into_connection();
+
+        let provider = RepositoryProvider::new(&db, TenantScope::All);
+        let result = provider
+            .job_repo()
+            .fail(Uuid::new_v4(), Uuid::new_v4(), "boom", None)
+            .await;
+
+        assert!(result.is_ok());
+    }
+}
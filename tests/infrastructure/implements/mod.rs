@@ -1 +1,4 @@
+mod audit_repo_impl;
 mod company_repo_impl;
+mod event_log_repo_impl;
+mod job_repo_impl;
\ No newline at end of file
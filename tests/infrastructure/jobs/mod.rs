@@ -0,0 +1,4 @@
+mod outbox;
+mod purge;
+mod queue;
+mod webhook;
\ No newline at end of file
@@ -0,0 +1,227 @@
+#[cfg(test)]
+mod outbox_dispatcher_test_suite {
+    use std::sync::{Arc, Mutex};
+
+    use async_trait::async_trait;
+    use chrono::Utc;
+    use lib::{
+        domain::events::{DomainEvent, OutboxMessage},
+        infrastructure::{
+            db::{DbContext, entities::outbox},
+            events::{EventBus, EventSink},
+            jobs::OutboxDispatcher,
+        },
+    };
+    use sea_orm::{DatabaseBackend, DatabaseConnection, DbErr, MockDatabase, MockExecResult};
+    use uuid::Uuid;
+
+    struct RecordingSink {
+        fail: bool,
+        received: Mutex<Vec<Uuid>>,
+    }
+
+    #[async_trait]
+    impl EventSink for RecordingSink {
+        fn name(&self) -> &str {
+            "recording"
+        }
+
+        async fn publish(&self, message: &OutboxMessage) -> anyhow::Result<()> {
+            self.received.lock().unwrap().push(message.id);
+
+            if self.fail {
+                anyhow::bail!("unavailable");
+            }
+            Ok(())
+        }
+    }
+
+    fn message(event: DomainEvent) -> outbox::Model {
+        let now = Utc::now().fixed_offset();
+
+        outbox::Model {
+            id: Uuid::new_v4(),
+            tenant_id: Some(Uuid::nil()),
+            event_type: event.event_type().to_string(),
+            aggregate_id: event.aggregate_id(),
+            payload: serde_json::to_value(&event).unwrap(),
+            status: "pending".to_string(),
+            attempts: 0,
+            next_attempt_at: now,
+            last_error: None,
+            created_at: now,
+            published_at: None,
+        }
+    }
+
+    // the claim, then one update per message
+    fn mock_db(messages: Vec<outbox::Model>) -> Arc<DatabaseConnection> {
+        let updates = messages.len() + 1;
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results([messages])
+            .append_exec_results((0..updates).map(|_| MockExecResult {
+                last_insert_id: 0,
+                rows_affected: 1,
+            }))
+            .into_connection();
+
+        Arc::new(db)
+    }
+
+    fn last_update(db: Arc<DatabaseConnection>) -> String {
+        let log = Arc::try_unwrap(db).unwrap().into_transaction_log();
+
+        log.iter()
+            .flat_map(|t| t.statements())
+            .map(|s| s.to_string())
+            .rfind(|s| s.starts_with("UPDATE"))
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn deliver_due_messages_to_every_sink() {
+        let id = Uuid::new_v4();
+        let pending = message(DomainEvent::CompanyCreated {
+            id,
+            name: "test-1".to_owned(),
+        });
+        let message_id = pending.id;
+
+        let bus = Arc::new(EventBus::new(8));
+        let mut subscriber = bus.subscribe();
+        let sink = Arc::new(RecordingSink {
+            fail: false,
+            received: Mutex::new(Vec::new()),
+        });
+
+        let db = mock_db(vec![pending]);
+        let dispatcher = OutboxDispatcher::new(Arc::new(DbContext::new(db.clone())))
+            .with_sink(bus)
+            .with_sink(sink.clone());
+
+        let result = dispatcher.run_once().await;
+        drop(dispatcher);
+
+        assert_eq!(result.unwrap(), 1);
+        assert_eq!(*sink.received.lock().unwrap(), [message_id]);
+
+        let delivered = subscriber.recv().await.unwrap();
+        assert_eq!(delivered.event_type, "company.created");
+        assert_eq!(delivered.aggregate_id, id);
+        assert_eq!(delivered.payload["data"]["name"], "test-1");
+
+        assert!(last_update(db).contains(r#"SET "status" = 'published'"#));
+    }
+
+    #[tokio::test]
+    async fn keep_message_for_retry_when_a_sink_fails() {
+        let pending = message(DomainEvent::DepartmentDeleted { id: Uuid::new_v4() });
+
+        let sink = Arc::new(RecordingSink {
+            fail: true,
+            received: Mutex::new(Vec::new()),
+        });
+
+        let db = mock_db(vec![pending]);
+        let dispatcher =
+            OutboxDispatcher::new(Arc::new(DbContext::new(db.clone()))).with_sink(sink.clone());
+
+        let result = dispatcher.run_once().await;
+        drop(dispatcher);
+
+        assert_eq!(result.unwrap(), 1);
+        assert_eq!(sink.received.lock().unwrap().len(), 1);
+
+        let update = last_update(db);
+        assert!(update.contains(r#""last_error" = 'recording: unavailable'"#));
+        assert!(update.contains(r#""next_attempt_at" = "#));
+        assert!(!update.contains(r#""status""#));
+    }
+
+    #[tokio::test]
+    async fn give_up_on_message_after_max_attempts() {
+        let mut pending = message(DomainEvent::DepartmentDeleted { id: Uuid::new_v4() });
+        pending.attempts = 2;
+
+        let sink = Arc::new(RecordingSink {
+            fail: true,
+            received: Mutex::new(Vec::new()),
+        });
+
+        let db = mock_db(vec![pending]);
+        let dispatcher = OutboxDispatcher::new(Arc::new(DbContext::new(db.clone())))
+            .with_sink(sink)
+            .with_max_attempts(3);
+
+        dispatcher.run_once().await.unwrap();
+        drop(dispatcher);
+
+        assert!(last_update(db).contains(r#""status" = 'dead'"#));
+    }
+
+    #[tokio::test]
+    async fn deliver_outside_of_the_claiming_transaction() {
+        let messages = vec![
+            message(DomainEvent::DepartmentDeleted { id: Uuid::new_v4() }),
+            message(DomainEvent::DepartmentDeleted { id: Uuid::new_v4() }),
+        ];
+
+        let db = mock_db(messages);
+        let dispatcher = OutboxDispatcher::new(Arc::new(DbContext::new(db.clone())));
+
+        assert_eq!(dispatcher.run_once().await.unwrap(), 2);
+        drop(dispatcher);
+
+        let log = Arc::try_unwrap(db).unwrap().into_transaction_log();
+        // the claim, then each outcome in a statement of its own
+        assert_eq!(log.len(), 3);
+
+        let claim: Vec<String> = log[0].statements().iter().map(|s| s.to_string()).collect();
+        assert!(claim[1].contains("FOR UPDATE SKIP LOCKED"), "{:?}", claim);
+        assert!(
+            claim[2].contains(r#"SET "next_attempt_at" = "#),
+            "{:?}",
+            claim
+        );
+        for outcome in &log[1..] {
+            let statements = outcome.statements();
+            assert_eq!(statements.len(), 1);
+            assert!(
+                statements[0]
+                    .to_string()
+                    .contains(r#""status" = 'published'"#)
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn a_failing_record_does_not_stop_the_batch() {
+        let messages = vec![
+            message(DomainEvent::DepartmentDeleted { id: Uuid::new_v4() }),
+            message(DomainEvent::DepartmentDeleted { id: Uuid::new_v4() }),
+        ];
+        let ids: Vec<Uuid> = messages.iter().map(|m| m.id).collect();
+
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results([messages])
+            .append_exec_results([MockExecResult {
+                last_insert_id: 0,
+                rows_affected: 2,
+            }])
+            .append_exec_errors([DbErr::Custom("connection reset".to_string())])
+            .append_exec_results([MockExecResult {
+                last_insert_id: 0,
+                rows_affected: 1,
+            }])
+            .into_connection();
+        let sink = Arc::new(RecordingSink {
+            fail: false,
+            received: Mutex::new(Vec::new()),
+        });
+        let dispatcher =
+            OutboxDispatcher::new(Arc::new(DbContext::new(Arc::new(db)))).with_sink(sink.clone());
+
+        assert_eq!(dispatcher.run_once().await.unwrap(), 2);
+        assert_eq!(*sink.received.lock().unwrap(), ids);
+    }
+}
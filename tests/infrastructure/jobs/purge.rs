@@ -0,0 +1,106 @@
+#[cfg(test)]
+mod purge_job_test_suite {
+    use std::sync::Arc;
+
+    use chrono::{Duration, Utc};
+    use lib::{
+        infrastructure::{
+            db::{
+                DbContext, TenantScope,
+                entities::{audit_log, companies, departments},
+            },
+            jobs::PurgeJob,
+            seed::{CompanyFactory, DepartmentFactory},
+        },
+        test_util::migrated_sqlite,
+    };
+    use sea_orm::{
+        ColumnTrait, DatabaseBackend, EntityTrait, MockDatabase, MockExecResult, QueryFilter,
+        sea_query::Expr,
+    };
+    use uuid::Uuid;
+
+    #[tokio::test]
+    async fn purge_departments_then_companies() {
+        let at = Utc::now().fixed_offset();
+        let deleted_at = Some((Utc::now() - Duration::days(31)).fixed_offset());
+        let company_id = Uuid::new_v4();
+
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results([(0..3)
+                .map(|i| departments::Model {
+                    id: Uuid::new_v4(),
+                    name: format!("dep-{}", i),
+                    company_id,
+                    version: 1,
+                    deleted_at,
+                    created_at: at,
+                    updated_at: at,
+                    created_by: None,
+                    updated_by: None,
+                    tenant_id: Uuid::new_v4(),
+                })
+                .collect::<Vec<_>>()])
+            .append_query_results([vec![companies::Model {
+                id: company_id,
+                name: "test-1".to_owned(),
+                version: 2,
+                deleted_at,
+                created_at: at,
+                updated_at: at,
+                created_by: None,
+                updated_by: None,
+                tenant_id: Uuid::new_v4(),
+            }]])
+            .append_exec_results([3, 1, 1, 1, 1, 1].into_iter().map(|rows_affected| {
+                MockExecResult {
+                    last_insert_id: 0,
+                    rows_affected,
+                }
+            }))
+            .into_connection();
+
+        let job = PurgeJob::new(Arc::new(DbContext::new(Arc::new(db))), 30);
+
+        let result = job.run_once().await;
+
+        assert_eq!(result.unwrap(), (1, 3));
+    }
+
+    #[tokio::test]
+    async fn departments_of_purged_companies_are_audited() {
+        let db_context = Arc::new(DbContext::new(Arc::new(migrated_sqlite().await)));
+
+        let provider = db_context.provider(TenantScope::Tenant(Uuid::nil()));
+        let company = CompanyFactory::new()
+            .deleted()
+            .create(&provider)
+            .await
+            .unwrap();
+        // still active, it would only go with the cascade on its company
+        let department = DepartmentFactory::new()
+            .company(company.id)
+            .create(&provider)
+            .await
+            .unwrap();
+        companies::Entity::update_many()
+            .col_expr(
+                companies::Column::DeletedAt,
+                Expr::value((Utc::now() - Duration::days(31)).fixed_offset()),
+            )
+            .exec(provider.c)
+            .await
+            .unwrap();
+
+        let result = PurgeJob::new(db_context.clone(), 30).run_once().await;
+
+        assert_eq!(result.unwrap(), (1, 1));
+        let purged = audit_log::Entity::find()
+            .filter(audit_log::Column::Action.eq("purge"))
+            .filter(audit_log::Column::EntityId.eq(department.id.as_uuid()))
+            .all(provider.c)
+            .await
+            .unwrap();
+        assert_eq!(purged.len(), 1);
+    }
+}
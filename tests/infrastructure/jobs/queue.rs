@@ -0,0 +1,175 @@
+#[cfg(test)]
+mod job_worker_pool_test_suite {
+    use std::sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    };
+
+    use async_trait::async_trait;
+    use chrono::Utc;
+    use lib::infrastructure::{
+        db::{DbContext, entities::jobs},
+        jobs::{Job, JobContext, JobRegistry, JobScheduler, JobWorkerPool},
+    };
+    use sea_orm::{DatabaseBackend, DatabaseConnection, MockDatabase, MockExecResult};
+    use serde::{Deserialize, Serialize};
+    use uuid::Uuid;
+
+    static GREETED: AtomicUsize = AtomicUsize::new(0);
+
+    #[derive(Serialize, Deserialize)]
+    struct Greet {
+        fail: bool,
+    }
+
+    #[async_trait]
+    impl Job for Greet {
+        const NAME: &'static str = "greet";
+
+        async fn run(self, _: JobContext) -> anyhow::Result<()> {
+            if self.fail {
+                anyhow::bail!("unreachable");
+            }
+
+            GREETED.fetch_add(1, Ordering::SeqCst);
+            Ok(())
+        }
+    }
+
+    fn job(name: &str, payload: serde_json::Value, attempts: i32) -> jobs::Model {
+        let now = Utc::now().fixed_offset();
+
+        jobs::Model {
+            id: Uuid::new_v4(),
+            tenant_id: None,
+            name: name.to_string(),
+            queue: "default".to_string(),
+            payload,
+            status: "pending".to_string(),
+            attempts,
+            max_attempts: 3,
+            run_at: now,
+            locked_at: None,
+            locked_by: None,
+            locked_until: None,
+            dedupe_key: None,
+            last_error: None,
+            created_at: now,
+            finished_at: None,
+        }
+    }
+
+    fn mock_db(job: jobs::Model) -> Arc<DatabaseConnection> {
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results([vec![job]])
+            .append_exec_results((0..2).map(|_| MockExecResult {
+                last_insert_id: 0,
+                rows_affected: 1,
+            }))
+            .into_connection();
+
+        Arc::new(db)
+    }
+
+    fn statements(db: Arc<DatabaseConnection>) -> Vec<String> {
+        let log = Arc::try_unwrap(db).unwrap().into_transaction_log();
+
+        log.iter()
+            .flat_map(|t| t.statements())
+            .map(|s| s.to_string())
+            .collect()
+    }
+
+    fn pool(db: &Arc<DatabaseConnection>) -> JobWorkerPool {
+        JobWorkerPool::new(
+            Arc::new(DbContext::new(db.clone())),
+            Arc::new(JobRegistry::new().register::<Greet>()),
+        )
+    }
+
+    #[tokio::test]
+    async fn run_job_success() {
+        let db = mock_db(job("greet", serde_json::json!({ "fail": false }), 0));
+
+        let pool = pool(&db);
+        let before = GREETED.load(Ordering::SeqCst);
+
+        let count = pool.run_once().await.unwrap();
+        assert_eq!(count, 1);
+        assert_eq!(GREETED.load(Ordering::SeqCst), before + 1);
+
+        drop(pool);
+        let statements = statements(db);
+        assert!(statements.iter().any(|s| s.contains("SKIP LOCKED")));
+        assert!(
+            statements
+                .iter()
+                .any(|s| s.contains("\"attempts\" = \"attempts\" + 1"))
+        );
+        assert!(statements.last().unwrap().contains("'completed'"));
+    }
+
+    #[tokio::test]
+    async fn run_job_retry_when_failed() {
+        let db = mock_db(job("greet", serde_json::json!({ "fail": true }), 0));
+
+        let pool = pool(&db);
+        pool.run_once().await.unwrap();
+
+        drop(pool);
+        let update = statements(db).pop().unwrap();
+        assert!(update.contains("'pending'"));
+        assert!(update.contains("\"run_at\""));
+        assert!(update.contains("unreachable"));
+    }
+
+    #[tokio::test]
+    async fn run_job_failed_when_attempts_exhausted() {
+        let db = mock_db(job("greet", serde_json::json!({ "fail": true }), 2));
+
+        let pool = pool(&db);
+        pool.run_once().await.unwrap();
+
+        drop(pool);
+        let update = statements(db).pop().unwrap();
+        assert!(update.contains("'failed'"));
+        assert!(update.contains("\"finished_at\""));
+    }
+
+    #[tokio::test]
+    async fn run_job_failed_when_not_registered() {
+        let db = mock_db(job("unknown", serde_json::json!({}), 0));
+
+        let pool = pool(&db);
+        pool.run_once().await.unwrap();
+
+        drop(pool);
+        let update = statements(db).pop().unwrap();
+        assert!(update.contains("no job registered as unknown"));
+    }
+
+    #[tokio::test]
+    async fn schedule_interval_enqueue_once_per_slot() {
+        let db = Arc::new(
+            MockDatabase::new(DatabaseBackend::Postgres)
+                .append_exec_results([MockExecResult {
+                    last_insert_id: 0,
+                    rows_affected: 1,
+                }])
+                .into_connection(),
+        );
+
+        let mut scheduler = JobScheduler::new(Arc::new(DbContext::new(db.clone())))
+            .with_interval(std::time::Duration::from_secs(3600), Greet { fail: false })
+            .unwrap();
+
+        assert_eq!(scheduler.run_once().await.unwrap(), 1);
+        // the next slot is an hour away
+        assert_eq!(scheduler.run_once().await.unwrap(), 0);
+
+        drop(scheduler);
+        let insert = statements(db).pop().unwrap();
+        assert!(insert.contains("ON CONFLICT (\"dedupe_key\") DO NOTHING"));
+        assert!(insert.contains("'greet@"));
+    }
+}
@@ -0,0 +1,216 @@
+#[cfg(test)]
+mod webhook_delivery_worker_test_suite {
+    use std::sync::{Arc, Mutex};
+
+    use axum::{
+        Router, body::Bytes, extract::State, http::HeaderMap, http::StatusCode, routing::post,
+    };
+    use chrono::Utc;
+    use lib::infrastructure::{
+        db::{
+            DbContext,
+            entities::{webhook_deliveries, webhook_subscriptions},
+        },
+        helpers::signature,
+        jobs::{SIGNATURE_HEADER, TIMESTAMP_HEADER, WebhookDeliveryWorker},
+    };
+    use sea_orm::{DatabaseBackend, DatabaseConnection, MockDatabase, MockExecResult};
+    use uuid::Uuid;
+
+    const SECRET: &str = "whsec_test";
+
+    type Received = Arc<Mutex<Vec<(HeaderMap, Bytes)>>>;
+
+    async fn receiver(status: StatusCode) -> (String, Received) {
+        let received: Received = Arc::default();
+
+        let app = Router::new()
+            .route(
+                "/hook",
+                post(
+                    move |State(received): State<Received>, headers: HeaderMap, body: Bytes| async move {
+                        received.lock().unwrap().push((headers, body));
+                        status
+                    },
+                ),
+            )
+            .with_state(received.clone());
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/hook", listener.local_addr().unwrap());
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+
+        (url, received)
+    }
+
+    fn mock_db(url: &str, attempts: i32) -> Arc<DatabaseConnection> {
+        let now = Utc::now().fixed_offset();
+        let subscription_id = Uuid::new_v4();
+
+        let delivery = webhook_deliveries::Model {
+            id: Uuid::new_v4(),
+            tenant_id: Uuid::nil(),
+            subscription_id,
+            message_id: Uuid::new_v4(),
+            event_type: "company.created".to_string(),
+            payload: serde_json::json!({ "type": "company.created", "data": { "name": "test" } }),
+            status: "pending".to_string(),
+            attempts,
+            next_attempt_at: now,
+            last_status_code: None,
+            last_error: None,
+            created_at: now,
+            delivered_at: None,
+        };
+        let subscription = webhook_subscriptions::Model {
+            id: subscription_id,
+            tenant_id: Uuid::nil(),
+            url: url.to_string(),
+            events: serde_json::json!(["company.*"]),
+            secret: SECRET.to_string(),
+            active: true,
+            created_at: now,
+            updated_at: now,
+        };
+
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results([vec![delivery]])
+            .append_query_results([vec![subscription]])
+            // the claim, then the outcome
+            .append_exec_results([
+                MockExecResult {
+                    last_insert_id: 0,
+                    rows_affected: 1,
+                },
+                MockExecResult {
+                    last_insert_id: 0,
+                    rows_affected: 1,
+                },
+            ])
+            .into_connection();
+
+        Arc::new(db)
+    }
+
+    fn last_update(db: Arc<DatabaseConnection>) -> String {
+        let log = Arc::try_unwrap(db).unwrap().into_transaction_log();
+
+        log.iter()
+            .flat_map(|t| t.statements())
+            .map(|s| s.to_string())
+            .rfind(|s| s.starts_with("UPDATE"))
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn deliver_signed_payload_success() {
+        let (url, received) = receiver(StatusCode::OK).await;
+        let db = mock_db(&url, 0);
+
+        let worker = WebhookDeliveryWorker::new(Arc::new(DbContext::new(db.clone())))
+            .unwrap()
+            .with_private_destinations(true)
+            .unwrap();
+
+        let count = worker.run_once().await.unwrap();
+        assert_eq!(count, 1);
+
+        let (headers, body) = received.lock().unwrap().pop().unwrap();
+        let timestamp: i64 = headers[TIMESTAMP_HEADER].to_str().unwrap().parse().unwrap();
+        let sig = headers[SIGNATURE_HEADER].to_str().unwrap();
+
+        assert_eq!(headers["x-webhook-event"], "company.created");
+        assert!(signature::verify(SECRET, timestamp, &body, sig));
+
+        drop(worker);
+        let update = last_update(db);
+        assert!(update.contains("'delivered'"));
+        assert!(update.contains("\"last_status_code\" = 200"));
+    }
+
+    #[tokio::test]
+    async fn deliver_retry_when_receiver_fails() {
+        let (url, received) = receiver(StatusCode::INTERNAL_SERVER_ERROR).await;
+        let db = mock_db(&url, 0);
+
+        let worker = WebhookDeliveryWorker::new(Arc::new(DbContext::new(db.clone())))
+            .unwrap()
+            .with_private_destinations(true)
+            .unwrap();
+
+        worker.run_once().await.unwrap();
+        assert_eq!(received.lock().unwrap().len(), 1);
+
+        drop(worker);
+        let update = last_update(db);
+        assert!(
+            update.contains("\"attempts\" = \"attempts\" + 1"),
+            "{update}"
+        );
+        assert!(update.contains("\"last_status_code\" = 500"));
+        assert!(!update.contains("'dead'"));
+    }
+
+    #[tokio::test]
+    async fn deliver_dead_when_attempts_exhausted() {
+        let (url, _) = receiver(StatusCode::BAD_GATEWAY).await;
+        let db = mock_db(&url, 2);
+
+        let worker = WebhookDeliveryWorker::new(Arc::new(DbContext::new(db.clone())))
+            .unwrap()
+            .with_private_destinations(true)
+            .unwrap()
+            .with_max_attempts(3);
+
+        worker.run_once().await.unwrap();
+
+        drop(worker);
+        let update = last_update(db);
+        assert!(update.contains("'dead'"));
+    }
+
+    #[tokio::test]
+    async fn deliver_refused_to_private_addresses() {
+        let (url, received) = receiver(StatusCode::OK).await;
+        let db = mock_db(&url, 0);
+
+        let worker = WebhookDeliveryWorker::new(Arc::new(DbContext::new(db.clone()))).unwrap();
+
+        assert_eq!(worker.run_once().await.unwrap(), 1);
+        assert!(received.lock().unwrap().is_empty());
+
+        drop(worker);
+        let update = last_update(db);
+        assert!(update.contains("is not a public address"), "{update}");
+        assert!(!update.contains("'delivered'"));
+    }
+
+    #[tokio::test]
+    async fn deliver_outside_of_the_claiming_transaction() {
+        let (url, _) = receiver(StatusCode::OK).await;
+        let db = mock_db(&url, 0);
+
+        let worker = WebhookDeliveryWorker::new(Arc::new(DbContext::new(db.clone())))
+            .unwrap()
+            .with_private_destinations(true)
+            .unwrap();
+        worker.run_once().await.unwrap();
+        drop(worker);
+
+        let log = Arc::try_unwrap(db).unwrap().into_transaction_log();
+        assert_eq!(log.len(), 2);
+
+        let claim: Vec<String> = log[0].statements().iter().map(|s| s.to_string()).collect();
+        assert!(
+            claim.iter().any(|s| s.contains("FOR UPDATE SKIP LOCKED")),
+            "{claim:?}"
+        );
+        assert!(
+            claim
+                .iter()
+                .any(|s| s.contains(r#"SET "next_attempt_at" = "#)),
+            "{claim:?}"
+        );
+        assert!(log[1].statements()[0].to_string().contains("'delivered'"));
+    }
+}
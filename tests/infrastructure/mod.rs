@@ -1,2 +1,5 @@
+mod db;
 mod helpers;
-mod implements;
\ No newline at end of file
+mod implements;
+mod jobs;
+mod seed;
\ No newline at end of file
@@ -0,0 +1,76 @@
+#[cfg(test)]
+mod dataset_test_suite {
+    use std::sync::Arc;
+
+    use lib::{
+        domain::{error::DomainError, organization::CompanyCriteria},
+        infrastructure::{
+            db::{DEFAULT_TENANT_ID, DbContext, TenantScope},
+            seed::{Dataset, SeedReport},
+        },
+        test_util::migrated_sqlite,
+    };
+
+    async fn company_names(db_context: &DbContext) -> Vec<String> {
+        let provider = db_context.provider(TenantScope::Tenant(DEFAULT_TENANT_ID));
+        let mut names: Vec<String> = provider
+            .company_repo()
+            .query(&CompanyCriteria::default())
+            .await
+            .unwrap()
+            .into_iter()
+            .map(|c| c.name.into_inner())
+            .collect();
+        names.sort();
+        names
+    }
+
+    #[tokio::test]
+    async fn the_demo_dataset_seeds() {
+        let db_context = DbContext::new(Arc::new(migrated_sqlite().await));
+        let dir = concat!(env!("CARGO_MANIFEST_DIR"), "/fixtures");
+
+        let report = Dataset::load(dir, "demo")
+            .unwrap()
+            .seed(&db_context)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            report,
+            SeedReport {
+                companies: 4,
+                departments: 6,
+                webhooks: 1,
+                jobs: 1,
+            }
+        );
+        assert_eq!(
+            company_names(&db_context).await,
+            ["Acme Corporation", "Globex", "Initech"]
+        );
+    }
+
+    #[tokio::test]
+    async fn a_bad_fixture_leaves_nothing_behind() {
+        let db_context = DbContext::new(Arc::new(migrated_sqlite().await));
+        let dataset = Dataset::from_json(
+            r#"{"companies": [{"name": "Acme"}], "webhooks": [{"events": ["compnay.*"]}]}"#,
+        )
+        .unwrap();
+
+        let err = dataset.seed(&db_context).await.unwrap_err();
+
+        assert!(matches!(err, DomainError::Invalid(_)));
+        assert!(company_names(&db_context).await.is_empty());
+    }
+
+    #[test]
+    fn unknown_fields_and_datasets_are_rejected() {
+        assert!(Dataset::from_yaml("companies:\n  - nmae: Acme\n").is_err());
+
+        let dir = concat!(env!("CARGO_MANIFEST_DIR"), "/fixtures");
+        assert!(Dataset::load(dir, "missing").is_err());
+        assert!(Dataset::load(dir, "../Cargo").is_err());
+    }
+}
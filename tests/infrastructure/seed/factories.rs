@@ -0,0 +1,89 @@
+#[cfg(test)]
+mod factories_test_suite {
+    use lib::{
+        domain::organization::CompanyCriteria,
+        infrastructure::{
+            db::{RepositoryProvider, TenantScope},
+            seed::{CompanyFactory, DepartmentFactory, JobFactory},
+        },
+        test_util::migrated_sqlite,
+    };
+    use uuid::Uuid;
+
+    const TENANT: Uuid = Uuid::from_u128(1);
+
+    #[test]
+    fn defaults_are_random_and_overrides_win() {
+        let a = CompanyFactory::new().build().unwrap();
+        let b = CompanyFactory::new().build().unwrap();
+        assert_ne!(a.id, b.id);
+        assert_ne!(a.name, b.name);
+
+        let company = CompanyFactory::new()
+            .name("Acme")
+            .deleted()
+            .build()
+            .unwrap();
+        assert_eq!(company.name.as_str(), "Acme");
+        assert!(company.is_deleted());
+
+        assert!(CompanyFactory::new().name(" ").build().is_err());
+    }
+
+    #[tokio::test]
+    async fn departments_get_a_company_when_none_is_given() {
+        let db = migrated_sqlite().await;
+        let provider = RepositoryProvider::new(&db, TenantScope::Tenant(TENANT));
+
+        let department = DepartmentFactory::new().create(&provider).await.unwrap();
+
+        let company = provider
+            .company_repo()
+            .find(department.company_id)
+            .await
+            .unwrap();
+        assert!(company.is_some());
+        let found = provider
+            .department_repo()
+            .find(department.id)
+            .await
+            .unwrap();
+        assert_eq!(found, Some(department));
+    }
+
+    #[tokio::test]
+    async fn created_rows_belong_to_the_provider_tenant() {
+        let db = migrated_sqlite().await;
+        let provider = RepositoryProvider::new(&db, TenantScope::Tenant(TENANT));
+        CompanyFactory::new().create(&provider).await.unwrap();
+        CompanyFactory::new()
+            .deleted()
+            .create(&provider)
+            .await
+            .unwrap();
+
+        let other = RepositoryProvider::new(&db, TenantScope::Tenant(Uuid::from_u128(2)));
+        let criteria = CompanyCriteria::default();
+        assert_eq!(
+            provider
+                .company_repo()
+                .query(&criteria)
+                .await
+                .unwrap()
+                .len(),
+            1
+        );
+        assert!(
+            other
+                .company_repo()
+                .query(&criteria)
+                .await
+                .unwrap()
+                .is_empty()
+        );
+
+        let job = JobFactory::new().dedupe_key("once");
+        assert!(job.clone().create(&provider).await.unwrap().is_some());
+        assert!(job.create(&provider).await.unwrap().is_none());
+    }
+}
@@ -0,0 +1,2 @@
+mod dataset;
+mod factories;
\ No newline at end of file
@@ -1,2 +1,4 @@
 mod infrastructure;
-mod application;
\ No newline at end of file
+mod application;
+mod domain;
+mod presentation;
\ No newline at end of file
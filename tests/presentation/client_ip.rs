@@ -0,0 +1,120 @@
+#[cfg(test)]
+mod client_ip_test_suite {
+    use std::{
+        net::{IpAddr, SocketAddr},
+        sync::Arc,
+    };
+
+    use axum::http::{HeaderMap, StatusCode, header};
+    use lib::{
+        infrastructure::db::DbContext,
+        presentation::middlewares::client_ip::TrustedProxies,
+        test_util::{TEST_PEER, TestApp},
+    };
+    use sea_orm::{DatabaseBackend, MockDatabase};
+
+    fn forwarded_for(hops: &[&str]) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        for hop in hops {
+            headers.append("x-forwarded-for", hop.parse().unwrap());
+        }
+        headers
+    }
+
+    fn ip(s: &str) -> IpAddr {
+        s.parse().unwrap()
+    }
+
+    fn app(proxies: &[&str]) -> TestApp {
+        let db = MockDatabase::new(DatabaseBackend::Postgres).into_connection();
+        let mut config = TestApp::config();
+        config.trusted_proxies = proxies.iter().map(|p| p.parse().unwrap()).collect();
+
+        TestApp::with_config(DbContext::new(Arc::new(db)), &config)
+    }
+
+    /// Signs in until the limit of 10 per minute is reached.
+    async fn exhaust(app: &TestApp, peer: SocketAddr, forwarded: impl Fn(usize) -> String) {
+        for i in 0..10 {
+            app.get("/api/v1/signin")
+                .peer(peer)
+                .header("x-forwarded-for", &forwarded(i))
+                .send()
+                .await
+                .assert_status(StatusCode::OK);
+        }
+    }
+
+    #[test]
+    fn untrusted_peers_are_the_client() {
+        let proxies = TrustedProxies::new(vec!["10.0.0.0/8".parse().unwrap()]);
+
+        let client = proxies.resolve(ip("203.0.113.7"), &forwarded_for(&["198.51.100.1"]));
+
+        assert_eq!(client, ip("203.0.113.7"));
+    }
+
+    #[test]
+    fn trusted_hops_are_walked_from_the_right() {
+        let proxies = TrustedProxies::new(vec![
+            "10.0.0.0/8".parse().unwrap(),
+            "192.168.1.10/32".parse().unwrap(),
+        ]);
+
+        // the left-most entry is whatever the client sent, only the hops appended by the
+        // trusted proxies are believed
+        let headers = forwarded_for(&["1.2.3.4, 198.51.100.1", "192.168.1.10"]);
+        assert_eq!(
+            proxies.resolve(ip("10.0.0.2"), &headers),
+            ip("198.51.100.1")
+        );
+
+        let headers = forwarded_for(&["198.51.100.1, garbage, 10.0.0.3"]);
+        assert_eq!(proxies.resolve(ip("10.0.0.2"), &headers), ip("10.0.0.3"));
+
+        assert_eq!(
+            proxies.resolve(ip("10.0.0.2"), &HeaderMap::new()),
+            ip("10.0.0.2")
+        );
+    }
+
+    #[tokio::test]
+    async fn rotating_the_forwarded_header_shares_the_peer_bucket() {
+        let app = app(&[]);
+
+        exhaust(&app, TEST_PEER, |i| format!("198.51.100.{}", i)).await;
+
+        let res = app
+            .get("/api/v1/signin")
+            .header("x-forwarded-for", "198.51.100.200")
+            .send()
+            .await;
+        res.assert_status(StatusCode::TOO_MANY_REQUESTS);
+        assert!(res.headers.contains_key(header::RETRY_AFTER));
+
+        // another peer is not locked out
+        app.get("/api/v1/signin")
+            .peer(SocketAddr::from(([203, 0, 113, 7], 40000)))
+            .send()
+            .await
+            .assert_status(StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn clients_behind_a_trusted_proxy_are_limited_apart() {
+        let app = app(&["127.0.0.1/32"]);
+
+        exhaust(&app, TEST_PEER, |_| "198.51.100.1".to_string()).await;
+
+        app.get("/api/v1/signin")
+            .header("x-forwarded-for", "198.51.100.1")
+            .send()
+            .await
+            .assert_status(StatusCode::TOO_MANY_REQUESTS);
+        app.get("/api/v1/signin")
+            .header("x-forwarded-for", "198.51.100.2")
+            .send()
+            .await
+            .assert_status(StatusCode::OK);
+    }
+}
@@ -0,0 +1,150 @@
+#[cfg(test)]
+mod conditional_test_suite {
+    use std::future::ready;
+
+    use axum::http::{StatusCode, header};
+    use lib::{
+        domain::{
+            error::{DomainError, ErrorKind},
+            organization::Department,
+        },
+        infrastructure::{db::TenantScope, seed::DepartmentFactory},
+        presentation::middlewares::conditional::IfMatch,
+        test_util::TestApp,
+    };
+    use serde_json::json;
+
+    async fn with_department() -> (TestApp, Department) {
+        let app = TestApp::sqlite().await;
+        let department = DepartmentFactory::new()
+            .create(
+                &app.state
+                    .db_context
+                    .provider(TenantScope::Tenant(Default::default())),
+            )
+            .await
+            .unwrap();
+
+        (app, department)
+    }
+
+    #[test]
+    fn if_match_parses_lists_and_keeps_only_strong_tags() {
+        assert_eq!(IfMatch::parse(" * "), Some(IfMatch::Any));
+        assert_eq!(IfMatch::parse(r#""3""#), Some(IfMatch::version(3)));
+        assert_eq!(IfMatch::parse("3"), Some(IfMatch::version(3)));
+        assert_eq!(
+            IfMatch::parse(r#""1", W/"2", "3""#),
+            Some(IfMatch::Versions(vec![1, 3]))
+        );
+        assert_eq!(
+            IfMatch::parse(r#""abc", W/"2""#),
+            Some(IfMatch::Versions(vec![]))
+        );
+
+        assert_eq!(IfMatch::parse(""), None);
+        assert_eq!(IfMatch::parse("abc"), None);
+        assert_eq!(IfMatch::parse(r#"W/2"#), None);
+    }
+
+    #[tokio::test]
+    async fn if_match_resolves_against_the_current_version() {
+        // a single tag is left to the repository, without reading the current version
+        let version = IfMatch::version(2)
+            .resolve(async { unreachable!("not read for a single tag") })
+            .await
+            .unwrap();
+        assert_eq!(version, 2);
+
+        let current = || ready(Ok(Some(3)));
+        assert_eq!(IfMatch::Any.resolve(current()).await.unwrap(), 3);
+        assert_eq!(
+            IfMatch::Versions(vec![1, 3])
+                .resolve(current())
+                .await
+                .unwrap(),
+            3
+        );
+
+        for (if_match, current) in [
+            (IfMatch::Versions(vec![]), Some(3)),
+            (IfMatch::Versions(vec![1, 2]), Some(3)),
+            (IfMatch::Any, None),
+        ] {
+            let err = if_match.resolve(ready(Ok(current))).await.unwrap_err();
+            assert!(matches!(
+                err,
+                DomainError::CaseError(ErrorKind::PreconditionFailed, _, _)
+            ));
+        }
+    }
+
+    #[tokio::test]
+    async fn department_writes_require_a_matching_if_match() {
+        let (app, department) = with_department().await;
+        let token = app.token(&[]);
+        let uri = format!("/api/v1/departments/{}", department.id);
+        let body = json!({ "name": "Renamed", "company_id": department.company_id.as_uuid() });
+
+        app.put(&uri)
+            .bearer(&token)
+            .json(&body)
+            .send()
+            .await
+            .assert_error(StatusCode::PRECONDITION_REQUIRED, "PRECONDITION_REQUIRED");
+
+        for stale in [r#""2""#, r#"W/"1""#] {
+            app.put(&uri)
+                .bearer(&token)
+                .header(header::IF_MATCH, stale)
+                .json(&body)
+                .send()
+                .await
+                .assert_error(StatusCode::PRECONDITION_FAILED, "VERSION_MISMATCH");
+        }
+
+        let res = app
+            .put(&uri)
+            .bearer(&token)
+            .header(header::IF_MATCH, r#""1""#)
+            .json(&body)
+            .send()
+            .await;
+        res.assert_status(StatusCode::NO_CONTENT);
+        assert_eq!(res.headers[header::ETAG], r#""2""#);
+
+        app.delete(&uri)
+            .bearer(&token)
+            .header(header::IF_MATCH, r#""1""#)
+            .send()
+            .await
+            .assert_error(StatusCode::PRECONDITION_FAILED, "VERSION_MISMATCH");
+        app.delete(&uri)
+            .bearer(&token)
+            .send()
+            .await
+            .assert_error(StatusCode::PRECONDITION_REQUIRED, "PRECONDITION_REQUIRED");
+    }
+
+    #[tokio::test]
+    async fn a_star_matches_any_version_of_an_existing_department() {
+        let (app, department) = with_department().await;
+        let token = app.token(&[]);
+        let uri = format!("/api/v1/departments/{}", department.id);
+
+        app.delete(&uri)
+            .bearer(&token)
+            .header(header::IF_MATCH, "*")
+            .send()
+            .await
+            .assert_status(StatusCode::NO_CONTENT);
+
+        // gone now, so `*` no longer matches
+        app.delete(&uri)
+            .bearer(&token)
+            .header(header::IF_MATCH, "*")
+            .send()
+            .await
+            .assert_error(StatusCode::PRECONDITION_FAILED, "VERSION_MISMATCH");
+    }
+}
@@ -0,0 +1,115 @@
+#[cfg(test)]
+mod consistency_test_suite {
+    use std::{sync::Arc, time::Duration};
+
+    use axum::http::{StatusCode, header};
+    use lib::{
+        infrastructure::db::DbContext,
+        presentation::middlewares::consistency::LAST_WRITE_HEADER,
+        test_util::{TestApp, TestResponse, migrated_sqlite},
+    };
+    use serde_json::json;
+
+    /// A replica that never caught up with the primary.
+    async fn lagging_app() -> TestApp {
+        let db_context = DbContext::new(Arc::new(migrated_sqlite().await))
+            .with_replicas(vec![Arc::new(migrated_sqlite().await)])
+            .with_read_your_writes(Duration::from_secs(60));
+
+        TestApp::with_db_context(db_context)
+    }
+
+    async fn add_company(app: &TestApp, token: &str) -> TestResponse {
+        let res = app
+            .post("/api/v1/companies")
+            .bearer(token)
+            .json(&json!({ "name": "Acme" }))
+            .send()
+            .await;
+        res.assert_status(StatusCode::CREATED);
+        res
+    }
+
+    #[tokio::test]
+    async fn writers_read_from_the_primary_with_their_marker() {
+        let app = lagging_app().await;
+        let token = app.token(&["admin"]);
+
+        let res = add_company(&app, &token).await;
+        let location = res.headers[header::LOCATION].to_str().unwrap().to_string();
+        let marker = res.headers[LAST_WRITE_HEADER].to_str().unwrap().to_string();
+        let cookie = res.headers[header::SET_COOKIE]
+            .to_str()
+            .unwrap()
+            .to_string();
+        assert!(
+            cookie.starts_with(&format!("last_write={};", marker)),
+            "{}",
+            cookie
+        );
+        assert!(cookie.contains("Max-Age=60"), "{}", cookie);
+
+        app.get(&location)
+            .bearer(&token)
+            .header(LAST_WRITE_HEADER, &marker)
+            .send()
+            .await
+            .assert_status(StatusCode::OK);
+        app.get(&location)
+            .bearer(&token)
+            .header(
+                header::COOKIE,
+                &format!("theme=dark; last_write={}", marker),
+            )
+            .send()
+            .await
+            .assert_status(StatusCode::OK);
+
+        // without it, whoever wrote, the read goes to the replica
+        app.get(&location)
+            .bearer(&token)
+            .send()
+            .await
+            .assert_status(StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn old_markers_are_ignored() {
+        let app = lagging_app().await;
+        let token = app.token(&["admin"]);
+
+        let res = add_company(&app, &token).await;
+        let location = res.headers[header::LOCATION].to_str().unwrap().to_string();
+        let marker: i64 = res.headers[LAST_WRITE_HEADER]
+            .to_str()
+            .unwrap()
+            .parse()
+            .unwrap();
+
+        app.get(&location)
+            .bearer(&token)
+            .header(LAST_WRITE_HEADER, &(marker - 61_000).to_string())
+            .send()
+            .await
+            .assert_status(StatusCode::NOT_FOUND);
+        app.get(&location)
+            .bearer(&token)
+            .header(LAST_WRITE_HEADER, "yesterday")
+            .send()
+            .await
+            .assert_status(StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn nothing_is_told_without_replicas() {
+        let app = TestApp::with_db_context(
+            DbContext::new(Arc::new(migrated_sqlite().await))
+                .with_read_your_writes(Duration::from_secs(60)),
+        );
+
+        let res = add_company(&app, &app.token(&["admin"])).await;
+
+        assert!(!res.headers.contains_key(LAST_WRITE_HEADER));
+        assert!(!res.headers.contains_key(header::SET_COOKIE));
+    }
+}
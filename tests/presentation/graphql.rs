@@ -0,0 +1,162 @@
+#[cfg(test)]
+mod graphql_test_suite {
+    use std::sync::Arc;
+
+    use axum::{
+        Extension, Router,
+        body::{Body, to_bytes},
+        http::{Request, header},
+        routing::post,
+    };
+    use chrono::Utc;
+    use lib::{
+        infrastructure::{
+            db::{
+                DbContext,
+                entities::{companies, departments},
+            },
+            events::EventBus,
+            helpers::token::JwtHelper,
+        },
+        presentation::{
+            graphql::{build_schema, graphql_handler},
+            guards::UserInfo,
+            http::AppState,
+        },
+    };
+    use sea_orm::{DatabaseBackend, DatabaseConnection, MockDatabase};
+    use serde_json::{Value, json};
+    use tower_service::Service;
+    use uuid::Uuid;
+
+    fn company(name: &str) -> companies::Model {
+        companies::Model {
+            id: Uuid::new_v4(),
+            name: name.to_owned(),
+            version: 1,
+            deleted_at: None,
+            created_at: Utc::now().fixed_offset(),
+            updated_at: Utc::now().fixed_offset(),
+            created_by: None,
+            updated_by: None,
+            tenant_id: Uuid::nil(),
+        }
+    }
+
+    fn department(name: &str, company_id: Uuid) -> departments::Model {
+        departments::Model {
+            id: Uuid::new_v4(),
+            name: name.to_owned(),
+            company_id,
+            version: 1,
+            deleted_at: None,
+            created_at: Utc::now().fixed_offset(),
+            updated_at: Utc::now().fixed_offset(),
+            created_by: None,
+            updated_by: None,
+            tenant_id: Uuid::nil(),
+        }
+    }
+
+    async fn execute(
+        db: Arc<DatabaseConnection>,
+        roles: &[&str],
+        max_depth: usize,
+        query: &str,
+    ) -> Value {
+        let state = AppState {
+            db_context: Arc::new(DbContext::new(db)),
+            jwt_helper: Arc::new(JwtHelper::new("secret".to_owned())),
+            event_bus: Arc::new(EventBus::new(8)),
+        };
+        let user = UserInfo {
+            id: "logon-user".to_owned(),
+            roles: roles.iter().map(|r| r.to_string()).collect(),
+            tenant_id: Uuid::nil(),
+        };
+
+        let mut router = Router::new()
+            .route("/graphql", post(graphql_handler))
+            .layer(Extension(build_schema(max_depth, 250)))
+            .layer(Extension(user))
+            .with_state(state);
+
+        let req = Request::post("/graphql")
+            .header(header::CONTENT_TYPE, "application/json")
+            .body(Body::from(json!({ "query": query }).to_string()))
+            .unwrap();
+        let res = router.call(req).await.unwrap();
+        let body = to_bytes(res.into_body(), usize::MAX).await.unwrap();
+
+        serde_json::from_slice(&body).unwrap()
+    }
+
+    #[tokio::test]
+    async fn departments_of_all_companies_are_loaded_at_once() {
+        let acme = company("acme");
+        let globex = company("globex");
+        let db = Arc::new(
+            MockDatabase::new(DatabaseBackend::Postgres)
+                .append_query_results([vec![acme.clone(), globex.clone()]])
+                .append_query_results([vec![
+                    department("sales", acme.id),
+                    department("support", acme.id),
+                    department("research", globex.id),
+                ]])
+                .into_connection(),
+        );
+
+        let res = execute(
+            db.clone(),
+            &["admin"],
+            8,
+            "{ companies { name departments { name } } }",
+        )
+        .await;
+
+        assert!(res.get("errors").is_none(), "{}", res);
+        assert_eq!(
+            res["data"]["companies"],
+            json!([
+                { "name": "acme", "departments": [{ "name": "sales" }, { "name": "support" }] },
+                { "name": "globex", "departments": [{ "name": "research" }] },
+            ])
+        );
+
+        let log = Arc::try_unwrap(db).unwrap().into_transaction_log();
+        assert_eq!(log.len(), 2);
+        let departments_query = log[1].statements()[0].to_string();
+        assert!(departments_query.contains(r#""departments"."company_id" IN"#));
+        assert!(departments_query.contains(&acme.id.to_string()));
+        assert!(departments_query.contains(&globex.id.to_string()));
+    }
+
+    #[tokio::test]
+    async fn companies_require_the_admin_role() {
+        let db = Arc::new(MockDatabase::new(DatabaseBackend::Postgres).into_connection());
+
+        let res = execute(db, &["user"], 8, "{ companies { name } }").await;
+
+        assert_eq!(res["errors"][0]["extensions"]["code"], "FORBIDDEN");
+        assert_eq!(res["errors"][0]["extensions"]["status"], 403);
+    }
+
+    #[tokio::test]
+    async fn queries_deeper_than_the_limit_are_rejected() {
+        let db = Arc::new(MockDatabase::new(DatabaseBackend::Postgres).into_connection());
+        let query = format!(
+            r#"{{ department(id: "{}") {{ company {{ departments {{ company {{ name }} }} }} }} }}"#,
+            Uuid::new_v4()
+        );
+
+        let res = execute(db, &["admin"], 3, &query).await;
+
+        assert!(res["data"].is_null());
+        assert!(
+            res["errors"][0]["message"]
+                .as_str()
+                .unwrap()
+                .contains("nested too deep")
+        );
+    }
+}
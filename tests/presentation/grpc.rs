@@ -0,0 +1,322 @@
+#[cfg(test)]
+mod grpc_test_suite {
+    use std::{
+        net::SocketAddr,
+        sync::{Arc, Mutex},
+    };
+
+    use async_trait::async_trait;
+    use axum::{Router, routing::get, serve};
+    use chrono::{DateTime, Utc};
+    use lib::{
+        domain::{
+            error::DomainError,
+            events::{DomainEvent, OutboxMessage, repositories::OutboxRepository},
+        },
+        infrastructure::{
+            db::{AuditContext, DbContext, RepositoryOverrides, entities::companies},
+            events::EventBus,
+            helpers::token::JwtHelper,
+        },
+        presentation::{
+            grpc::{
+                ERROR_CODE_METADATA, grpc_routes,
+                proto::{
+                    CreateCompanyRequest, IdRequest, ListCompaniesRequest,
+                    company_service_client::CompanyServiceClient,
+                },
+            },
+            http::AppState,
+            middlewares::rate_limit::RateLimit,
+        },
+    };
+    use sea_orm::{DatabaseBackend, MockDatabase, MockExecResult};
+    use tonic::{Code, Request, transport::Channel};
+    use uuid::Uuid;
+
+    const SECRET: &str = "secret";
+
+    fn company(name: &str) -> companies::Model {
+        companies::Model {
+            id: Uuid::new_v4(),
+            name: name.to_owned(),
+            version: 3,
+            deleted_at: None,
+            created_at: Utc::now().fixed_offset(),
+            updated_at: Utc::now().fixed_offset(),
+            created_by: None,
+            updated_by: None,
+            tenant_id: Uuid::nil(),
+        }
+    }
+
+    async fn serve_app(db: MockDatabase) -> String {
+        serve_context(
+            DbContext::new(Arc::new(db.into_connection())),
+            RateLimit::per_minute(600),
+        )
+        .await
+    }
+
+    /// Serves the gRPC services next to a REST route, like `HttpServer` does.
+    async fn serve_context(db_context: DbContext, limit: RateLimit) -> String {
+        let state = AppState {
+            db_context: Arc::new(db_context),
+            jwt_helper: Arc::new(JwtHelper::new(SECRET.to_owned())),
+            event_bus: Arc::new(EventBus::new(8)),
+        };
+        let router = Router::new()
+            .route("/api/healthy", get(|| async { "I'm alive!" }))
+            .merge(grpc_routes(state, limit));
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move {
+            serve(
+                listener,
+                router.into_make_service_with_connect_info::<SocketAddr>(),
+            )
+            .await
+            .unwrap()
+        });
+
+        format!("http://{}", addr)
+    }
+
+    async fn client(url: &str) -> CompanyServiceClient<Channel> {
+        CompanyServiceClient::connect(url.to_owned()).await.unwrap()
+    }
+
+    fn authorized<T>(message: T, roles: &[&str]) -> Request<T> {
+        let token = JwtHelper::new(SECRET.to_owned())
+            .generate_for_tenant(
+                "logon-user".to_owned(),
+                Some(Uuid::nil()),
+                roles.iter().map(|r| r.to_string()).collect(),
+            )
+            .unwrap();
+
+        let mut req = Request::new(message);
+        req.metadata_mut().insert(
+            "authorization",
+            format!("Bearer {}", token).parse().unwrap(),
+        );
+        req
+    }
+
+    #[tokio::test]
+    async fn company_is_read_next_to_the_rest_api() {
+        let acme = company("acme");
+        let url = serve_app(
+            MockDatabase::new(DatabaseBackend::Postgres).append_query_results([vec![acme.clone()]]),
+        )
+        .await;
+
+        let res = client(&url)
+            .await
+            .get_company(authorized(
+                IdRequest {
+                    id: acme.id.to_string(),
+                },
+                &[],
+            ))
+            .await
+            .unwrap();
+
+        assert_eq!(res.metadata().get("etag").unwrap(), "\"3\"");
+        let company = res.into_inner();
+        assert_eq!(company.id, acme.id.to_string());
+        assert_eq!(company.name, "acme");
+        assert_eq!(company.version, 3);
+
+        let rest = reqwest::get(format!("{}/api/healthy", url)).await.unwrap();
+        assert_eq!(rest.text().await.unwrap(), "I'm alive!");
+    }
+
+    #[tokio::test]
+    async fn calls_without_a_token_are_unauthenticated() {
+        let url = serve_app(MockDatabase::new(DatabaseBackend::Postgres)).await;
+
+        let status = client(&url)
+            .await
+            .get_company(Request::new(IdRequest {
+                id: Uuid::new_v4().to_string(),
+            }))
+            .await
+            .unwrap_err();
+
+        assert_eq!(status.code(), Code::Unauthenticated);
+        assert_eq!(
+            status.metadata().get(ERROR_CODE_METADATA).unwrap(),
+            "UNAUTHORIZED"
+        );
+    }
+
+    #[tokio::test]
+    async fn companies_require_the_roles_of_their_routes() {
+        let url = serve_app(MockDatabase::new(DatabaseBackend::Postgres)).await;
+        let mut client = client(&url).await;
+
+        let status = client
+            .list_companies(authorized(ListCompaniesRequest { name: None }, &["user"]))
+            .await
+            .unwrap_err();
+        assert_eq!(status.code(), Code::PermissionDenied);
+
+        let status = client
+            .create_company(authorized(
+                CreateCompanyRequest {
+                    name: "acme".to_owned(),
+                },
+                &["user"],
+            ))
+            .await
+            .unwrap_err();
+        assert_eq!(status.code(), Code::PermissionDenied);
+    }
+
+    #[tokio::test]
+    async fn domain_errors_keep_their_meaning() {
+        let url = serve_app(
+            MockDatabase::new(DatabaseBackend::Postgres)
+                .append_query_results([Vec::<companies::Model>::new()]),
+        )
+        .await;
+        let mut client = client(&url).await;
+
+        let missing = client
+            .get_company(authorized(
+                IdRequest {
+                    id: Uuid::new_v4().to_string(),
+                },
+                &[],
+            ))
+            .await
+            .unwrap_err();
+        assert_eq!(missing.code(), Code::NotFound);
+
+        let malformed = client
+            .get_company(authorized(
+                IdRequest {
+                    id: "not-an-id".to_owned(),
+                },
+                &[],
+            ))
+            .await
+            .unwrap_err();
+        assert_eq!(malformed.code(), Code::InvalidArgument);
+        assert_eq!(
+            malformed.metadata().get(ERROR_CODE_METADATA).unwrap(),
+            "INPUT_PARSE_FAIL"
+        );
+    }
+
+    #[tokio::test]
+    async fn calls_past_the_limit_are_exhausted() {
+        let url = serve_context(
+            DbContext::new(Arc::new(
+                MockDatabase::new(DatabaseBackend::Postgres).into_connection(),
+            )),
+            RateLimit::per_minute(2),
+        )
+        .await;
+        let mut client = client(&url).await;
+
+        for _ in 0..2 {
+            let status = client
+                .list_companies(authorized(ListCompaniesRequest { name: None }, &["user"]))
+                .await
+                .unwrap_err();
+            assert_eq!(status.code(), Code::PermissionDenied);
+        }
+
+        let status = client
+            .list_companies(authorized(ListCompaniesRequest { name: None }, &["user"]))
+            .await
+            .unwrap_err();
+        assert_eq!(status.code(), Code::ResourceExhausted);
+        assert_eq!(
+            status.metadata().get(ERROR_CODE_METADATA).unwrap(),
+            "TOO_MANY_REQUESTS"
+        );
+        assert!(status.metadata().get("retry-after").is_some());
+
+        // anonymous callers are counted apart, by their address
+        let status = client
+            .get_company(Request::new(IdRequest {
+                id: Uuid::new_v4().to_string(),
+            }))
+            .await
+            .unwrap_err();
+        assert_eq!(status.code(), Code::Unauthenticated);
+    }
+
+    /// Records who the events were added on behalf of.
+    #[derive(Default)]
+    struct AuditedOutbox(Mutex<Vec<Option<String>>>);
+
+    #[async_trait]
+    impl OutboxRepository for AuditedOutbox {
+        async fn add(&self, _: DomainEvent) -> Result<(), DomainError> {
+            self.0.lock().unwrap().push(AuditContext::current().actor);
+            Ok(())
+        }
+
+        async fn add_many(&self, events: Vec<DomainEvent>) -> Result<(), DomainError> {
+            for event in events {
+                self.add(event).await?;
+            }
+            Ok(())
+        }
+
+        async fn claim_due(
+            &self,
+            _: u64,
+            _: DateTime<Utc>,
+        ) -> Result<Vec<OutboxMessage>, DomainError> {
+            Ok(Vec::new())
+        }
+
+        async fn mark_published(&self, id: Uuid) -> Result<(), DomainError> {
+            Err(DomainError::NotFound(format!("{} was never claimed", id)))
+        }
+
+        async fn mark_failed(
+            &self,
+            id: Uuid,
+            _: &str,
+            _: Option<DateTime<Utc>>,
+        ) -> Result<(), DomainError> {
+            Err(DomainError::NotFound(format!("{} was never claimed", id)))
+        }
+    }
+
+    #[tokio::test]
+    async fn writes_run_on_behalf_of_the_caller() {
+        let outbox = Arc::new(AuditedOutbox::default());
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_query_results([vec![company("acme")]])
+            .append_exec_results([MockExecResult {
+                last_insert_id: 0,
+                rows_affected: 1,
+            }])
+            .into_connection();
+        let db_context = DbContext::new(Arc::new(db)).with_repository_overrides(
+            RepositoryOverrides::default().with_outbox_repo(outbox.clone()),
+        );
+        let url = serve_context(db_context, RateLimit::per_minute(600)).await;
+
+        client(&url)
+            .await
+            .create_company(authorized(
+                CreateCompanyRequest {
+                    name: "acme".to_owned(),
+                },
+                &["admin"],
+            ))
+            .await
+            .unwrap();
+
+        assert_eq!(*outbox.0.lock().unwrap(), [Some("logon-user".to_owned())]);
+    }
+}
@@ -0,0 +1,9 @@
+mod client_ip;
+mod conditional;
+mod consistency;
+mod graphql;
+mod grpc;
+mod openapi;
+mod registry;
+mod response;
+mod test_app;
\ No newline at end of file
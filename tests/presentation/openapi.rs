@@ -0,0 +1,220 @@
+#[cfg(test)]
+mod openapi_test_suite {
+    use std::sync::Arc;
+
+    use axum::{
+        body::Body,
+        http::{Method, Request, StatusCode, header},
+    };
+    use lib::{
+        infrastructure::{db::DbContext, events::EventBus, helpers::token::JwtHelper},
+        presentation::http::{
+            AppState,
+            routes::v1::{v1_registry, v1_routes},
+        },
+        test_util::TestApp,
+    };
+    use sea_orm::{DatabaseBackend, MockDatabase};
+    use serde_json::json;
+    use tower_service::Service;
+
+    #[test]
+    fn documents_validator_constraints() {
+        let doc = v1_registry().openapi();
+        let schemas = &doc["components"]["schemas"];
+
+        assert_eq!(doc["openapi"], "3.1.0");
+        assert_eq!(
+            schemas["ReqAddCompanyDto"]["properties"]["name"],
+            json!({ "type": "string", "minLength": 1, "maxLength": 200 })
+        );
+        assert_eq!(
+            schemas["ReqBatchCompanyDto"]["properties"]["operations"]["maxItems"],
+            500
+        );
+        assert_eq!(
+            schemas["ReqAddWebhookDto"]["properties"]["secret"]["minLength"],
+            16
+        );
+        let search = doc["paths"]["/search"]["get"]["parameters"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|p| p["name"] == "limit")
+            .unwrap();
+        assert_eq!(search["schema"]["maximum"], 100);
+        assert_eq!(schemas["ErrorData"]["required"], json!(["code", "message"]));
+    }
+
+    #[test]
+    fn documents_responses_as_serialized() {
+        let doc = v1_registry().openapi();
+        let job = &doc["components"]["schemas"]["ResJobDto"];
+
+        // optional fields are written as null rather than left out
+        let required = job["required"].as_array().unwrap();
+        assert!(required.contains(&json!("finished_at")));
+        assert_eq!(job["properties"]["id"]["format"], "uuid");
+        assert_eq!(
+            job["properties"]["status"],
+            json!({ "$ref": "#/components/schemas/JobStatus" })
+        );
+        assert_eq!(
+            doc["components"]["schemas"]["JobStatus"]["enum"],
+            json!(["pending", "running", "completed", "failed", "cancelled"])
+        );
+    }
+
+    #[test]
+    fn routes_are_documented() {
+        let registry = v1_registry();
+        let doc = registry.openapi();
+        let permissions = registry.permissions();
+
+        let documented: usize = doc["paths"]
+            .as_object()
+            .unwrap()
+            .values()
+            .map(|operations| operations.as_object().unwrap().len())
+            .sum();
+        assert_eq!(documented, permissions.len());
+
+        for permission in permissions {
+            let method = permission.method.to_lowercase();
+            let operation = &doc["paths"][&permission.path][&method];
+            assert!(operation.is_object(), "{} {}", method, permission.path);
+
+            let mut in_path: Vec<&str> = permission
+                .path
+                .split('/')
+                .filter_map(|s| s.strip_prefix('{')?.strip_suffix('}'))
+                .collect();
+            let mut documented: Vec<&str> = operation["parameters"]
+                .as_array()
+                .into_iter()
+                .flatten()
+                .filter(|p| p["in"] == "path")
+                .map(|p| p["name"].as_str().unwrap())
+                .collect();
+            in_path.sort();
+            documented.sort();
+            assert_eq!(in_path, documented, "{} {}", method, permission.path);
+
+            assert_eq!(
+                operation["security"] == json!([]),
+                !permission.authenticated,
+                "{} {}",
+                method,
+                permission.path
+            );
+            assert_eq!(
+                operation.get("x-required-roles").is_some(),
+                !permission.roles.is_empty(),
+                "{} {}",
+                method,
+                permission.path
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn serve_the_docs_without_a_cdn() {
+        let app = TestApp::new(MockDatabase::new(DatabaseBackend::Postgres).into_connection());
+
+        let page = app.get("/api/docs").send().await;
+        page.assert_status(StatusCode::OK);
+        assert!(!page.text().contains("https://"));
+
+        for (asset, content_type) in [
+            ("swagger-ui-bundle.js", "text/javascript"),
+            ("swagger-ui.css", "text/css"),
+        ] {
+            assert!(page.text().contains(&format!("/api/docs/{}", asset)));
+
+            let res = app.get(&format!("/api/docs/{}", asset)).send().await;
+            res.assert_status(StatusCode::OK);
+            assert_eq!(res.headers[header::CONTENT_TYPE], content_type);
+        }
+    }
+
+    #[test]
+    fn documents_parameters_and_responses() {
+        let doc = v1_registry().openapi();
+        let paths = &doc["paths"];
+
+        let list = &paths["/companies"]["get"];
+        assert_eq!(list["security"], json!([{ "bearerAuth": [] }]));
+        assert_eq!(list["x-required-roles"], json!(["admin"]));
+        assert_eq!(list["parameters"][0]["name"], "filter");
+        assert_eq!(list["parameters"][0]["style"], "deepObject");
+
+        let add = &paths["/companies"]["post"];
+        assert_eq!(
+            add["requestBody"]["content"]["application/json"]["schema"],
+            json!({ "$ref": "#/components/schemas/ReqAddCompanyDto" })
+        );
+        assert!(add["responses"]["201"].is_object());
+        assert!(add["responses"]["401"].is_object());
+        assert!(add["responses"]["403"].is_object());
+        assert_eq!(add["x-required-roles"], json!(["admin"]));
+
+        let add_department = &paths["/departments"]["post"];
+        assert!(add_department.get("x-required-roles").is_none());
+
+        let update = &paths["/companies/{id}"]["put"];
+        let parameters: Vec<&str> = update["parameters"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|p| p["name"].as_str().unwrap())
+            .collect();
+        assert_eq!(parameters, vec!["id", "If-Match"]);
+        assert!(update["responses"]["204"].is_object());
+        assert!(update["responses"]["412"].is_object());
+
+        let export = &paths["/companies/export"]["get"]["responses"]["200"]["content"];
+        assert!(export["text/csv"].is_object());
+
+        let signin = &paths["/signin"]["get"];
+        assert_eq!(signin["security"], json!([]));
+        assert!(signin["responses"].get("401").is_none());
+        assert_eq!(
+            doc["components"]["securitySchemes"]["bearerAuth"]["scheme"],
+            "bearer"
+        );
+    }
+
+    #[tokio::test]
+    async fn documented_operations_are_routed() {
+        let db = MockDatabase::new(DatabaseBackend::Postgres).into_connection();
+        let state = AppState {
+            db_context: Arc::new(DbContext::new(Arc::new(db))),
+            jwt_helper: Arc::new(JwtHelper::new("secret".to_owned())),
+            event_bus: Arc::new(EventBus::new(8)),
+        };
+        let mut router = v1_routes(state.clone()).with_state(state);
+
+        let doc = v1_registry().openapi();
+        for (path, operations) in doc["paths"].as_object().unwrap() {
+            for method in operations.as_object().unwrap().keys() {
+                let uri = path.replace("{id}", "7f1c2a52-5b1e-4f59-9d5b-0b0d2d7a6a01");
+                let req = Request::builder()
+                    .method(Method::from_bytes(method.to_uppercase().as_bytes()).unwrap())
+                    .uri(uri)
+                    .body(Body::empty())
+                    .unwrap();
+
+                let res = router.call(req).await.unwrap();
+
+                assert_ne!(res.status(), StatusCode::NOT_FOUND, "{} {}", method, path);
+                assert_ne!(
+                    res.status(),
+                    StatusCode::METHOD_NOT_ALLOWED,
+                    "{} {}",
+                    method,
+                    path
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,204 @@
+#[cfg(test)]
+mod registry_test_suite {
+    use std::{
+        net::SocketAddr,
+        sync::Arc,
+        time::{Duration, Instant},
+    };
+
+    use axum::{
+        Router,
+        body::Body,
+        extract::ConnectInfo,
+        http::{Method, Request, StatusCode, header},
+    };
+    use lib::{
+        application::cases::{company::AddCompanyUseCase, department::GetDepartmentUseCase},
+        infrastructure::{
+            db::{DEFAULT_TENANT_ID, DbContext},
+            events::EventBus,
+            helpers::token::JwtHelper,
+        },
+        presentation::{
+            http::{
+                AppState,
+                routes::v1::{v1_registry, v1_routes},
+            },
+            middlewares::rate_limit::{RateLimit, RateLimiter},
+        },
+    };
+    use sea_orm::{DatabaseBackend, MockDatabase};
+    use tower_service::Service;
+
+    fn router() -> (Router, Arc<JwtHelper>) {
+        let db = MockDatabase::new(DatabaseBackend::Postgres).into_connection();
+        let state = AppState {
+            db_context: Arc::new(DbContext::new(Arc::new(db))),
+            jwt_helper: Arc::new(JwtHelper::new("secret".to_owned())),
+            event_bus: Arc::new(EventBus::new(8)),
+        };
+        let jwt = state.jwt_helper.clone();
+
+        (v1_routes(state.clone()).with_state(state), jwt)
+    }
+
+    fn request(method: Method, uri: &str, token: Option<&str>, body: &str) -> Request<Body> {
+        let mut req = Request::builder()
+            .method(method)
+            .uri(uri)
+            .header(header::CONTENT_TYPE, "application/json")
+            .extension(ConnectInfo(SocketAddr::from(([127, 0, 0, 1], 40000))));
+        if let Some(token) = token {
+            req = req.header(header::AUTHORIZATION, format!("Bearer {}", token));
+        }
+        req.body(Body::from(body.to_string())).unwrap()
+    }
+
+    #[tokio::test]
+    async fn company_reads_and_writes_are_admin_only() {
+        let (mut router, jwt) = router();
+        let token = jwt
+            .generate_for_tenant("user".into(), Some(DEFAULT_TENANT_ID), vec!["user".into()])
+            .unwrap();
+        let admin = jwt
+            .generate_for_tenant(
+                "admin".into(),
+                Some(DEFAULT_TENANT_ID),
+                vec!["admin".into()],
+            )
+            .unwrap();
+
+        for (method, uri) in [
+            (Method::GET, "/companies"),
+            (Method::POST, "/companies"),
+            (
+                Method::PUT,
+                "/companies/00000000-0000-0000-0000-000000000001",
+            ),
+            (
+                Method::DELETE,
+                "/companies/00000000-0000-0000-0000-000000000001",
+            ),
+            (Method::POST, "/companies/batch"),
+            (Method::POST, "/organization/import"),
+        ] {
+            let res = router
+                .call(request(method.clone(), uri, Some(&token), "{}"))
+                .await
+                .unwrap();
+            assert_eq!(res.status(), StatusCode::FORBIDDEN, "{} {}", method, uri);
+        }
+
+        // rejected by validation, which comes after the guards
+        let res = router
+            .call(request(
+                Method::POST,
+                "/companies",
+                Some(&admin),
+                r#"{"name":""}"#,
+            ))
+            .await
+            .unwrap();
+        assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+
+        let res = router
+            .call(request(Method::POST, "/webhooks", Some(&token), "{}"))
+            .await
+            .unwrap();
+        assert_eq!(res.status(), StatusCode::FORBIDDEN);
+
+        let res = router
+            .call(request(Method::POST, "/companies", None, r#"{"name":""}"#))
+            .await
+            .unwrap();
+        assert_eq!(res.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[tokio::test]
+    async fn exports_are_admin_only() {
+        let (mut router, jwt) = router();
+        let token = jwt
+            .generate_for_tenant("user".into(), Some(DEFAULT_TENANT_ID), vec![])
+            .unwrap();
+
+        for uri in ["/companies/export", "/departments/export"] {
+            let res = router
+                .call(request(Method::GET, uri, Some(&token), ""))
+                .await
+                .unwrap();
+            assert_eq!(res.status(), StatusCode::FORBIDDEN, "{}", uri);
+        }
+    }
+
+    #[tokio::test]
+    async fn rate_limited_routes_respond_too_many_requests() {
+        let (mut router, _) = router();
+
+        for _ in 0..10 {
+            let res = router
+                .call(request(Method::GET, "/signin", None, ""))
+                .await
+                .unwrap();
+            assert_eq!(res.status(), StatusCode::OK);
+        }
+
+        let res = router
+            .call(request(Method::GET, "/signin", None, ""))
+            .await
+            .unwrap();
+        assert_eq!(res.status(), StatusCode::TOO_MANY_REQUESTS);
+        assert!(res.headers().contains_key(header::RETRY_AFTER));
+    }
+
+    #[test]
+    fn permission_matrix_lists_every_route() {
+        let registry = v1_registry();
+        let permissions = registry.permissions();
+
+        let find = |method: &str, path: &str| {
+            permissions
+                .iter()
+                .find(|p| p.method == method && p.path == path)
+                .unwrap()
+        };
+
+        assert_eq!(find("GET", "/companies").roles, vec!["admin"]);
+        assert_eq!(find("POST", "/companies").roles, vec!["admin"]);
+        assert_eq!(find("POST", "/companies/batch").roles, vec!["admin"]);
+        assert_eq!(find("POST", "/organization/import").roles, vec!["admin"]);
+        assert!(find("POST", "/departments").roles.is_empty());
+        assert!(find("POST", "/departments").authenticated);
+
+        let signin = find("GET", "/signin");
+        assert!(!signin.authenticated);
+        assert_eq!(signin.rate_limit, Some(RateLimit::per_minute(10)));
+
+        let paths = registry.openapi()["paths"].as_object().unwrap().clone();
+        let documented: usize = paths.values().map(|p| p.as_object().unwrap().len()).sum();
+        assert_eq!(documented, permissions.len());
+    }
+
+    #[test]
+    fn use_cases_keep_the_roles_of_their_routes() {
+        let roles = v1_registry().case_roles();
+
+        assert_eq!(
+            roles.of::<AddCompanyUseCase>(),
+            Some(&["admin".to_string()][..])
+        );
+        assert_eq!(roles.of::<GetDepartmentUseCase>(), Some(&[][..]));
+        assert_eq!(roles.of::<String>(), None);
+    }
+
+    #[test]
+    fn rate_limiter_counts_per_caller_and_window() {
+        let limiter = RateLimiter::new(RateLimit::per_minute(2));
+        let now = Instant::now();
+
+        assert!(limiter.acquire("a", now).is_ok());
+        assert!(limiter.acquire("a", now).is_ok());
+        assert_eq!(limiter.acquire("a", now + Duration::from_secs(15)), Err(45));
+        assert!(limiter.acquire("b", now).is_ok());
+        assert!(limiter.acquire("a", now + Duration::from_secs(60)).is_ok());
+    }
+}
@@ -0,0 +1,54 @@
+#[cfg(test)]
+mod response_test_suite {
+    use axum::{
+        body::to_bytes,
+        http::{StatusCode, header},
+        response::IntoResponse,
+    };
+    use cookie::Cookie;
+    use lib::presentation::response::{CaseResponse, RawBody};
+
+    #[tokio::test]
+    async fn created_render_json_with_location() {
+        let res = CaseResponse::created("new-id".to_string())
+            .with_location("/api/v1/companies/new-id")
+            .into_response();
+
+        assert_eq!(res.status(), StatusCode::CREATED);
+        assert_eq!(res.headers()[header::LOCATION], "/api/v1/companies/new-id");
+        assert_eq!(res.headers()[header::CONTENT_TYPE], "application/json");
+
+        let body = to_bytes(res.into_body(), usize::MAX).await.unwrap();
+        assert_eq!(&body[..], br#""new-id""#);
+    }
+
+    #[tokio::test]
+    async fn raw_body_render_as_is_with_attachment() {
+        let res = CaseResponse::ok(RawBody::new("text/csv", "id,name\n1,test-1\n"))
+            .with_attachment("companies.csv")
+            .with_cache_control("no-store")
+            .into_response();
+
+        assert_eq!(res.status(), StatusCode::OK);
+        assert_eq!(res.headers()[header::CONTENT_TYPE], "text/csv");
+        assert_eq!(
+            res.headers()[header::CONTENT_DISPOSITION],
+            r#"attachment; filename="companies.csv""#
+        );
+        assert_eq!(res.headers()[header::CACHE_CONTROL], "no-store");
+
+        let body = to_bytes(res.into_body(), usize::MAX).await.unwrap();
+        assert_eq!(&body[..], b"id,name\n1,test-1\n");
+    }
+
+    #[tokio::test]
+    async fn cookies_are_appended() {
+        let res = CaseResponse::ok(())
+            .with_cookie(Cookie::new("a", "1"))
+            .with_cookie(Cookie::new("b", "2"))
+            .into_response();
+
+        let cookies: Vec<_> = res.headers().get_all(header::SET_COOKIE).iter().collect();
+        assert_eq!(cookies, ["a=1", "b=2"]);
+    }
+}
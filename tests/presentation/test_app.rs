@@ -0,0 +1,89 @@
+#[cfg(test)]
+mod test_app_test_suite {
+    use axum::http::{StatusCode, header};
+    use lib::test_util::TestApp;
+    use sea_orm::{DatabaseBackend, MockDatabase};
+    use serde_json::{Value, json};
+    use uuid::Uuid;
+
+    fn mocked() -> TestApp {
+        TestApp::new(MockDatabase::new(DatabaseBackend::Postgres).into_connection())
+    }
+
+    #[tokio::test]
+    async fn guards_reject_before_the_use_case_runs() {
+        let app = mocked();
+
+        app.get("/api/v1/companies")
+            .send()
+            .await
+            .assert_error(StatusCode::UNAUTHORIZED, "UNAUTHORIZED");
+
+        app.get("/api/v1/companies")
+            .bearer(&app.token(&["user"]))
+            .send()
+            .await
+            .assert_error(StatusCode::FORBIDDEN, "FORBIDDEN");
+    }
+
+    #[tokio::test]
+    async fn signing_in_grants_no_role() {
+        let app = mocked();
+
+        let res = app.get("/api/v1/signin").send().await;
+        res.assert_status(StatusCode::OK);
+        let token = res.json::<String>();
+
+        app.get("/api/v1/companies/deleted")
+            .bearer(&token)
+            .send()
+            .await
+            .assert_error(StatusCode::FORBIDDEN, "FORBIDDEN");
+    }
+
+    #[tokio::test]
+    async fn extractor_rejections_render_as_app_errors() {
+        let app = mocked();
+        let token = app.token(&["admin"]);
+
+        app.post("/api/v1/companies")
+            .bearer(&token)
+            .header(header::CONTENT_TYPE, "application/json")
+            .body("{")
+            .send()
+            .await
+            .assert_error(StatusCode::BAD_REQUEST, "INPUT_PARSE_FAIL");
+
+        app.post("/api/v1/companies")
+            .bearer(&token)
+            .json(&json!({ "name": "" }))
+            .send()
+            .await
+            .assert_error(StatusCode::BAD_REQUEST, "INPUT_VALIDATE_FAIL");
+    }
+
+    #[tokio::test]
+    async fn companies_round_trip_through_sqlite() {
+        let app = TestApp::sqlite().await;
+        let token = app.token(&["admin"]);
+
+        let res = app
+            .post("/api/v1/companies")
+            .bearer(&token)
+            .json(&json!({ "name": "Acme" }))
+            .send()
+            .await;
+        res.assert_status(StatusCode::CREATED);
+        let location = res.headers[header::LOCATION].to_str().unwrap().to_string();
+
+        let res = app.get(&location).bearer(&token).send().await;
+        res.assert_status(StatusCode::OK);
+        assert_eq!(res.json::<Value>()["name"], "Acme", "{}", res.text());
+
+        app.get(&format!("/api/v1/companies/{}", Uuid::new_v4()))
+            .bearer(&token)
+            .send()
+            .await
+            .assert_error(StatusCode::NOT_FOUND, "DATA_DUPPLICATED");
+    }
+}